@@ -14,9 +14,8 @@ fn main() {
     card.exp_year = "20";
 
     // Define the charge
-    let mut params = stripe::ChargeParams::default();
-    params.amount = Some(1000);
-    params.source = Some(stripe::PaymentSourceParams::Card(card));
+    let params = stripe::ChargeParams::new(1000, stripe::Currency::USD)
+        .source(stripe::PaymentSourceParams::Card(card));
 
     // Create the charge
     let charge = stripe::Charge::create(&client, params).unwrap();
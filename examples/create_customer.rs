@@ -1,7 +1,7 @@
 extern crate stripe;
 
 use std::env;
-use stripe::{Customer, CustomerParams, PaymentSourceParams};
+use stripe::{Customer, CustomerParams, PaymentSourceParams, UpdateValue};
 
 fn main() {
     // Create a new client
@@ -21,10 +21,13 @@ fn main() {
             //       so we can use default struct field value syntax eventually
             account_balance: None,
             business_vat_id: None,
-            coupon: None,
-            description: None,
+            coupon: UpdateValue::Keep,
+            description: UpdateValue::Keep,
+            invoice_settings: None,
             metadata: None,
+            preferred_locales: None,
             shipping: None,
+            test_clock: None,
         },
     ).unwrap();
 
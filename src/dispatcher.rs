@@ -0,0 +1,193 @@
+use resources::{Event, EventType};
+use std::collections::HashMap;
+use std::error;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// The error returned by an event handler.
+pub type HandlerError = Box<dyn error::Error + Send + Sync>;
+
+type Handler = dyn Fn(&Event) -> Result<(), HandlerError> + Send + Sync;
+
+/// The number of events of one type handled by an `EventDispatcher`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DispatchStats {
+    /// Events whose handler succeeded.
+    pub succeeded: u64,
+    /// Events whose handler returned an error or panicked.
+    pub failed: u64,
+    /// Events which had no handler registered for their type.
+    pub unhandled: u64,
+}
+
+/// Routes (verified) events to the handler registered for their type.
+///
+/// Batches of events are handled on up to `max_concurrency` threads at once,
+/// eg. to process a backlog of events without overloading a database.
+/// Since the client is blocking, handlers are plain closures rather than futures.
+///
+/// A handler which panics fails its event, like a handler which returns an error.
+///
+/// ```rust,ignore
+/// let mut dispatcher = stripe::EventDispatcher::new(4);
+/// dispatcher.on(stripe::EventType::ChargeSucceeded, |event| {
+///     println!("charge succeeded: {}", event.id);
+///     Ok(())
+/// });
+/// let event = stripe::Webhook::construct_event(payload, signature, secret)?;
+/// dispatcher.dispatch(&event)?;
+/// ```
+pub struct EventDispatcher {
+    handlers: Arc<HashMap<EventType, Box<Handler>>>,
+    max_concurrency: usize,
+    stats: Arc<Mutex<HashMap<EventType, DispatchStats>>>,
+}
+
+impl EventDispatcher {
+    /// Creates a dispatcher which handles at most `max_concurrency` events at once.
+    pub fn new(max_concurrency: usize) -> EventDispatcher {
+        EventDispatcher {
+            handlers: Arc::new(HashMap::new()),
+            max_concurrency: ::std::cmp::max(max_concurrency, 1),
+            stats: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Registers the handler of the events of `event_type`, replacing any previous handler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while events are being dispatched.
+    pub fn on<F>(&mut self, event_type: EventType, handler: F) -> &mut Self
+        where F: Fn(&Event) -> Result<(), HandlerError> + Send + Sync + 'static
+    {
+        Arc::get_mut(&mut self.handlers)
+            .expect("handlers can't be registered while events are being dispatched")
+            .insert(event_type, Box::new(handler));
+        self
+    }
+
+    /// Handles an event on the current thread.
+    ///
+    /// Events without a handler are counted as `unhandled` and succeed.
+    pub fn dispatch(&self, event: &Event) -> Result<(), HandlerError> {
+        dispatch(&self.handlers, &self.stats, event)
+    }
+
+    /// Handles a batch of events, on up to `max_concurrency` threads at once.
+    ///
+    /// Returns the events whose handler failed (or panicked), along with the error.
+    pub fn dispatch_all(&self, events: Vec<Event>) -> Vec<(Event, HandlerError)> {
+        let workers = ::std::cmp::min(self.max_concurrency, events.len());
+        let queue = Arc::new(Mutex::new(events.into_iter()));
+        let failures = Arc::new(Mutex::new(Vec::new()));
+
+        let threads = (0..workers)
+            .map(|_| {
+                let handlers = self.handlers.clone();
+                let stats = self.stats.clone();
+                let queue = queue.clone();
+                let failures = failures.clone();
+                thread::spawn(move || loop {
+                    let event = match queue.lock().unwrap().next() {
+                        Some(event) => event,
+                        None => return,
+                    };
+                    if let Err(err) = dispatch(&handlers, &stats, &event) {
+                        failures.lock().unwrap().push((event, err));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            // NOTE: Panics of the handlers are caught by `dispatch`, so the workers don't panic
+            thread.join().expect("event dispatcher worker panicked");
+        }
+
+        let mut failures = failures.lock().unwrap();
+        failures.drain(..).collect()
+    }
+
+    /// The number of events handled so far, by type.
+    pub fn stats(&self) -> HashMap<EventType, DispatchStats> {
+        self.stats.lock().unwrap().clone()
+    }
+}
+
+fn dispatch(
+    handlers: &HashMap<EventType, Box<Handler>>,
+    stats: &Mutex<HashMap<EventType, DispatchStats>>,
+    event: &Event,
+) -> Result<(), HandlerError> {
+    let result = handlers.get(&event.event_type).map(|handler| {
+        panic::catch_unwind(AssertUnwindSafe(|| handler(event))).unwrap_or_else(|payload| {
+            let message = payload.downcast_ref::<&str>().map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(format!("handler of event {} panicked: {}", event.id, message).into())
+        })
+    });
+
+    let mut stats = stats.lock().unwrap();
+    let stats = stats.entry(event.event_type.clone()).or_insert_with(DispatchStats::default);
+    match result {
+        Some(Ok(())) => stats.succeeded += 1,
+        Some(Err(_)) => stats.failed += 1,
+        None => stats.unhandled += 1,
+    }
+    result.unwrap_or(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DispatchStats, EventDispatcher};
+    use resources::{Event, EventType};
+    use serde_json as json;
+
+    fn event(id: &str, event_type: &str) -> Event {
+        let data = r#"{"object": {"object": "balance", "available": [], "livemode": false, "pending": []}}"#;
+        json::from_str(&format!(r#"{{"id": "{}", "type": "{}", "data": {}}}"#, id, event_type, data)).unwrap()
+    }
+
+    #[test]
+    fn dispatch_all_counts_results_by_type() {
+        let mut dispatcher = EventDispatcher::new(2);
+        dispatcher.on(EventType::BalanceAvailable, |event| match event.id.as_str() {
+            "evt_bad" => Err("boom".into()),
+            _ => Ok(()),
+        });
+
+        let events = vec![
+            event("evt_1", "balance.available"),
+            event("evt_bad", "balance.available"),
+            event("evt_2", "balance.available"),
+            event("evt_3", "account.updated"),
+        ];
+        let failures = dispatcher.dispatch_all(events);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0.id, "evt_bad");
+
+        let stats = dispatcher.stats();
+        assert_eq!(stats[&EventType::BalanceAvailable], DispatchStats { succeeded: 2, failed: 1, unhandled: 0 });
+        assert_eq!(stats[&EventType::AccountUpdated], DispatchStats { succeeded: 0, failed: 0, unhandled: 1 });
+    }
+
+    #[test]
+    fn dispatch_all_reports_panics_as_failures() {
+        let mut dispatcher = EventDispatcher::new(1);
+        dispatcher.on(EventType::BalanceAvailable, |event| match event.id.as_str() {
+            "evt_panic" => panic!("lost connection"),
+            _ => Ok(()),
+        });
+
+        let events = vec![event("evt_panic", "balance.available"), event("evt_2", "balance.available")];
+        let failures = dispatcher.dispatch_all(events);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0.id, "evt_panic");
+        assert!(failures[0].1.to_string().contains("lost connection"));
+
+        let stats = dispatcher.stats();
+        assert_eq!(stats[&EventType::BalanceAvailable], DispatchStats { succeeded: 1, failed: 1, unhandled: 0 });
+    }
+}
@@ -2,23 +2,103 @@ extern crate hyper;
 extern crate serde_json as json;
 extern crate serde_qs as qs;
 
-use params::to_snakecase;
 use std::error;
 use std::fmt;
 use std::io;
-use std::num::ParseIntError;
 
 /// An error encountered when communicating with the Stripe API.
 #[derive(Debug)]
 pub enum Error {
     /// An error reported by Stripe.
-    Stripe(RequestError),
+    ///
+    /// Boxed, like the other large variants, so that `Result<T, Error>` stays small.
+    Stripe(Box<RequestError>),
     /// A networking error communicating with the Stripe server.
     Http(hyper::Error),
     /// An error reading the response body.
     Io(io::Error),
     /// An error converting between wire format and Rust types.
-    Conversion(Box<error::Error + Send>),
+    Conversion(Box<dyn error::Error + Send + Sync>),
+    /// Request parameters that were rejected before being sent to Stripe.
+    InvalidParams(Vec<Violation>),
+    /// An invalid client configuration, eg. a missing environment variable.
+    Config(String),
+    /// A response from Stripe which couldn't be deserialized.
+    Deserialization {
+        /// The path of the offending value in the response, eg. `data[2].source.exp_month`.
+        path: String,
+        /// The part of the response around the offending value.
+        snippet: String,
+        source: Box<json::Error>,
+    },
+    /// An idempotency key was reused with different parameters than the request it was first used for.
+    IdempotencyMismatch {
+        /// The id of the request the idempotency key was first used for, if Stripe reported it.
+        original_request_id: Option<String>,
+        error: Box<RequestError>,
+    },
+}
+
+impl Error {
+    /// The error reported by Stripe, if the request reached Stripe.
+    pub fn request_error(&self) -> Option<&RequestError> {
+        match *self {
+            Error::Stripe(ref err) => Some(err),
+            Error::IdempotencyMismatch { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// The id of the failed request, to find it in the Stripe dashboard's logs or for support tickets.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_error().and_then(|err| err.request_id.as_ref()).map(|id| id.as_str())
+    }
+
+    /// The code of the error reported by Stripe, if any.
+    pub fn code(&self) -> Option<&ErrorCode> {
+        self.request_error().and_then(|err| err.code.as_ref())
+    }
+
+    /// The decline code of a card error, if the card issuer gave one.
+    pub fn decline_code(&self) -> Option<&DeclineCode> {
+        self.request_error().and_then(|err| err.decline_code.as_ref())
+    }
+
+    /// Whether the request failed because a card was declined.
+    pub fn is_card_declined(&self) -> bool {
+        self.code() == Some(&ErrorCode::CardDeclined)
+    }
+
+    /// Whether the request failed because its idempotency key was reused,
+    /// either with different parameters or while the first request was still in progress.
+    pub fn is_idempotency_conflict(&self) -> bool {
+        match self.request_error() {
+            Some(err) => err.error_type == ErrorType::Idempotency || err.code == Some(ErrorCode::IdempotencyKeyInUse),
+            None => false,
+        }
+    }
+}
+
+impl Error {
+    /// Whether the request may succeed if it is sent again (with the same idempotency key).
+    ///
+    /// Connection failures, timeouts, rate limiting (429), lock timeouts (409) and server errors (5xx)
+    /// are retryable; other errors will fail again until the request is changed.
+    pub fn is_retryable(&self) -> bool {
+        match *self {
+            Error::Stripe(ref err) => match err.http_status {
+                409 => err.code == Some(ErrorCode::LockTimeout) || err.code == Some(ErrorCode::IdempotencyKeyInUse),
+                429 => true,
+                500..=599 => true,
+                _ => false,
+            },
+            Error::IdempotencyMismatch { .. } => false,
+            Error::Http(hyper::Error::Io(_)) => true,
+            Error::Http(_) => false,
+            Error::Io(_) => true,
+            Error::Conversion(_) | Error::InvalidParams(_) | Error::Config(_) | Error::Deserialization { .. } => false,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -29,6 +109,23 @@ impl fmt::Display for Error {
             Error::Http(ref err) => write!(f, ": {}", err),
             Error::Io(ref err) => write!(f, ": {}", err),
             Error::Conversion(ref err) => write!(f, ": {}", err),
+            Error::InvalidParams(ref violations) => {
+                for (i, violation) in violations.iter().enumerate() {
+                    write!(f, "{}{}", if i == 0 { ": " } else { ", " }, violation)?;
+                }
+                Ok(())
+            }
+            Error::Config(ref message) => write!(f, ": {}", message),
+            Error::Deserialization { ref path, ref snippet, ref source } => {
+                write!(f, ": {} at `{}` near `{}`", source, path, snippet)
+            }
+            Error::IdempotencyMismatch { ref original_request_id, ref error } => {
+                write!(f, ": {}", error)?;
+                match *original_request_id {
+                    Some(ref id) => write!(f, " (key first used by {})", id),
+                    None => Ok(()),
+                }
+            }
         }
     }
 }
@@ -40,22 +137,33 @@ impl error::Error for Error {
             Error::Http(_) => "error communicating with stripe",
             Error::Io(_) => "error reading response from stripe",
             Error::Conversion(_) => "error converting between wire format and Rust types",
+            Error::InvalidParams(_) => "invalid request parameters",
+            Error::Config(_) => "invalid client configuration",
+            Error::Deserialization { .. } => "error deserializing response from stripe",
+            Error::IdempotencyMismatch { .. } => "idempotency key reused with different parameters",
         }
     }
 
-    fn cause(&self) -> Option<&error::Error> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
-            Error::Stripe(ref err) => Some(err),
+            Error::Stripe(ref err) => Some(&**err),
             Error::Http(ref err) => Some(err),
             Error::Io(ref err) => Some(err),
             Error::Conversion(ref err) => Some(&**err),
+            Error::InvalidParams(_) | Error::Config(_) => None,
+            Error::Deserialization { ref source, .. } => Some(&**source),
+            Error::IdempotencyMismatch { ref error, .. } => Some(&**error),
         }
     }
 }
 
 impl From<RequestError> for Error {
     fn from(err: RequestError) -> Error {
-        Error::Stripe(err)
+        if err.error_type == ErrorType::Idempotency {
+            Error::IdempotencyMismatch { original_request_id: None, error: Box::new(err) }
+        } else {
+            Error::Stripe(Box::new(err))
+        }
     }
 }
 
@@ -73,7 +181,8 @@ impl From<io::Error> for Error {
 
 impl From<qs::Error> for Error {
     fn from(err: qs::Error) -> Error {
-        Error::Conversion(Box::new(err))
+        // NOTE: serde_qs errors aren't `Sync`, so only their message is kept
+        Error::Conversion(err.to_string().into())
     }
 }
 
@@ -83,127 +192,276 @@ impl From<json::Error> for Error {
     }
 }
 
+impl Error {
+    /// Wraps an error deserializing `body`, adding the path and a snippet of the offending value.
+    pub(crate) fn deserialization(body: &[u8], source: json::Error) -> Error {
+        let offset = error_offset(body, source.line(), source.column());
+        let start = offset.saturating_sub(40);
+        let end = ::std::cmp::min(offset + 40, body.len());
+        Error::Deserialization {
+            path: json_path(&body[..offset]),
+            snippet: String::from_utf8_lossy(&body[start..end]).into_owned(),
+            source: Box::new(source),
+        }
+    }
+}
 
-/// The list of possible values for a RequestError's type.
-#[derive(Debug, PartialEq, Deserialize)]
-pub enum ErrorType {
-    #[serde(skip_deserializing)]
-    Unknown,
-
-    #[serde(rename = "api_error")]
-    Api,
-    #[serde(rename = "api_connection_error")]
-    Connection,
-    #[serde(rename = "authentication_error")]
-    Authentication,
-    #[serde(rename = "card_error")]
-    Card,
-    #[serde(rename = "invalid_request_error")]
-    InvalidRequest,
-    #[serde(rename = "rate_limit_error")]
-    RateLimit,
-    #[serde(rename = "validation_error")]
-    Validation,
+/// Converts the (1-based) line and column of a serde_json error to a byte offset.
+fn error_offset(body: &[u8], line: usize, column: usize) -> usize {
+    let mut offset = 0;
+    for _ in 1..line {
+        match body[offset..].iter().position(|&b| b == b'\n') {
+            Some(i) => offset += i + 1,
+            None => return body.len(),
+        }
+    }
+    ::std::cmp::min(offset + column, body.len())
 }
 
-impl Default for ErrorType {
-    fn default() -> Self {
-        ErrorType::Unknown
+/// Finds the path of the value being parsed at the end of `json`, by scanning its open objects and arrays.
+fn json_path(json: &[u8]) -> String {
+    enum Frame {
+        Object { key: Option<String>, expect_key: bool },
+        Array { index: usize },
+    }
+
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut i = 0;
+    while i < json.len() {
+        match json[i] {
+            b'{' => stack.push(Frame::Object { key: None, expect_key: true }),
+            b'[' => stack.push(Frame::Array { index: 0 }),
+            b'}' | b']' => {
+                stack.pop();
+            }
+            b':' => {
+                if let Some(&mut Frame::Object { ref mut expect_key, .. }) = stack.last_mut() {
+                    *expect_key = false;
+                }
+            }
+            b',' => match stack.last_mut() {
+                Some(&mut Frame::Object { ref mut expect_key, .. }) => *expect_key = true,
+                Some(&mut Frame::Array { ref mut index }) => *index += 1,
+                None => {}
+            },
+            b'"' => {
+                let start = i + 1;
+                i += 1;
+                while i < json.len() && json[i] != b'"' {
+                    i += if json[i] == b'\\' { 2 } else { 1 };
+                }
+                let end = ::std::cmp::min(i, json.len());
+                if let Some(&mut Frame::Object { ref mut key, expect_key: true }) = stack.last_mut() {
+                    *key = Some(String::from_utf8_lossy(&json[start..end]).into_owned());
+                }
+            }
+            _ => {}
+        }
+        i += 1;
     }
+
+    let mut path = String::new();
+    for frame in &stack {
+        match *frame {
+            Frame::Object { key: Some(ref key), .. } => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Frame::Object { key: None, .. } => {}
+            Frame::Array { index } => path.push_str(&format!("[{}]", index)),
+        }
+    }
+    path
+}
+
+/// A request parameter which failed client-side validation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// The name of the offending parameter, eg. `transfer_data[amount]`.
+    pub param: &'static str,
+    /// A human-readable description of the problem.
+    pub message: String,
 }
 
-impl fmt::Display for ErrorType {
+impl Violation {
+    pub fn new<M: Into<String>>(param: &'static str, message: M) -> Violation {
+        Violation { param, message: message.into() }
+    }
+}
+
+impl fmt::Display for Violation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}Error", self)))
+        write!(f, "{}: {}", self.param, self.message)
     }
 }
 
-/// The list of possible values for a RequestError's code.
-#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-#[serde(rename_all = "snake_case")]
-pub enum ErrorCode {
-    AccountAlreadyExists,
-    AccountCountryInvalidAddress,
-    AccountInvalid,
-    AccountNumberInvalid,
-    AlipayUpgradeRequired,
-    AmountTooLarge,
-    AmountTooSmall,
-    ApiKeyExpired,
-    BalanceInsufficient,
-    BankAccountExists,
-    BankAccountUnusable,
-    BankAccountUnverified,
-    BitcoinUpgradeRequired,
-    CardDeclined,
-    ChargeAlreadyCaptured,
-    ChargeAlreadyRefunded,
-    ChargeDisputed,
-    ChargeExpiredForCapture,
-    CountryUnsupported,
-    CouponExpired,
-    CustomerMaxSubscriptions,
-    EmailInvalid,
-    ExpiredCard,
-    IncorrectAddress,
-    IncorrectCvc,
-    IncorrectNumber,
-    IncorrectZip,
-    InstantPayoutsUnsupported,
-    InvalidCardType,
-    InvalidChargeAmount,
-    InvalidCvc,
-    InvalidExpiryMonth,
-    InvalidExpiryYear,
-    InvalidNumber,
-    InvalidSourceUsage,
-    InvoiceNoCustomerLineItems,
-    InvoiceNoSubscriptionLineItems,
-    InvoiceNotEditable,
-    InvoiceUpcomingNone,
-    LivemodeMismatch,
-    Missing,
-    OrderCreationFailed,
-    OrderRequiredSettings,
-    OrderStatusInvalid,
-    OrderUpstreamTimeout,
-    OutOfInventory,
-    ParameterInvalidEmpty,
-    ParameterInvalidInteger,
-    ParameterInvalidStringBlank,
-    ParameterInvalidStringEmpty,
-    ParameterMissing,
-    ParameterUnknown,
-    PaymentMethodUnactivated,
-    PayoutsNotAllowed,
-    PlatformApiKeyExpired,
-    PostalCodeInvalid,
-    ProcessingError,
-    ProductInactive,
-    RateLimit,
-    ResourceAlreadyExists,
-    ResourceMissing,
-    RoutingNumberInvalid,
-    SecretKeyRequired,
-    SepaUnsupportedAccount,
-    ShippingCalculationFailed,
-    SkuInactive,
-    StateUnsupported,
-    TaxIdInvalid,
-    TaxesCalculationFailed,
-    TestmodeChargesOnly,
-    TlsVersionUnsupported,
-    TokenAlreadyUsed,
-    TokenInUse,
-    TransfersNotAllowed,
-    UpstreamOrderCreationFailed,
-    UrlInvalid,
-    #[doc(hidden)] __NonExhaustive,
+string_enum! {
+    /// The list of possible values for a RequestError's type.
+    pub enum ErrorType {
+        /// The type of errors which didn't come from Stripe, eg. `Error::Stripe` for a bad redirect.
+        Unknown => "unknown",
+        Api => "api_error",
+        Connection => "api_connection_error",
+        Authentication => "authentication_error",
+        Card => "card_error",
+        Idempotency => "idempotency_error",
+        InvalidRequest => "invalid_request_error",
+        RateLimit => "rate_limit_error",
+        Validation => "validation_error",
+    }
 }
 
-impl fmt::Display for ErrorCode {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+impl Default for ErrorType {
+    fn default() -> Self {
+        ErrorType::Unknown
+    }
+}
+
+string_enum! {
+    /// The list of possible values for a RequestError's code.
+    ///
+    /// For more details see https://stripe.com/docs/error-codes.
+    pub enum ErrorCode {
+        AccountAlreadyExists => "account_already_exists",
+        AccountCountryInvalidAddress => "account_country_invalid_address",
+        AccountInvalid => "account_invalid",
+        AccountNumberInvalid => "account_number_invalid",
+        AlipayUpgradeRequired => "alipay_upgrade_required",
+        AmountTooLarge => "amount_too_large",
+        AmountTooSmall => "amount_too_small",
+        ApiKeyExpired => "api_key_expired",
+        AuthenticationRequired => "authentication_required",
+        BalanceInsufficient => "balance_insufficient",
+        BankAccountExists => "bank_account_exists",
+        BankAccountUnusable => "bank_account_unusable",
+        BankAccountUnverified => "bank_account_unverified",
+        BitcoinUpgradeRequired => "bitcoin_upgrade_required",
+        CardDeclineRateLimitExceeded => "card_decline_rate_limit_exceeded",
+        CardDeclined => "card_declined",
+        ChargeAlreadyCaptured => "charge_already_captured",
+        ChargeAlreadyRefunded => "charge_already_refunded",
+        ChargeDisputed => "charge_disputed",
+        ChargeExpiredForCapture => "charge_expired_for_capture",
+        CountryUnsupported => "country_unsupported",
+        CouponExpired => "coupon_expired",
+        CustomerMaxSubscriptions => "customer_max_subscriptions",
+        EmailInvalid => "email_invalid",
+        ExpiredCard => "expired_card",
+        IdempotencyKeyInUse => "idempotency_key_in_use",
+        IncorrectAddress => "incorrect_address",
+        IncorrectCvc => "incorrect_cvc",
+        IncorrectNumber => "incorrect_number",
+        IncorrectZip => "incorrect_zip",
+        InstantPayoutsUnsupported => "instant_payouts_unsupported",
+        InvalidCardType => "invalid_card_type",
+        InvalidChargeAmount => "invalid_charge_amount",
+        InvalidCvc => "invalid_cvc",
+        InvalidExpiryMonth => "invalid_expiry_month",
+        InvalidExpiryYear => "invalid_expiry_year",
+        InvalidNumber => "invalid_number",
+        InvalidSourceUsage => "invalid_source_usage",
+        InvoiceNoCustomerLineItems => "invoice_no_customer_line_items",
+        InvoiceNoSubscriptionLineItems => "invoice_no_subscription_line_items",
+        InvoiceNotEditable => "invoice_not_editable",
+        InvoiceUpcomingNone => "invoice_upcoming_none",
+        LivemodeMismatch => "livemode_mismatch",
+        LockTimeout => "lock_timeout",
+        Missing => "missing",
+        OrderCreationFailed => "order_creation_failed",
+        OrderRequiredSettings => "order_required_settings",
+        OrderStatusInvalid => "order_status_invalid",
+        OrderUpstreamTimeout => "order_upstream_timeout",
+        OutOfInventory => "out_of_inventory",
+        ParameterInvalidEmpty => "parameter_invalid_empty",
+        ParameterInvalidInteger => "parameter_invalid_integer",
+        ParameterInvalidStringBlank => "parameter_invalid_string_blank",
+        ParameterInvalidStringEmpty => "parameter_invalid_string_empty",
+        ParameterMissing => "parameter_missing",
+        ParameterUnknown => "parameter_unknown",
+        PaymentIntentAuthenticationFailure => "payment_intent_authentication_failure",
+        PaymentIntentUnexpectedState => "payment_intent_unexpected_state",
+        PaymentMethodUnactivated => "payment_method_unactivated",
+        PayoutsNotAllowed => "payouts_not_allowed",
+        PlatformApiKeyExpired => "platform_api_key_expired",
+        PostalCodeInvalid => "postal_code_invalid",
+        ProcessingError => "processing_error",
+        ProductInactive => "product_inactive",
+        RateLimit => "rate_limit",
+        ResourceAlreadyExists => "resource_already_exists",
+        ResourceMissing => "resource_missing",
+        RoutingNumberInvalid => "routing_number_invalid",
+        SecretKeyRequired => "secret_key_required",
+        SepaUnsupportedAccount => "sepa_unsupported_account",
+        SetupIntentAuthenticationFailure => "setup_intent_authentication_failure",
+        ShippingCalculationFailed => "shipping_calculation_failed",
+        SkuInactive => "sku_inactive",
+        StateUnsupported => "state_unsupported",
+        TaxIdInvalid => "tax_id_invalid",
+        TaxesCalculationFailed => "taxes_calculation_failed",
+        TestmodeChargesOnly => "testmode_charges_only",
+        TlsVersionUnsupported => "tls_version_unsupported",
+        TokenAlreadyUsed => "token_already_used",
+        TokenInUse => "token_in_use",
+        TransfersNotAllowed => "transfers_not_allowed",
+        UpstreamOrderCreationFailed => "upstream_order_creation_failed",
+        UrlInvalid => "url_invalid",
+    }
+}
+
+string_enum! {
+    /// The list of possible values for a RequestError's decline code.
+    ///
+    /// For more details see https://stripe.com/docs/declines/codes.
+    pub enum DeclineCode {
+        ApproveWithId => "approve_with_id",
+        AuthenticationRequired => "authentication_required",
+        CallIssuer => "call_issuer",
+        CardNotSupported => "card_not_supported",
+        CardVelocityExceeded => "card_velocity_exceeded",
+        CurrencyNotSupported => "currency_not_supported",
+        DoNotHonor => "do_not_honor",
+        DoNotTryAgain => "do_not_try_again",
+        DuplicateTransaction => "duplicate_transaction",
+        ExpiredCard => "expired_card",
+        Fraudulent => "fraudulent",
+        GenericDecline => "generic_decline",
+        IncorrectCvc => "incorrect_cvc",
+        IncorrectNumber => "incorrect_number",
+        IncorrectPin => "incorrect_pin",
+        IncorrectZip => "incorrect_zip",
+        InsufficientFunds => "insufficient_funds",
+        InvalidAccount => "invalid_account",
+        InvalidAmount => "invalid_amount",
+        InvalidCvc => "invalid_cvc",
+        InvalidExpiryMonth => "invalid_expiry_month",
+        InvalidExpiryYear => "invalid_expiry_year",
+        InvalidNumber => "invalid_number",
+        InvalidPin => "invalid_pin",
+        IssuerNotAvailable => "issuer_not_available",
+        LostCard => "lost_card",
+        MerchantBlacklist => "merchant_blacklist",
+        NewAccountInformationAvailable => "new_account_information_available",
+        NoActionTaken => "no_action_taken",
+        NotPermitted => "not_permitted",
+        OfflinePinRequired => "offline_pin_required",
+        OnlineOrOfflinePinRequired => "online_or_offline_pin_required",
+        PickupCard => "pickup_card",
+        PinTryExceeded => "pin_try_exceeded",
+        ProcessingError => "processing_error",
+        ReenterTransaction => "reenter_transaction",
+        RestrictedCard => "restricted_card",
+        RevocationOfAllAuthorizations => "revocation_of_all_authorizations",
+        RevocationOfAuthorization => "revocation_of_authorization",
+        SecurityViolation => "security_violation",
+        ServiceNotAllowed => "service_not_allowed",
+        StolenCard => "stolen_card",
+        StopPaymentOrder => "stop_payment_order",
+        TestmodeDecline => "testmode_decline",
+        TransactionNotAllowed => "transaction_not_allowed",
+        TryAgainLater => "try_again_later",
+        WithdrawalCountLimitExceeded => "withdrawal_count_limit_exceeded",
     }
 }
 
@@ -230,10 +488,34 @@ pub struct RequestError {
 
     /// For card errors resulting from a bank decline, a string indicating the
     /// bank's reason for the decline if they provide one.
-    pub decline_code: Option<String>,
+    pub decline_code: Option<DeclineCode>,
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// The ID of the failed payment intent, if applicable.
+    #[serde(default, deserialize_with = "deserialize_object_id")]
+    pub payment_intent: Option<String>,
+
+    /// The ID of the failed setup intent, if applicable.
+    #[serde(default, deserialize_with = "deserialize_object_id")]
+    pub setup_intent: Option<String>,
+
+    /// For errors about a specific parameter, the name of that parameter.
+    #[serde(default)]
+    pub param: Option<String>,
+
+    /// A link to more information about the error code.
+    #[serde(default)]
+    pub doc_url: Option<String>,
+
+    /// The id of the request, as shown in the Stripe dashboard's logs.
+    #[serde(skip_deserializing)]
+    pub request_id: Option<String>,
+
+    /// The raw body of the response, if enabled with `Client::set_capture_error_body`.
+    #[serde(skip_deserializing)]
+    pub raw_body: Option<String>,
 }
 
 impl fmt::Display for RequestError {
@@ -242,10 +524,43 @@ impl fmt::Display for RequestError {
         if let Some(ref message) = self.message {
             write!(f, ": {}", message)?;
         }
+        if let Some(ref param) = self.param {
+            write!(f, " (param: {})", param)?;
+        }
+        if let Some(ref charge) = self.charge {
+            write!(f, " (charge: {})", charge)?;
+        }
+        if let Some(ref payment_intent) = self.payment_intent {
+            write!(f, " (payment_intent: {})", payment_intent)?;
+        }
+        if let Some(ref setup_intent) = self.setup_intent {
+            write!(f, " (setup_intent: {})", setup_intent)?;
+        }
+        if let Some(ref doc_url) = self.doc_url {
+            write!(f, " see {}", doc_url)?;
+        }
+        if let Some(ref request_id) = self.request_id {
+            write!(f, " [{}]", request_id)?;
+        }
         Ok(())
     }
 }
 
+/// Deserializes the id of an object which is sometimes embedded in full (eg. the `payment_intent` of an error).
+fn deserialize_object_id<'de, D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    use serde::Deserialize;
+
+    let value = Option::<json::Value>::deserialize(deserializer)?;
+    Ok(match value {
+        Some(json::Value::String(id)) => Some(id),
+        Some(json::Value::Object(mut object)) => match object.remove("id") {
+            Some(json::Value::String(id)) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
 impl error::Error for RequestError {
     fn description(&self) -> &str {
         self.message.as_ref().map(|s| s.as_str()).unwrap_or(
@@ -263,7 +578,12 @@ pub struct ErrorObject {
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug)]
 pub enum WebhookError {
-    BadHeader(ParseIntError),
+    /// The signature header has no `t=` timestamp.
+    MissingTimestamp,
+    /// The signature header has no `v1=` signature.
+    MissingSignature,
+    /// The signature header isn't a list of `key=value` pairs, or its timestamp isn't a number.
+    MalformedHeader(String),
     BadSignature,
     BadTimestamp(i64),
     BadParse(json::Error),
@@ -273,8 +593,9 @@ impl fmt::Display for WebhookError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(error::Error::description(self))?;
         match *self {
-            WebhookError::BadHeader(ref err) => write!(f, ": {}", err),
-            WebhookError::BadSignature => write!(f, "Signatures do not match"),
+            WebhookError::MissingTimestamp | WebhookError::MissingSignature => Ok(()),
+            WebhookError::MalformedHeader(ref err) => write!(f, ": {}", err),
+            WebhookError::BadSignature => f.write_str(": signatures do not match"),
             WebhookError::BadTimestamp(ref err) => write!(f, ": {}", err),
             WebhookError::BadParse(ref err) => write!(f, ": {}", err),
         }
@@ -284,19 +605,48 @@ impl fmt::Display for WebhookError {
 impl error::Error for WebhookError {
     fn description(&self) -> &str {
         match *self {
-            WebhookError::BadHeader(_) => "error parsing timestamp",
+            WebhookError::MissingTimestamp => "timestamp missing from signature header",
+            WebhookError::MissingSignature => "signature missing from signature header",
+            WebhookError::MalformedHeader(_) => "error parsing signature header",
             WebhookError::BadSignature => "error comparing signatures",
             WebhookError::BadTimestamp(_) => "error comparing timestamps - over tolerance",
             WebhookError::BadParse(_) => "error parsing event object",
         }
     }
 
-    fn cause(&self) -> Option<&error::Error> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
-            WebhookError::BadHeader(ref err) => Some(err),
+            WebhookError::MissingTimestamp => None,
+            WebhookError::MissingSignature => None,
+            WebhookError::MalformedHeader(_) => None,
             WebhookError::BadSignature => None,
             WebhookError::BadTimestamp(_) => None,
             WebhookError::BadParse(ref err) => Some(err),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{json_path, Error, WebhookError};
+    use std::mem;
+
+    #[test]
+    fn error_stays_small() {
+        // NOTE: Clippy's `result_large_err` lint fires above 128 bytes
+        assert!(mem::size_of::<Error>() <= 64, "Error is {} bytes", mem::size_of::<Error>());
+    }
+
+    #[test]
+    fn json_path_of_nested_value() {
+        assert_eq!(json_path(br#"{"data": [{"id": "ch_1"}, {"id": "ch_2", "source": {"exp_month": "#), "data[1].source.exp_month");
+        assert_eq!(json_path(br#"{"id": "a,b:{c", "amount": "#), "amount");
+        assert_eq!(json_path(br#"{"list": [1, 2, "#), "list[2]");
+    }
+
+    #[test]
+    fn display_webhook_errors() {
+        assert_eq!(WebhookError::BadSignature.to_string(), "error comparing signatures: signatures do not match");
+        assert_eq!(WebhookError::MissingSignature.to_string(), "signature missing from signature header");
+    }
+}
@@ -48,6 +48,10 @@
 //! let charges = stripe::Charge::list(&client, params).unwrap();
 //! println!("{:?}", charges); // =>  List { data: [Charge { id: "ch_12345", .. }] }
 //! ```
+//!
+//! ## Optional Features
+//!
+//! - `extra-fields`: keep fields which this crate doesn't know about yet in an `extra` map on each object.
 
 extern crate chrono;
 extern crate hmac;
@@ -63,14 +67,27 @@ extern crate serde_json;
 extern crate serde_qs;
 extern crate sha2;
 
+#[macro_use]
+mod macros;
+
 mod client;
+mod dispatcher;
 mod error;
 mod ids;
+mod money;
 mod resources;
 mod params;
+mod path;
+mod poller;
 
-pub use client::{Client, Params};
-pub use error::{Error, ErrorCode, ErrorType, RequestError};
+pub use client::{Auth, Client, ClientStats, Params, Profile};
+pub use dispatcher::{DispatchStats, EventDispatcher, HandlerError};
+pub use error::{DeclineCode, Error, ErrorCode, ErrorType, RequestError, Violation, WebhookError};
 pub use ids::{SourceId, TokenId};
-pub use params::{List, RangeQuery, RangeBounds, Metadata, Timestamp};
+pub use money::Money;
+#[cfg(feature = "extra-fields")]
+pub use params::ExtraFields;
+pub use params::{to_datetime, to_form, to_timestamp, Expand, Expandable, List, ListPageParams, Paginator, RangeQuery, RangeBounds, Metadata, Timestamp, UpdateValue, Validate};
+pub use path::Path;
+pub use poller::{CursorStore, EventPoller, FileCursorStore, MemoryCursorStore};
 pub use resources::*;
@@ -0,0 +1,78 @@
+/// Defines an enum for a string field with a known set of values, like a status.
+///
+/// Values which aren't known (eg. because Stripe added them after this crate was released)
+/// are kept in an `Other` variant instead of failing to deserialize.
+macro_rules! string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident => $value:tt,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+        pub enum $name {
+            $($(#[$variant_attr])* $variant,)*
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $($name::$variant => $value,)*
+                    $name::Other(ref value) => value,
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> $name {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(value.to_string()),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+                let value: String = ::serde::Deserialize::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    }
+}
+
+/// Implements builder-style setters for the optional fields of a params struct,
+/// eg. `ChargeParams::new(1000, Currency::USD).customer(id).capture(false)`.
+///
+/// Each setter sets its field to `Some(value)`, or `UpdateValue::Set(value)` for `UpdateValue` fields.
+/// The setters of `UpdateValue` lists are written by hand, so an empty list clears the field.
+macro_rules! setters {
+    (
+        $name:ident $(<$lt:lifetime>)* {
+            $($field:ident: $ty:ty,)*
+        }
+    ) => {
+        impl<$($lt),*> $name<$($lt),*> {
+            $(
+                pub fn $field(mut self, $field: $ty) -> Self {
+                    self.$field = Some($field).into();
+                    self
+                }
+            )*
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use resources::{format_amount, Currency};
+use std::fmt;
+
+/// An amount of money, in the minor unit of its currency (eg. cents for `USD`).
+///
+/// Arithmetic is checked, and only defined between amounts of the same currency.
+///
+/// Resources and params keep Stripe's raw integer amount fields; use `Money::from_unsigned`
+/// to convert one together with its currency, eg. when totalling amounts.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub struct Money {
+    pub amount: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: i64, currency: Currency) -> Money {
+        Money { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Money {
+        Money { amount: 0, currency }
+    }
+
+    /// Converts an unsigned amount as returned by most Stripe resources.
+    ///
+    /// Returns `None` if the amount doesn't fit in an `i64`.
+    pub fn from_unsigned(amount: u64, currency: Currency) -> Option<Money> {
+        if amount > i64::max_value() as u64 {
+            None
+        } else {
+            Some(Money { amount: amount as i64, currency })
+        }
+    }
+
+    /// Returns the amount as an unsigned integer, as expected by params like `ChargeParams::amount`.
+    ///
+    /// Returns `None` if the amount is negative.
+    pub fn unsigned_amount(&self) -> Option<u64> {
+        if self.amount < 0 {
+            None
+        } else {
+            Some(self.amount as u64)
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    /// Adds two amounts, returning `None` if the currencies differ or the result overflows.
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.amount.checked_add(other.amount).map(|amount| Money { amount, currency: self.currency })
+    }
+
+    /// Subtracts two amounts, returning `None` if the currencies differ or the result overflows.
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.amount.checked_sub(other.amount).map(|amount| Money { amount, currency: self.currency })
+    }
+
+    /// Multiplies the amount, eg. by a quantity, returning `None` if the result overflows.
+    pub fn checked_mul(self, factor: i64) -> Option<Money> {
+        self.amount.checked_mul(factor).map(|amount| Money { amount, currency: self.currency })
+    }
+
+    /// Negates the amount, returning `None` if the result overflows.
+    pub fn checked_neg(self) -> Option<Money> {
+        self.amount.checked_neg().map(|amount| Money { amount, currency: self.currency })
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&format_amount(self.amount, self.currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Money;
+    use resources::Currency;
+
+    #[test]
+    fn checked_arithmetic() {
+        let usd = Money::new(1050, Currency::USD);
+        assert_eq!(usd.checked_add(usd), Some(Money::new(2100, Currency::USD)));
+        assert_eq!(usd.checked_sub(Money::new(2000, Currency::USD)), Some(Money::new(-950, Currency::USD)));
+        assert_eq!(usd.checked_add(Money::new(1, Currency::EUR)), None);
+        assert_eq!(Money::new(i64::max_value(), Currency::USD).checked_add(usd), None);
+        assert_eq!(usd.checked_mul(3), Some(Money::new(3150, Currency::USD)));
+        assert_eq!(Money::from_unsigned(u64::max_value(), Currency::USD), None);
+        assert_eq!(Money::new(-1, Currency::USD).unsigned_amount(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Money::new(-1050, Currency::USD).to_string(), "-10.50 USD");
+        assert_eq!(Money::new(500, Currency::JPY).to_string(), "500 JPY");
+    }
+}
@@ -1,4 +1,27 @@
-use std::collections::HashMap;
+use chrono::{DateTime, TimeZone};
+use client::Client;
+use error::{Error, Violation};
+use resources::Object;
+use serde::de::DeserializeOwned;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_qs as qs;
+use std::collections::hash_map::{HashMap, Iter};
+use std::iter::FromIterator;
+use std::str::FromStr;
+use std::vec;
+
+/// Encodes request parameters the way Stripe expects them, for a request body or query.
+///
+/// Fields of nested structs and maps are encoded as `parent[field]=value`, and items of lists
+/// by their index, so `items: vec![ItemParams { plan: Some("gold"), .. }]` becomes `items[0][plan]=gold`
+/// and `expand: &["customer"]` becomes `expand[0]=customer`.
+///
+/// An empty list has no items to encode, so it is dropped. To clear a list, use `UpdateValue::Clear`,
+/// which is sent as an empty string; converting an empty `Vec` to an `UpdateValue` gives `Clear`.
+pub fn to_form<P: Serialize>(params: &P) -> Result<String, Error> {
+    Ok(qs::to_string(params)?)
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct List<T> {
@@ -8,9 +31,392 @@ pub struct List<T> {
     pub url: String,
 }
 
-pub type Metadata = HashMap<String, String>;
+/// The cursor params common to every list request, eg. to fetch a page of a list with `Client::get_list_page`.
+#[derive(Debug, Default, Serialize)]
+pub struct ListPageParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<T> List<T> {
+    /// The number of objects fetched in this page of the list.
+    pub fn total_fetched(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl<T: DeserializeOwned + Object> List<T> {
+    /// Retrieves the next page of the list, or `None` if this was the last page.
+    ///
+    /// This is meant for lists embedded in another object (eg. `Subscription::items`),
+    /// since the filters of a list request aren't included in its `url`.
+    pub fn retrieve_next(&self, client: &Client) -> Result<Option<List<T>>, Error> {
+        match self.data.last() {
+            Some(last) if self.has_more => {
+                let params = ListPageParams { starting_after: Some(last.id()), ..Default::default() };
+                client.get_list_page(&self.url, params).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Fetches all remaining pages of the list, returning every object of the list.
+    ///
+    /// This is meant to complete lists embedded in another object (eg. `ApplicationFee::refunds`).
+    pub fn get_all(self, client: &Client) -> Result<Vec<T>, Error> {
+        self.paginate(client, ListPageParams::default())?.collect()
+    }
+
+    /// Returns an iterator over every object of the list, lazily fetching the following pages.
+    ///
+    /// Stripe doesn't include the filters of a list request in its `url`, so the params
+    /// used to fetch this list must be passed again (their `limit` sets the page size).
+    /// If the params have an `ending_before` cursor the list is paged backwards, towards
+    /// newer objects, otherwise it is paged forwards.
+    ///
+    /// The client is blocking, so this is a plain `Iterator` which fetches each page when
+    /// the previous one has been consumed, rather than a `Stream` prefetching pages.
+    ///
+    /// ```rust,ignore
+    /// let params = || stripe::ChargeListParams { customer: Some("cus_123"), ..Default::default() };
+    /// let charges = stripe::Charge::list(&client, params())?;
+    /// for charge in charges.paginate(&client, params())? {
+    ///     println!("{:?}", charge?);
+    /// }
+    /// ```
+    pub fn paginate<'a, P: Serialize>(self, client: &'a Client, params: P) -> Result<Paginator<'a, T>, Error> {
+        let query = to_form(&params)?;
+        let mut pairs = query.split('&').filter(|pair| !pair.is_empty()).collect::<Vec<_>>();
+        let backward = pairs.iter().any(|pair| pair.starts_with("ending_before="));
+        pairs.retain(|pair| !pair.starts_with("starting_after=") && !pair.starts_with("ending_before="));
+
+        let url = match (pairs.is_empty(), self.url.contains('?')) {
+            (true, _) => self.url,
+            (false, true) => format!("{}&{}", self.url, pairs.join("&")),
+            (false, false) => format!("{}?{}", self.url, pairs.join("&")),
+        };
+        let cursor = if backward { self.data.first() } else { self.data.last() };
+        Ok(Paginator {
+            client: client,
+            url: url,
+            backward: backward,
+            cursor: cursor.map(|obj| obj.id().to_string()),
+            page: self.data.into_iter(),
+            has_more: self.has_more,
+        })
+    }
+}
+
+/// An iterator over every object of a list, see `List::paginate`.
+///
+/// Pages are only fetched once the objects of the previous page have been consumed,
+/// so iterating over a long list doesn't keep all of its objects in memory.
+pub struct Paginator<'a, T> {
+    client: &'a Client,
+    url: String,
+    backward: bool,
+    cursor: Option<String>,
+    page: vec::IntoIter<T>,
+    has_more: bool,
+}
+
+impl<'a, T: DeserializeOwned + Object> Paginator<'a, T> {
+    fn next_page(&mut self) -> Result<(), Error> {
+        self.has_more = false;
+        let cursor = match self.cursor.take() {
+            Some(cursor) => cursor,
+            None => return Ok(()),
+        };
+        let params = if self.backward {
+            ListPageParams { ending_before: Some(&cursor), ..Default::default() }
+        } else {
+            ListPageParams { starting_after: Some(&cursor), ..Default::default() }
+        };
+        let list: List<T> = self.client.get_list_page(&self.url, params)?;
+        let next = if self.backward { list.data.first() } else { list.data.last() };
+        self.cursor = next.map(|obj| obj.id().to_string());
+        self.has_more = list.has_more;
+        self.page = list.data.into_iter();
+        Ok(())
+    }
+}
+
+impl<'a, T: DeserializeOwned + Object> Iterator for Paginator<'a, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Result<T, Error>> {
+        if let Some(obj) = self.page.next() {
+            return Some(Ok(obj));
+        }
+        if !self.has_more {
+            return None;
+        }
+        match self.next_page() {
+            Ok(()) => self.page.next().map(Ok),
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// A field which is either an id or, if it was requested with `expand`, the full object.
+///
+/// For more details see https://stripe.com/docs/api/expanding_objects.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Expandable<T> {
+    Id(String),
+    Object(Box<T>),
+}
+
+impl<T> Expandable<T> {
+    pub fn is_object(&self) -> bool {
+        match *self {
+            Expandable::Id(_) => false,
+            Expandable::Object(_) => true,
+        }
+    }
+
+    /// Returns the expanded object, if the field was expanded.
+    pub fn as_object(&self) -> Option<&T> {
+        match *self {
+            Expandable::Id(_) => None,
+            Expandable::Object(ref obj) => Some(obj),
+        }
+    }
+}
+
+/// Request parameters which can be checked for mistakes Stripe would reject, without making a request.
+///
+/// It is implemented by the params of `Charge::create`, `PaymentIntent::create`, `Payout::create`,
+/// `Subscription::create` and `Transfer::create`, which validate them before sending the request
+/// so mistakes don't burn API calls (or rate limit) in live mode. Other params are sent unchecked.
+pub trait Validate {
+    /// The problems with the params, if any.
+    fn violations(&self) -> Vec<Violation>;
+
+    /// Fails with `Error::InvalidParams` if the params have any problems.
+    fn validate(&self) -> Result<(), Error> {
+        let violations = self.violations();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidParams(violations))
+        }
+    }
+}
+
+/// The generic request parameter used to expand fields of a response.
+#[derive(Debug, Default, Serialize)]
+pub struct Expand<'a> {
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+/// A set of key-value pairs that can be attached to an object.
+///
+/// When sent in update params, a key with an empty value deletes that key from the object,
+/// see `Metadata::unset`. Keys which aren't included are left unchanged.
+///
+/// For more details see https://stripe.com/docs/api/metadata.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Metadata(HashMap<String, String>);
+
+impl Metadata {
+    pub fn new() -> Metadata {
+        Metadata(HashMap::new())
+    }
+
+    /// Returns the value of a key, or `None` if it isn't set or is marked for deletion.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        match self.0.get(key) {
+            Some(value) if !value.is_empty() => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a key parsed as `T`, eg. an integer id stored by your application.
+    pub fn get_parsed<T: FromStr>(&self, key: &str) -> Option<Result<T, T::Err>> {
+        self.get(key).map(str::parse)
+    }
+
+    /// Sets the value of a key.
+    pub fn set<K: Into<String>, V: ToString>(&mut self, key: K, value: V) -> &mut Metadata {
+        self.0.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Marks a key to be deleted from the object when these params are sent with an update.
+    pub fn unset<K: Into<String>>(&mut self, key: K) -> &mut Metadata {
+        self.0.insert(key.into(), String::new());
+        self
+    }
+
+    /// Removes a key from this map, so that it is left unchanged by an update.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter<'a>(&'a self) -> Iter<'a, String, String> {
+        self.0.iter()
+    }
+}
+
+impl From<HashMap<String, String>> for Metadata {
+    fn from(map: HashMap<String, String>) -> Metadata {
+        Metadata(map)
+    }
+}
+
+impl From<Metadata> for HashMap<String, String> {
+    fn from(metadata: Metadata) -> HashMap<String, String> {
+        metadata.0
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Metadata {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Metadata {
+        Metadata(iter.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Metadata {
+    type Item = (&'a String, &'a String);
+    type IntoIter = Iter<'a, String, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Serialize for Metadata {
+    /// Serializes the keys in order, so they are encoded as `metadata[key]=value` in a stable order.
+    ///
+    /// Fails for empty keys or keys containing `[` or `]`, which Stripe doesn't allow
+    /// and which would otherwise be encoded as a nested parameter.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeMap};
+
+        let mut keys = self.0.keys().collect::<Vec<_>>();
+        keys.sort();
+        let mut map = serializer.serialize_map(Some(keys.len()))?;
+        for key in keys {
+            if key.is_empty() || key.contains('[') || key.contains(']') {
+                return Err(S::Error::custom(format!("invalid metadata key {:?}", key)));
+            }
+            map.serialize_entry(key, &self.0[key])?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Metadata {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Metadata, D::Error> {
+        // NOTE: Some objects return `null` rather than an empty map
+        Option::<HashMap<String, String>>::deserialize(deserializer).map(|map| Metadata(map.unwrap_or_default()))
+    }
+}
+
+/// The fields of an object which aren't (yet) known to this crate, with the `extra-fields` feature.
+///
+/// These are kept when an object is deserialized, and written back out if it is serialized again.
+#[cfg(feature = "extra-fields")]
+pub type ExtraFields = ::serde_json::Map<String, ::serde_json::Value>;
+
+/// A unix timestamp, in seconds.
+///
+/// Use `to_datetime` to convert a timestamp to a `chrono::DateTime<Utc>`.
 pub type Timestamp = i64;
 
+/// Converts a date and time to a `Timestamp`, eg. for the bounds of a `created` filter.
+pub fn to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Timestamp {
+    datetime.timestamp()
+}
+
+/// Converts a `Timestamp` to a date and time in UTC, or `None` if it is out of range.
+pub fn to_datetime(timestamp: Timestamp) -> Option<DateTime<::chrono::Utc>> {
+    ::chrono::Utc.timestamp_opt(timestamp, 0).single()
+}
+
+/// A field of update params which can be left unchanged, cleared, or set to a new value.
+///
+/// Stripe clears a field when it is sent as an empty string, which can't be expressed
+/// with an `Option`. Fields using `UpdateValue` must be skipped when `Keep`, with
+/// `#[serde(skip_serializing_if = "UpdateValue::is_keep")]`: serializing `Keep` is an error,
+/// rather than clearing the field.
+///
+/// A list is cleared with `Clear`, since `Set` with an empty list has no items to encode.
+/// The setters of list fields convert the list with `From<Vec<T>>`, which takes care of that.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UpdateValue<T> {
+    Keep,
+    Clear,
+    Set(T),
+}
+
+impl<T> UpdateValue<T> {
+    pub fn is_keep(&self) -> bool {
+        match *self {
+            UpdateValue::Keep => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> Default for UpdateValue<T> {
+    fn default() -> Self {
+        UpdateValue::Keep
+    }
+}
+
+impl<T> From<Option<T>> for UpdateValue<T> {
+    /// Converts `Some(value)` to `Set(value)` and `None` to `Keep`, like an optional field of params.
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => UpdateValue::Set(value),
+            None => UpdateValue::Keep,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for UpdateValue<Vec<T>> {
+    /// Converts an empty list to `Clear`, which is how setting an empty list is sent anyway.
+    fn from(values: Vec<T>) -> Self {
+        if values.is_empty() {
+            UpdateValue::Clear
+        } else {
+            UpdateValue::Set(values)
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for UpdateValue<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+
+        match *self {
+            UpdateValue::Set(ref value) => value.serialize(serializer),
+            UpdateValue::Clear => serializer.serialize_str(""),
+            UpdateValue::Keep => Err(S::Error::custom("`UpdateValue::Keep` must be skipped with `skip_serializing_if`")),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub struct RangeBounds<T> {
@@ -63,16 +469,31 @@ impl<T> RangeQuery<T> {
     /// Filter results to be before to a given value
     pub fn lt(value: T) -> RangeQuery<T> {
         let mut bounds = RangeBounds::default();
-        bounds.gt = Some(value);
+        bounds.lt = Some(value);
         RangeQuery::Bounds(bounds)
     }
 
     /// Filter results to be before or equal to a given value
     pub fn lte(value: T) -> RangeQuery<T> {
         let mut bounds = RangeBounds::default();
-        bounds.gte = Some(value);
+        bounds.lte = Some(value);
         RangeQuery::Bounds(bounds)
     }
+
+    /// Filter results to be after or equal to `start` and before `end`
+    pub fn between(start: T, end: T) -> RangeQuery<T> {
+        let mut bounds = RangeBounds::default();
+        bounds.gte = Some(start);
+        bounds.lt = Some(end);
+        RangeQuery::Bounds(bounds)
+    }
+}
+
+impl RangeQuery<Timestamp> {
+    /// Filter results to be after or equal to `start` and before `end`, eg. to list the objects created on a day
+    pub fn between_datetimes<Tz: TimeZone>(start: &DateTime<Tz>, end: &DateTime<Tz>) -> RangeQuery<Timestamp> {
+        RangeQuery::between(to_timestamp(start), to_timestamp(end))
+    }
 }
 
 // NOTE: Only intended to handle conversion from ASCII CamelCase to SnakeCase
@@ -99,6 +520,123 @@ pub fn to_snakecase(camel: &str) -> String {
 
 #[cfg(test)]
 mod tests {
+    use super::{List, ListPageParams};
+    use client::testing::stub;
+    use error::Error;
+    use resources::Object;
+    use serde_json as json;
+
+    #[test]
+    fn metadata_unset() {
+        use super::Metadata;
+
+        let mut metadata = Metadata::new();
+        metadata.set("order_id", 42).unset("note");
+        assert_eq!(metadata.get_parsed::<u64>("order_id"), Some(Ok(42)));
+        assert_eq!(metadata.get("note"), None);
+        assert!(metadata.contains_key("note"));
+    }
+
+    #[test]
+    fn to_datetime() {
+        use chrono::{TimeZone, Utc};
+
+        assert_eq!(super::to_datetime(1504233902), Some(Utc.ymd(2017, 9, 1).and_hms(2, 45, 2)));
+        assert_eq!(super::to_datetime(::std::i64::MAX), None);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Obj {
+        id: String,
+    }
+
+    impl Object for Obj {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn object(&self) -> &'static str {
+            "obj"
+        }
+    }
+
+    fn page(ids: &[&str], has_more: bool, url: &str) -> String {
+        let data = ids.iter().map(|id| format!("{{\"id\": \"{}\"}}", id)).collect::<Vec<_>>().join(", ");
+        format!("{{\"data\": [{}], \"has_more\": {}, \"url\": \"{}\"}}", data, has_more, url)
+    }
+
+    fn list(ids: &[&str], url: &str) -> List<Obj> {
+        json::from_str(&page(ids, true, url)).unwrap()
+    }
+
+    fn ids(objs: Vec<Result<Obj, Error>>) -> Vec<String> {
+        objs.into_iter().map(|obj| obj.unwrap().id).collect()
+    }
+
+    #[test]
+    fn retrieve_next_page() {
+        let (client, paths) = stub(1, |_| (200, page(&["obj_3"], false, "/v1/objs")));
+        let next = list(&["obj_1", "obj_2"], "/v1/objs").retrieve_next(&client).unwrap().unwrap();
+        assert_eq!(next.total_fetched(), 1);
+        assert!(next.retrieve_next(&client).unwrap().is_none());
+        assert_eq!(paths.join().unwrap(), vec!["/v1/objs?starting_after=obj_2"]);
+    }
+
+    #[test]
+    fn get_all_fetches_every_page() {
+        let (client, paths) = stub(2, |path| match path {
+            "/v1/objs?starting_after=obj_2" => (200, page(&["obj_3"], true, "/v1/objs")),
+            _ => (200, page(&["obj_4"], false, "/v1/objs")),
+        });
+        let objs = list(&["obj_1", "obj_2"], "/v1/objs").get_all(&client).unwrap();
+        assert_eq!(objs.iter().map(|obj| obj.id.as_str()).collect::<Vec<_>>(), vec!["obj_1", "obj_2", "obj_3", "obj_4"]);
+        assert_eq!(paths.join().unwrap(), vec!["/v1/objs?starting_after=obj_2", "/v1/objs?starting_after=obj_3"]);
+
+        let (client, _) = stub(1, |_| (500, r#"{"error": {"type": "api_error", "message": "oops"}}"#.to_string()));
+        assert!(list(&["obj_1"], "/v1/objs").get_all(&client).is_err());
+    }
+
+    #[test]
+    fn paginate_fetches_every_page() {
+        let (client, paths) = stub(2, |path| match path {
+            "/v1/objs?limit=2&starting_after=obj_2" => (200, page(&["obj_3", "obj_4"], true, "/v1/objs")),
+            _ => (200, page(&["obj_5"], false, "/v1/objs")),
+        });
+        let params = ListPageParams { limit: Some(2), starting_after: Some("obj_0"), ..Default::default() };
+        let objs = list(&["obj_1", "obj_2"], "/v1/objs").paginate(&client, params).unwrap().collect();
+        assert_eq!(ids(objs), vec!["obj_1", "obj_2", "obj_3", "obj_4", "obj_5"]);
+        assert_eq!(paths.join().unwrap(), vec!["/v1/objs?limit=2&starting_after=obj_2", "/v1/objs?limit=2&starting_after=obj_4"]);
+    }
+
+    #[test]
+    fn paginate_backwards() {
+        let (client, paths) = stub(1, |_| (200, page(&["obj_1", "obj_2"], false, "/v1/objs")));
+        let params = ListPageParams { ending_before: Some("obj_5"), ..Default::default() };
+        let objs = list(&["obj_3", "obj_4"], "/v1/objs").paginate(&client, params).unwrap().collect();
+        assert_eq!(ids(objs), vec!["obj_3", "obj_4", "obj_1", "obj_2"]);
+        assert_eq!(paths.join().unwrap(), vec!["/v1/objs?ending_before=obj_3"]);
+    }
+
+    #[test]
+    fn paginate_url_with_query() {
+        let url = "/v1/invoices/upcoming/lines?customer=cus_123";
+        let (client, paths) = stub(1, move |_| (200, page(&["il_3"], false, url)));
+        let params = ListPageParams { limit: Some(2), ..Default::default() };
+        let objs = list(&["il_1", "il_2"], url).paginate(&client, params).unwrap().collect();
+        assert_eq!(ids(objs), vec!["il_1", "il_2", "il_3"]);
+        assert_eq!(paths.join().unwrap(), vec!["/v1/invoices/upcoming/lines?customer=cus_123&limit=2&starting_after=il_2"]);
+    }
+
+    #[test]
+    fn paginate_stops_after_an_error() {
+        let (client, paths) = stub(1, |_| (500, r#"{"error": {"type": "api_error", "message": "oops"}}"#.to_string()));
+        let mut objs = list(&["obj_1"], "/v1/objs").paginate(&client, ListPageParams::default()).unwrap();
+        assert_eq!(objs.next().unwrap().unwrap().id, "obj_1");
+        assert_eq!(objs.next().unwrap().unwrap_err().request_error().map(|err| err.http_status), Some(500));
+        assert!(objs.next().is_none());
+        assert_eq!(paths.join().unwrap().len(), 1);
+    }
+
     #[test]
     fn to_snakecase() {
         use super::to_snakecase;
@@ -0,0 +1,93 @@
+use error::Error;
+use std::fmt;
+use std::ops::Deref;
+
+/// The path of an API request, eg. `/customers/cus_123/sources`.
+///
+/// Ids are percent-encoded, and empty ids are rejected, so a missing id can't
+/// turn `/customers/{}/sources` into a request for `/customers//sources`.
+///
+/// ```rust
+/// # fn main() -> Result<(), stripe::Error> {
+/// let path = stripe::Path::new("/customers").id("cus_123")?.segment("sources");
+/// assert_eq!(&*path, "/customers/cus_123/sources");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Path(String);
+
+impl Path {
+    /// Starts a path at the given resource, eg. `/customers`.
+    pub fn new(resource: &'static str) -> Path {
+        debug_assert!(resource.starts_with('/'), "paths must start with a `/`");
+        Path(resource.to_string())
+    }
+
+    /// Appends a fixed segment, eg. `sources`.
+    pub fn segment(mut self, segment: &'static str) -> Path {
+        self.0.push('/');
+        self.0.push_str(segment);
+        self
+    }
+
+    /// Appends the id of an object.
+    ///
+    /// Fails with `Error::Config` if `id` is empty.
+    pub fn id(mut self, id: &str) -> Result<Path, Error> {
+        if id.is_empty() {
+            return Err(Error::Config(format!("empty id in request path `{}/`", self.0)));
+        }
+        self.0.push('/');
+        for byte in id.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => self.0.push(byte as char),
+                _ => self.0.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+        Ok(self)
+    }
+
+    /// Appends a query string (eg. from `to_form`), if it isn't empty.
+    pub fn query(mut self, query: &str) -> Path {
+        if !query.is_empty() {
+            self.0.push('?');
+            self.0.push_str(query);
+        }
+        self
+    }
+}
+
+impl Deref for Path {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Path;
+    use error::Error;
+
+    #[test]
+    fn encodes_ids() {
+        let path = Path::new("/customers").id("cus_1").unwrap().segment("tax_ids").id("a/b c").unwrap().query("limit=3");
+        assert_eq!(&*path, "/customers/cus_1/tax_ids/a%2Fb%20c?limit=3");
+    }
+
+    #[test]
+    fn rejects_empty_ids() {
+        match Path::new("/customers").id("") {
+            Err(Error::Config(message)) => assert!(message.contains("empty id"), "{}", message),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use client::Client;
+use dispatcher::HandlerError;
+use error::Error;
+use params::List;
+use resources::{Event, EventListParams, EventType};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// Where an `EventPoller` keeps the id of the last event it handled.
+pub trait CursorStore {
+    /// The id of the last handled event, or `None` if no event was handled yet.
+    fn load(&mut self) -> Result<Option<String>, Error>;
+
+    /// Stores the id of the last handled event.
+    fn save(&mut self, event_id: &str) -> Result<(), Error>;
+}
+
+/// Keeps the cursor in memory, so polling restarts from scratch with the process.
+#[derive(Debug, Default)]
+pub struct MemoryCursorStore {
+    cursor: Option<String>,
+}
+
+impl MemoryCursorStore {
+    pub fn new() -> MemoryCursorStore {
+        MemoryCursorStore::default()
+    }
+}
+
+impl CursorStore for MemoryCursorStore {
+    fn load(&mut self) -> Result<Option<String>, Error> {
+        Ok(self.cursor.clone())
+    }
+
+    fn save(&mut self, event_id: &str) -> Result<(), Error> {
+        self.cursor = Some(event_id.to_string());
+        Ok(())
+    }
+}
+
+/// Keeps the cursor in a file, so polling resumes where it stopped after a restart.
+#[derive(Debug)]
+pub struct FileCursorStore {
+    path: PathBuf,
+}
+
+impl FileCursorStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> FileCursorStore {
+        FileCursorStore { path: path.into() }
+    }
+}
+
+impl CursorStore for FileCursorStore {
+    fn load(&mut self) -> Result<Option<String>, Error> {
+        let mut cursor = String::new();
+        match fs::File::open(&self.path) {
+            Ok(mut file) => file.read_to_string(&mut cursor)?,
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let cursor = cursor.trim();
+        Ok(if cursor.is_empty() { None } else { Some(cursor.to_string()) })
+    }
+
+    fn save(&mut self, event_id: &str) -> Result<(), Error> {
+        // Write then rename, so a crash can't leave a truncated cursor behind
+        let tmp = self.path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(event_id.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Consumes events by polling the events list, for deployments which can't receive webhooks.
+///
+/// Events are handed out oldest first, and the cursor only moves past an event once it is acknowledged,
+/// so an event is handled at least once even if the process stops in the middle of a batch.
+///
+/// Without a stored cursor, the poller starts with the oldest event Stripe still has (up to 30 days old).
+///
+/// ```rust,ignore
+/// let mut poller = stripe::EventPoller::new(&client, stripe::FileCursorStore::new("stripe-events.cursor"));
+/// poller.run(Duration::from_secs(30), |event| dispatcher.dispatch(event))?;
+/// ```
+pub struct EventPoller<'a, S> {
+    client: &'a Client,
+    store: S,
+    types: Option<Vec<EventType>>,
+}
+
+impl<'a, S: CursorStore> EventPoller<'a, S> {
+    pub fn new(client: &'a Client, store: S) -> EventPoller<'a, S> {
+        EventPoller { client, store, types: None }
+    }
+
+    /// Only polls the events of the given types.
+    pub fn types(mut self, types: Vec<EventType>) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    /// Fetches the events created since the last acknowledged event, oldest first.
+    pub fn poll(&mut self) -> Result<Vec<Event>, Error> {
+        let cursor = self.store.load()?;
+        let mut events = Vec::new();
+        match cursor {
+            // NOTE: `ending_before` pages go forward in time, but each page is still sorted most recent first
+            Some(mut cursor) => loop {
+                let mut page = self.list(Some(&cursor), None)?;
+                let has_more = page.has_more;
+                match page.data.first() {
+                    Some(newest) => cursor = newest.id.clone(),
+                    None => break,
+                }
+                page.data.reverse();
+                events.extend(page.data);
+                if !has_more {
+                    break;
+                }
+            },
+            None => {
+                let mut starting_after = None;
+                loop {
+                    let page = self.list(None, starting_after.as_ref().map(String::as_str))?;
+                    starting_after = page.data.last().map(|event| event.id.clone());
+                    events.extend(page.data);
+                    if !page.has_more || starting_after.is_none() {
+                        break;
+                    }
+                }
+                events.reverse();
+            }
+        }
+        Ok(events)
+    }
+
+    /// Moves the cursor past `event`, once it has been handled.
+    pub fn ack(&mut self, event: &Event) -> Result<(), Error> {
+        self.store.save(&event.id)
+    }
+
+    /// Polls for new events every `interval`, handing each of them to `handle` and acknowledging it.
+    ///
+    /// If `handle` fails, the following events aren't handled and the failed event
+    /// is retried on the next poll. This only returns if polling or storing the cursor fails.
+    pub fn run<F>(&mut self, interval: Duration, mut handle: F) -> Result<(), Error>
+        where F: FnMut(&Event) -> Result<(), HandlerError>
+    {
+        loop {
+            for event in self.poll()? {
+                if handle(&event).is_err() {
+                    break;
+                }
+                self.ack(&event)?;
+            }
+            thread::sleep(interval);
+        }
+    }
+
+    fn list(&self, ending_before: Option<&str>, starting_after: Option<&str>) -> Result<List<Event>, Error> {
+        let params = EventListParams {
+            ending_before,
+            limit: Some(100),
+            starting_after,
+            types: self.types.clone(),
+            ..Default::default()
+        };
+        Event::list(self.client, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CursorStore, EventPoller, FileCursorStore, MemoryCursorStore};
+    use client::testing::stub;
+    use resources::{Event, EventObject, EventType};
+    use std::env;
+    use std::fs;
+
+    fn page(events: &[(&str, &str)], has_more: bool) -> String {
+        let data = events
+            .iter()
+            .map(|&(id, event_type)| {
+                let object = match event_type {
+                    "balance.available" => r#"{"object": "balance", "available": [], "livemode": false, "pending": []}"#,
+                    _ => r#"{"object": "brand_new_object", "id": "new_123"}"#,
+                };
+                format!(r#"{{"id": "{}", "type": "{}", "data": {{"object": {}}}}}"#, id, event_type, object)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(r#"{{"data": [{}], "has_more": {}, "url": "/v1/events"}}"#, data, has_more)
+    }
+
+    fn ids(events: &[Event]) -> Vec<&str> {
+        events.iter().map(|event| event.id.as_str()).collect()
+    }
+
+    #[test]
+    fn poll_without_cursor_returns_every_event_oldest_first() {
+        let (client, paths) = stub(2, |path| {
+            if path.contains("starting_after=evt_3") {
+                (200, page(&[("evt_2", "brand.new_event"), ("evt_1", "balance.available")], false))
+            } else {
+                (200, page(&[("evt_4", "balance.available"), ("evt_3", "balance.available")], true))
+            }
+        });
+        let events = EventPoller::new(&client, MemoryCursorStore::new()).poll().unwrap();
+        assert_eq!(ids(&events), vec!["evt_1", "evt_2", "evt_3", "evt_4"]);
+        assert_eq!(events[1].event_type, EventType::Other("brand.new_event".to_string()));
+        match events[1].data.object {
+            EventObject::Unknown => {}
+            ref other => panic!("expected an unknown object, got {:?}", other),
+        }
+        let paths = paths.join().unwrap();
+        assert!(!paths[0].contains("starting_after"), "{}", paths[0]);
+        assert!(paths[1].contains("starting_after=evt_3"), "{}", paths[1]);
+    }
+
+    #[test]
+    fn poll_with_cursor_moves_forward_and_ack_saves_it() {
+        let (client, paths) = stub(2, |path| {
+            if path.contains("ending_before=evt_3") {
+                (200, page(&[("evt_4", "balance.available")], false))
+            } else {
+                (200, page(&[("evt_3", "balance.available"), ("evt_2", "balance.available")], true))
+            }
+        });
+        let mut store = MemoryCursorStore::new();
+        store.save("evt_1").unwrap();
+        let mut poller = EventPoller::new(&client, store);
+        let events = poller.poll().unwrap();
+        assert_eq!(ids(&events), vec!["evt_2", "evt_3", "evt_4"]);
+        poller.ack(&events[2]).unwrap();
+        assert_eq!(poller.store.load().unwrap(), Some("evt_4".to_string()));
+        let paths = paths.join().unwrap();
+        assert!(paths[0].contains("ending_before=evt_1"), "{}", paths[0]);
+        assert!(paths[1].contains("ending_before=evt_3"), "{}", paths[1]);
+    }
+
+    #[test]
+    fn file_cursor_store_round_trip() {
+        let path = env::temp_dir().join(format!("stripe-rust-cursor-{}", ::std::process::id()));
+        let mut store = FileCursorStore::new(path.clone());
+        assert_eq!(store.load().unwrap(), None);
+        store.save("evt_1").unwrap();
+        store.save("evt_2").unwrap();
+        assert_eq!(store.load().unwrap(), Some("evt_2".to_string()));
+        fs::remove_file(path).unwrap();
+    }
+}
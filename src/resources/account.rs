@@ -1,5 +1,8 @@
-use params::{List, Metadata, Timestamp};
-use resources::BankAccount;
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Address, CapabilityStatus, Currency, Deleted, ExternalAccount, PayoutSchedule, PayoutScheduleParams, Person};
+use std::collections::HashMap;
 use serde_json as json;
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -30,49 +33,500 @@ pub struct TOSAcceptanceDetails {
     pub user_agent: Option<String>,
 }
 
-/// The set of parameters that can be used when creating an account for users.
+string_enum! {
+    /// The type of a Connect account.
+    ///
+    /// For more details see https://stripe.com/docs/connect/accounts.
+    pub enum AccountType {
+        Custom => "custom",
+        Express => "express",
+        Standard => "standard",
+    }
+}
+
+string_enum! {
+    /// The business type of a Connect account.
+    pub enum BusinessType {
+        Company => "company",
+        GovernmentEntity => "government_entity",
+        Individual => "individual",
+        NonProfit => "non_profit",
+    }
+}
+
+/// The reason given when rejecting a Connect account.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountRejectReason {
+    Fraud,
+    Other,
+    TermsOfService,
+}
+
+/// Information about the business of a Connect account, shown to its customers.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-business_profile.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BusinessProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Information about the company or business of a Connect account.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-company.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Company {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directors_provided: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owners_provided: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_id: Option<String>, // (write-only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_id_provided: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_id: Option<String>,
+}
+
+/// A date of birth.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub struct Dob {
+    pub day: u32,
+    pub month: u32,
+    pub year: u32,
+}
+
+/// The branding of an account, used by Checkout, invoices and receipts.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BrandingSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>, // (a file id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>, // (a file id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_color: Option<String>, // eg. "#ff0000"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_color: Option<String>,
+}
+
+/// The card payment settings of an account.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CardPaymentsSettings {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decline_on: Option<DeclineChargeDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_prefix: Option<String>,
+}
+
+/// The payment settings of an account.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PaymentsSettings {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_kana: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_kanji: Option<String>,
+}
+
+/// The payout settings of an account.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayoutSettings {
+    pub debit_negative_balances: bool,
+    pub schedule: PayoutSchedule,
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PayoutSettingsParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debit_negative_balances: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<PayoutScheduleParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+setters! {
+    PayoutSettingsParams<'a> {
+        debit_negative_balances: bool,
+        schedule: PayoutScheduleParams,
+        statement_descriptor: &'a str,
+    }
+}
+
+/// The settings of an account.
 ///
-/// For more details see https://stripe.com/docs/api#create_account.
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-settings.
 #[derive(Debug, Deserialize, Serialize)]
-pub struct AccountParams<'a> {
+pub struct AccountSettings {
+    #[serde(default)]
+    pub branding: Option<BrandingSettings>,
+    #[serde(default)]
+    pub card_payments: Option<CardPaymentsSettings>,
+    #[serde(default)]
+    pub payments: Option<PaymentsSettings>,
+    #[serde(default)]
+    pub payouts: Option<PayoutSettings>,
+}
+
+/// The set of parameters that can be used when updating an account's settings.
+#[derive(Debug, Default, Serialize)]
+pub struct AccountSettingsParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branding: Option<BrandingSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_payments: Option<CardPaymentsSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payments: Option<PaymentsSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payouts: Option<PayoutSettingsParams<'a>>,
+}
+
+setters! {
+    AccountSettingsParams<'a> {
+        branding: BrandingSettings,
+        card_payments: CardPaymentsSettings,
+        payments: PaymentsSettings,
+        payouts: PayoutSettingsParams<'a>,
+    }
+}
+
+/// The set of parameters that can be used to describe the individual of an `individual` account.
+///
+/// For more details see https://stripe.com/docs/api/accounts/create#create_account-individual.
+#[derive(Debug, Default, Serialize)]
+pub struct IndividualParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<Dob>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country: Option<&'a str>, // (country the account holder resides in)
+    pub id_number: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<&'a str>, // (required if account type is standard)
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+}
+
+setters! {
+    IndividualParams<'a> {
+        address: Address,
+        dob: Dob,
+        email: &'a str,
+        first_name: &'a str,
+        id_number: &'a str,
+        last_name: &'a str,
+        metadata: Metadata,
+        phone: &'a str,
+        ssn_last_4: &'a str,
+    }
+}
+
+/// The set of parameters that can be used when creating or updating an account.
+///
+/// For more details see https://stripe.com/docs/api/accounts/create and https://stripe.com/docs/api/accounts/update.
+#[derive(Debug, Default, Serialize)]
+pub struct AccountParams<'a> {
     #[serde(rename = "type")]
-    pub account_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<AccountType>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>, // (country the account holder resides in; create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_profile: Option<BusinessProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_type: Option<BusinessType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<Company>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_account: Option<&'a str>, // (a bank account or debit card token)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<IndividualParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_capabilities: Option<Vec<&'a str>>, // eg. card_payments, transfers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<AccountSettingsParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_acceptance: Option<TOSAcceptanceDetails>,
+}
+
+setters! {
+    AccountParams<'a> {
+        account_type: AccountType,
+        country: &'a str,
+        email: &'a str,
+        business_profile: BusinessProfile,
+        business_type: BusinessType,
+        company: Company,
+        default_currency: Currency,
+        external_account: &'a str,
+        individual: IndividualParams<'a>,
+        metadata: Metadata,
+        requested_capabilities: Vec<&'a str>,
+        settings: AccountSettingsParams<'a>,
+        tos_acceptance: TOSAcceptanceDetails,
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct AccountRejectParams {
+    reason: AccountRejectReason,
+}
+
+/// The set of parameters that can be used when listing connected accounts.
+///
+/// For more details see https://stripe.com/docs/api/accounts/list.
+#[derive(Debug, Default, Serialize)]
+pub struct AccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    AccountListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+string_enum! {
+    /// Why an account can't create charges or receive payouts.
+    ///
+    /// Stripe's `other` reason is kept as `Other("other")`.
+    ///
+    /// For more details see https://stripe.com/docs/connect/handling-api-verification#determine-if-verification-is-needed.
+    pub enum AccountDisabledReason {
+        ActionRequiredRequestedCapabilities => "action_required.requested_capabilities",
+        Listed => "listed",
+        PlatformPaused => "platform_paused",
+        RejectedFraud => "rejected.fraud",
+        RejectedListed => "rejected.listed",
+        RejectedOther => "rejected.other",
+        RejectedTermsOfService => "rejected.terms_of_service",
+        RequirementsPastDue => "requirements.past_due",
+        RequirementsPendingVerification => "requirements.pending_verification",
+        UnderReview => "under_review",
+    }
+}
+
+/// A requirement of an account which couldn't be verified, eg. because a document was unreadable.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountRequirementError {
+    pub code: String,
+    pub reason: String,
+    pub requirement: String,
+}
+
+/// The information Stripe still needs to verify an account.
+///
+/// Each requirement is the name of a field, e.g. `individual.verification.document`.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-requirements.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AccountRequirements {
+    #[serde(default)]
+    pub current_deadline: Option<Timestamp>,
+    #[serde(default)]
+    pub currently_due: Vec<String>,
+    #[serde(default)]
+    pub disabled_reason: Option<AccountDisabledReason>,
+    #[serde(default)]
+    pub errors: Vec<AccountRequirementError>,
+    #[serde(default)]
+    pub eventually_due: Vec<String>,
+    #[serde(default)]
+    pub past_due: Vec<String>,
+    #[serde(default)]
+    pub pending_verification: Vec<String>,
+}
+
+impl AccountRequirements {
+    /// Whether information must be provided before `current_deadline` (or already is overdue).
+    pub fn is_action_required(&self) -> bool {
+        !self.currently_due.is_empty() || !self.past_due.is_empty()
+    }
+
+    /// Whether Stripe doesn't need any further information, now or later.
+    pub fn is_complete(&self) -> bool {
+        self.currently_due.is_empty()
+            && self.eventually_due.is_empty()
+            && self.past_due.is_empty()
+            && self.pending_verification.is_empty()
+    }
 }
 
 /// The resource representing a Stripe account.
 ///
-/// For more details see https://stripe.com/docs/api#account.
+/// For more details see https://stripe.com/docs/api/accounts/object.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Account {
     pub id: String,
     pub object: String,
-    pub business_name: String,
+    #[serde(default)]
+    pub business_profile: Option<BusinessProfile>,
+    #[serde(default)]
+    pub business_type: Option<BusinessType>,
+    #[serde(default)]
+    pub capabilities: HashMap<String, CapabilityStatus>,
+    #[serde(default)]
+    pub charges_enabled: bool,
+    #[serde(default)]
+    pub company: Option<Company>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub created: Option<Timestamp>,
+    #[serde(default)]
+    pub default_currency: Option<Currency>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub details_submitted: bool,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub external_accounts: Option<List<ExternalAccount>>,
+    #[serde(default)]
+    pub future_requirements: Option<AccountRequirements>, // (requirements which will apply after an upcoming change)
+    #[serde(default)]
+    pub individual: Option<Person>,
+    #[serde(default)]
+    pub metadata: Metadata,
+    #[serde(default)]
+    pub payouts_enabled: bool,
+    #[serde(default)]
+    pub requirements: Option<AccountRequirements>,
+    #[serde(default)]
+    pub settings: Option<AccountSettings>,
+    #[serde(default)]
+    pub tos_acceptance: Option<TOSAcceptanceDetails>, // (who accepted Stripe's terms of service)
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub account_type: Option<AccountType>,
+
+    // NOTE: The following fields are only returned by older API versions.
+    #[serde(default)]
+    pub business_name: Option<String>,
+    #[serde(default)]
     pub business_url: Option<String>,
-    pub charges_enabed: bool,
-    pub country: String,
+    #[serde(default)]
     pub debit_negative_balances: Option<bool>,
+    #[serde(default)]
     pub decline_charge_on: Option<DeclineChargeDetails>,
-    pub default_currency: String,
-    pub details_submitted: bool,
-    pub display_name: String,
-    pub email: String,
-    pub external_accounts: List<BankAccount>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
     pub legal_entity: Option<json::Value>,
-    pub metadata: Metadata,
+    #[serde(default)]
     pub payout_schedule: Option<PayoutScheduleDetails>,
+    #[serde(default)]
     pub payout_statement_descriptor: Option<String>,
-    pub payouts_enabled: bool,
+    #[serde(default)]
     pub product_description: Option<String>,
-    pub statement_descriptor: String,
-    pub support_email: String,
-    pub support_phone: String,
-    pub timezone: String,
-    pub tos_acceptance: Option<TOSAcceptanceDetails>, // (who accepted Stripe's terms of service)
-    #[serde(rename = "type")]
-    pub account_type: Option<String>, // (Stripe, Custom, or Express)
+    #[serde(default)]
+    pub statement_descriptor: Option<String>,
+    #[serde(default)]
+    pub support_email: Option<String>,
+    #[serde(default)]
+    pub support_phone: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
     pub verification: Option<json::Value>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Account {
+    /// Creates a new connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/create.
+    pub fn create(client: &Client, params: AccountParams) -> Result<Account, Error> {
+        client.post("/accounts", params)
+    }
+
+    /// Retrieves the details of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str) -> Result<Account, Error> {
+        client.get(&format!("/accounts/{}", account_id))
+    }
+
+    /// Updates a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/update.
+    pub fn update(client: &Client, account_id: &str, params: AccountParams) -> Result<Account, Error> {
+        client.post(&format!("/accounts/{}", account_id), params)
+    }
+
+    /// Deletes a Custom or Express account.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/delete.
+    pub fn delete(client: &Client, account_id: &str) -> Result<Deleted<Account>, Error> {
+        client.delete(&format!("/accounts/{}", account_id))
+    }
+
+    /// Rejects a Custom or Express account, e.g. because it is suspected of fraud.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/reject.
+    pub fn reject(client: &Client, account_id: &str, reason: AccountRejectReason) -> Result<Account, Error> {
+        client.post(&format!("/accounts/{}/reject", account_id), AccountRejectParams { reason })
+    }
+
+    /// Lists all connected accounts.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/list.
+    pub fn list(client: &Client, params: AccountListParams) -> Result<List<Account>, Error> {
+        client.get(&format!("/accounts?{}", to_form(&params)?))
+    }
 }
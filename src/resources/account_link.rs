@@ -0,0 +1,62 @@
+use error::Error;
+use client::Client;
+use params::Timestamp;
+
+string_enum! {
+    /// The type of an account link.
+    pub enum AccountLinkType {
+        AccountOnboarding => "account_onboarding",
+        AccountUpdate => "account_update",
+    }
+}
+
+/// The set of parameters that can be used when creating an account link.
+///
+/// For more details see https://stripe.com/docs/api/account_links/create.
+#[derive(Debug, Serialize)]
+pub struct AccountLinkParams<'a> {
+    pub account: &'a str,
+    pub refresh_url: &'a str,
+    pub return_url: &'a str,
+    #[serde(rename = "type")]
+    pub link_type: AccountLinkType,
+}
+
+/// The resource representing a Stripe account link, a single-use url to hosted onboarding.
+///
+/// For more details see https://stripe.com/docs/api/account_links/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccountLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub expires_at: Timestamp,
+    pub url: String,
+}
+
+impl AccountLink {
+    /// Creates a new account link for a Custom or Express account.
+    ///
+    /// For more details see https://stripe.com/docs/api/account_links/create.
+    pub fn create(client: &Client, params: AccountLinkParams) -> Result<AccountLink, Error> {
+        client.post("/account_links", params)
+    }
+}
+
+/// The resource representing a Stripe login link to the Express dashboard.
+///
+/// For more details see https://stripe.com/docs/api/account/login_link.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoginLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub url: String,
+}
+
+impl LoginLink {
+    /// Creates a login link for an Express account.
+    ///
+    /// For more details see https://stripe.com/docs/api/account/create_login_link.
+    pub fn create(client: &Client, account_id: &str) -> Result<LoginLink, Error> {
+        client.post_empty(&format!("/accounts/{}/login_links", account_id))
+    }
+}
@@ -1,9 +1,80 @@
-#[derive(Debug, Deserialize, Serialize)]
+/// A postal address, shared by all resources which have one.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-billing_details-address.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
 pub struct Address {
-    pub line1: String,
-    pub line2: String,
-    pub city: String,
-    pub state: String,
-    pub postal_code: String,
-    pub country: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>, // eg. "US"
+}
+
+/// The shipping details of a customer, charge, payment intent or order.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct Shipping {
+    pub name: String,
+    pub address: Address,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>, // eg. Fedex, UPS, USPS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+}
+
+/// The billing details of a payment method, as collected at the time of a payment.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BillingDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+// NOTE: Kept so code written against the per-resource structs keeps compiling
+pub type ShippingDetails = Shipping;
+pub type CustomerShippingDetails = Shipping;
+
+impl From<Shipping> for BillingDetails {
+    /// Uses the shipping details as billing details, eg. when they are the same for a customer.
+    fn from(shipping: Shipping) -> BillingDetails {
+        BillingDetails {
+            address: Some(shipping.address),
+            email: None,
+            name: Some(shipping.name),
+            phone: shipping.phone,
+        }
+    }
+}
+
+impl BillingDetails {
+    /// Uses the billing details as shipping details.
+    ///
+    /// Returns `None` if there is no name or address, which are required for shipping.
+    pub fn to_shipping(&self) -> Option<Shipping> {
+        match (&self.name, &self.address) {
+            (&Some(ref name), &Some(ref address)) => Some(Shipping {
+                name: name.clone(),
+                address: address.clone(),
+                carrier: None,
+                phone: self.phone.clone(),
+                tracking_number: None,
+            }),
+            _ => None,
+        }
+    }
 }
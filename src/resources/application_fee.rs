@@ -1,5 +1,36 @@
-use params::{List, Timestamp};
+use error::Error;
+use client::Client;
+use money::Money;
+use params::{to_form, List, RangeQuery, Timestamp};
 use resources::{Currency, Refund};
+use std::collections::HashMap;
+
+/// The set of parameters that can be used when listing application fees.
+///
+/// For more details see https://stripe.com/docs/api/application_fees/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ApplicationFeeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ApplicationFeeListParams<'a> {
+        charge: &'a str,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
 
 /// The resource representing a Stripe application fee.
 ///
@@ -10,7 +41,7 @@ pub struct ApplicationFee {
     pub object: String,
     pub account: String,
     pub amount: u64,
-    pub amount_refunded: i64,
+    pub amount_refunded: u64,
     pub application: String,
     pub balance_transaction: String,
     pub charge: String,
@@ -20,4 +51,143 @@ pub struct ApplicationFee {
     pub originating_transaction: Option<String>,
     pub refunded: bool,
     pub refunds: List<Refund>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ApplicationFee {
+    /// Retrieves the details of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api/application_fees/retrieve.
+    pub fn retrieve(client: &Client, fee_id: &str) -> Result<ApplicationFee, Error> {
+        client.get(&format!("/application_fees/{}", fee_id))
+    }
+
+    /// Lists the application fees collected by the platform, most recent first.
+    ///
+    /// For more details see https://stripe.com/docs/api/application_fees/list.
+    pub fn list(client: &Client, params: ApplicationFeeListParams) -> Result<List<ApplicationFee>, Error> {
+        client.get(&format!("/application_fees?{}", to_form(&params)?))
+    }
+
+    /// Lists the application fees collected on a charge.
+    pub fn list_for_charge(client: &Client, charge_id: &str) -> Result<List<ApplicationFee>, Error> {
+        ApplicationFee::list(client, ApplicationFeeListParams { charge: Some(charge_id), ..Default::default() })
+    }
+
+    /// Sums the application fees created in the `created` range, per connected account and currency.
+    ///
+    /// This fetches every fee in the range, so prefer narrow ranges (eg. a day or a month).
+    pub fn summarize(client: &Client, created: RangeQuery<Timestamp>) -> Result<Vec<ApplicationFeeSummary>, Error> {
+        let params = ApplicationFeeListParams { created: Some(created), limit: Some(100), ..Default::default() };
+        let fees: List<ApplicationFee> = client.get(&format!("/application_fees?{}", to_form(&params)?))?;
+
+        let mut summaries: HashMap<(String, Currency), ApplicationFeeSummary> = HashMap::new();
+        for fee in fees.paginate(client, &params)? {
+            let fee = fee?;
+            summaries
+                .entry((fee.account.clone(), fee.currency))
+                .or_insert_with(|| ApplicationFeeSummary::new(fee.account.clone(), fee.currency))
+                .add(&fee)?;
+        }
+
+        let mut summaries: Vec<_> = summaries.into_iter().map(|(_, summary)| summary).collect();
+        summaries.sort_by(|a, b| (&a.account, a.currency.to_string()).cmp(&(&b.account, b.currency.to_string())));
+        Ok(summaries)
+    }
+}
+
+/// The application fees collected from one connected account in one currency, see `ApplicationFee::summarize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplicationFeeSummary {
+    pub account: String,
+    pub currency: Currency,
+    /// The number of fees collected.
+    pub count: u64,
+    /// The total amount of the fees.
+    pub amount: Money,
+    /// The part of `amount` which has been refunded.
+    pub amount_refunded: Money,
+}
+
+impl ApplicationFeeSummary {
+    fn new(account: String, currency: Currency) -> ApplicationFeeSummary {
+        ApplicationFeeSummary {
+            account,
+            currency,
+            count: 0,
+            amount: Money::zero(currency),
+            amount_refunded: Money::zero(currency),
+        }
+    }
+
+    fn add(&mut self, fee: &ApplicationFee) -> Result<(), Error> {
+        let add = |total: Money, amount: u64| Money::from_unsigned(amount, fee.currency).and_then(|amount| total.checked_add(amount));
+        match (add(self.amount, fee.amount), add(self.amount_refunded, fee.amount_refunded)) {
+            (Some(amount), Some(amount_refunded)) => {
+                self.count += 1;
+                self.amount = amount;
+                self.amount_refunded = amount_refunded;
+                Ok(())
+            }
+            _ => Err(Error::Conversion(format!("the total of the application fees from {} overflows", self.account).into())),
+        }
+    }
+
+    /// The amount of the fees which the platform kept, or `None` if it overflows.
+    pub fn net(&self) -> Option<Money> {
+        self.amount.checked_sub(self.amount_refunded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationFee;
+    use client::testing::stub;
+    use money::Money;
+    use params::RangeQuery;
+    use resources::Currency;
+
+    fn fee(id: &str, account: &str, currency: &str, amount: u64, amount_refunded: u64) -> String {
+        format!(
+            r#"{{"id": "{}", "object": "application_fee", "account": "{}", "amount": {}, "amount_refunded": {},
+                "application": "ca_123", "balance_transaction": "txn_123", "charge": "ch_123", "created": 1504233902,
+                "currency": "{}", "livemode": false, "originating_transaction": null, "refunded": false,
+                "refunds": {{"data": [], "has_more": false, "url": "/v1/application_fees/{}/refunds"}}}}"#,
+            id, account, amount, amount_refunded, currency, id
+        )
+    }
+
+    #[test]
+    fn summarize_groups_by_account_and_currency() {
+        let (client, paths) = stub(2, |path| {
+            let (fees, has_more) = if path.contains("starting_after=fee_3") {
+                (vec![fee("fee_4", "acct_a", "usd", 100, 0)], false)
+            } else {
+                (vec![fee("fee_1", "acct_b", "usd", 100, 0), fee("fee_2", "acct_a", "usd", 200, 50), fee("fee_3", "acct_a", "eur", 300, 0)], true)
+            };
+            (200, format!(r#"{{"data": [{}], "has_more": {}, "url": "/v1/application_fees"}}"#, fees.join(", "), has_more))
+        });
+
+        let summaries = ApplicationFee::summarize(&client, RangeQuery::between(1504224000, 1504310400)).unwrap();
+        assert_eq!(paths.join().unwrap().len(), 2);
+        let totals = summaries.iter()
+            .map(|summary| (summary.account.as_str(), summary.currency, summary.count, summary.amount, summary.net()))
+            .collect::<Vec<_>>();
+        assert_eq!(totals, vec![
+            ("acct_a", Currency::EUR, 1, Money::new(300, Currency::EUR), Some(Money::new(300, Currency::EUR))),
+            ("acct_a", Currency::USD, 2, Money::new(300, Currency::USD), Some(Money::new(250, Currency::USD))),
+            ("acct_b", Currency::USD, 1, Money::new(100, Currency::USD), Some(Money::new(100, Currency::USD))),
+        ]);
+    }
+
+    #[test]
+    fn summarize_fails_on_overflow() {
+        let (client, _) = stub(1, |_| {
+            let fees = vec![fee("fee_1", "acct_a", "usd", 1 << 62, 0), fee("fee_2", "acct_a", "usd", 1 << 62, 0)];
+            (200, format!(r#"{{"data": [{}], "has_more": false, "url": "/v1/application_fees"}}"#, fees.join(", ")))
+        });
+        assert!(ApplicationFee::summarize(&client, RangeQuery::gte(1504224000)).is_err());
+    }
 }
@@ -14,4 +14,7 @@ pub struct ApplicationFeeRefund {
     pub currency: Currency,
     pub fee: String,
     pub metadata: Metadata,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
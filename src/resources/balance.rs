@@ -1,27 +1,87 @@
+use error::Error;
+use client::Client;
+use money::Money;
 use serde_json as json;
-use params::{List, Timestamp};
-use resources::{Currency, PaymentSource};
+use params::{to_form, Expandable, List, RangeQuery, Timestamp};
+use resources::{Charge, Currency, Payout, Refund, Transfer};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct FeeDetails {
     pub amount: u64,
-    pub application: String,
+    pub application: Option<String>,
     pub currency: Currency,
-    pub description: String,
+    pub description: Option<String>,
     #[serde(rename = "type")]
     pub fee_type: String, // (application_fee, stripe_fee, or tax)
 }
 
+/// The part of a balance amount coming from each type of payment source.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BalanceSourceTypes {
+    #[serde(default)]
+    pub bank_account: i64,
+    #[serde(default)]
+    pub card: i64,
+    #[serde(default)]
+    pub fpx: i64,
+}
+
+/// The amount of a balance in one currency.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BalanceAmount {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(default)]
+    pub source_types: Option<BalanceSourceTypes>,
+}
+
+impl BalanceAmount {
+    pub fn money(&self) -> Money {
+        Money::new(self.amount, self.currency)
+    }
+}
+
 /// The resource representing a Stripe account balance.
 ///
 /// For more details see https://stripe.com/docs/api#balance_object.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Balance {
+    #[serde(default)] // NOTE: Consumed as the tag when deserialized as an `EventObject`
     pub object: String,
-    pub available: Vec<json::Value>,
-    pub connect_reserved: Vec<json::Value>,
+    pub available: Vec<BalanceAmount>,
+    #[serde(default)]
+    pub connect_reserved: Vec<BalanceAmount>, // (only for platforms)
+    #[serde(default)]
+    pub instant_available: Vec<BalanceAmount>, // (only for accounts with instant payouts)
     pub livemode: bool,
-    pub pending: Vec<json::Value>,
+    pub pending: Vec<BalanceAmount>,
+}
+
+impl Balance {
+    /// Retrieves the balance of the account.
+    ///
+    /// For more details see https://stripe.com/docs/api/balance/balance_retrieve.
+    pub fn retrieve(client: &Client) -> Result<Balance, Error> {
+        client.get("/balance")
+    }
+
+    /// The funds available to be paid out in `currency`.
+    pub fn available_in(&self, currency: Currency) -> Money {
+        amount_in(&self.available, currency)
+    }
+
+    /// The funds not yet available in `currency`, eg. because the charges are still being processed.
+    pub fn pending_in(&self, currency: Currency) -> Money {
+        amount_in(&self.pending, currency)
+    }
+}
+
+fn amount_in(amounts: &[BalanceAmount], currency: Currency) -> Money {
+    amounts
+        .iter()
+        .find(|amount| amount.currency == currency)
+        .map(|amount| amount.money())
+        .unwrap_or_else(|| Money::zero(currency))
 }
 
 /// The resource representing a Stripe balance transaction.
@@ -31,16 +91,124 @@ pub struct Balance {
 pub struct BalanceTransaction {
     pub id: String,
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub available_on: Timestamp,
     pub created: Timestamp,
     pub currency: Currency,
-    pub description: String,
-    pub fee: u64,
-    pub fee_details: List<FeeDetails>,
-    pub net: u64,
-    pub source: PaymentSource,
+    pub description: Option<String>,
+    pub fee: i64,
+    pub fee_details: Vec<FeeDetails>,
+    pub net: i64,
+    pub source: Option<Expandable<BalanceTransactionSource>>,
     pub status: String,
     #[serde(rename = "type")]
     pub transaction_type: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+/// The set of parameters that can be used when listing balance transactions.
+///
+/// For more details see https://stripe.com/docs/api/balance_transactions/list.
+#[derive(Debug, Default, Serialize)]
+pub struct BalanceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout: Option<&'a str>, // (only the transactions paid out in this automatic payout)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<&'a str>,
+}
+
+setters! {
+    BalanceTransactionListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        currency: Currency,
+        ending_before: &'a str,
+        limit: u64,
+        payout: &'a str,
+        source: &'a str,
+        starting_after: &'a str,
+        transaction_type: &'a str,
+    }
+}
+
+impl BalanceTransaction {
+    /// Retrieves the details of a balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/balance_transactions/retrieve.
+    pub fn retrieve(client: &Client, transaction_id: &str) -> Result<BalanceTransaction, Error> {
+        client.get(&format!("/balance_transactions/{}", transaction_id))
+    }
+
+    /// Lists the transactions which modified the account's balance, most recent first.
+    ///
+    /// For more details see https://stripe.com/docs/api/balance_transactions/list.
+    pub fn list(client: &Client, params: BalanceTransactionListParams) -> Result<List<BalanceTransaction>, Error> {
+        client.get(&format!("/balance_transactions?{}", to_form(&params)?))
+    }
+}
+
+/// A correction of the account's balance made by Stripe, eg. for a lost dispute or a failed payout.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Adjustment {
+    pub id: String,
+    #[serde(default)]
+    pub amount: Option<i64>,
+    #[serde(default)]
+    pub created: Option<Timestamp>,
+    #[serde(default)]
+    pub currency: Option<Currency>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+/// The object which caused a balance transaction.
+///
+/// Sources other than charges, refunds, payouts, transfers and adjustments (eg. application fees)
+/// are kept as `Other` with their raw JSON.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum BalanceTransactionSource {
+    Charge(Charge),
+    Refund(Refund),
+    Payout(Payout),
+    Transfer(Transfer),
+    Adjustment(Adjustment),
+    Other(json::Value),
+}
+
+impl<'de> ::serde::Deserialize<'de> for BalanceTransactionSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::de::Deserializer<'de>
+    {
+        use serde::de::Error;
+
+        // NOTE: Not a `#[serde(tag = "object")]` enum, so that the `object` field isn't consumed
+        let value = json::Value::deserialize(deserializer)?;
+        let source = match value.get("object").and_then(|object| object.as_str()) {
+            Some("charge") => json::from_value(value).map(BalanceTransactionSource::Charge),
+            Some("refund") => json::from_value(value).map(BalanceTransactionSource::Refund),
+            Some("payout") => json::from_value(value).map(BalanceTransactionSource::Payout),
+            Some("transfer") => json::from_value(value).map(BalanceTransactionSource::Transfer),
+            Some("adjustment") => json::from_value(value).map(BalanceTransactionSource::Adjustment),
+            _ => Ok(BalanceTransactionSource::Other(value)),
+        };
+        source.map_err(D::Error::custom)
+    }
 }
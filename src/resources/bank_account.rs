@@ -1,24 +1,67 @@
+use error::Error;
+use client::Client;
 use params::Metadata;
 use resources::Currency;
 
+string_enum! {
+    /// The verification status of a bank account.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_bank_accounts/object#customer_bank_account_object-status.
+    pub enum BankAccountStatus {
+        New => "new",
+        Validated => "validated",
+        Verified => "verified",
+        VerificationFailed => "verification_failed",
+        Errored => "errored",
+    }
+}
+
 /// The resource representing a Stripe bank account.
 ///
 /// For more details see https://stripe.com/docs/api#customer_bank_account_object.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct BankAccount {
     pub id: String,
+    #[serde(default)] // NOTE: Consumed as the tag when deserialized as an `ExternalAccount`
     pub object: String,
-    pub account: String,
-    pub account_holder_name: String,
-    pub account_holder_type: String, // (individual or company)
-    pub bank_name: String,
+    #[serde(default)]
+    pub account: Option<String>, // (only set for external accounts of a connected account)
+    pub account_holder_name: Option<String>,
+    pub account_holder_type: Option<String>, // (individual or company)
+    pub bank_name: Option<String>,
     pub country: String,
     pub currency: Currency,
-    pub customer: String,
-    pub default_for_currency: bool,
-    pub fingerprint: String,
+    #[serde(default)]
+    pub customer: Option<String>, // (only set for bank accounts of a customer)
+    #[serde(default)]
+    pub default_for_currency: Option<bool>,
+    pub fingerprint: Option<String>,
     pub last4: String,
+    #[serde(default)]
     pub metadata: Metadata,
-    pub routing_number: String,
-    pub status: String, // (new, validated, verified, verification_failed, errored)
+    pub routing_number: Option<String>,
+    pub status: BankAccountStatus,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+/// The set of parameters that can be used when verifying a bank account.
+///
+/// For more details see https://stripe.com/docs/api/customer_bank_accounts/verify.
+#[derive(Debug, Serialize)]
+struct BankAccountVerifyParams {
+    amounts: Vec<u32>,
+}
+
+impl BankAccount {
+    /// Verifies a customer's bank account with the amounts (in cents) of the two micro-deposits sent to it.
+    ///
+    /// The bank account can be charged once its status is `Verified`.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_bank_accounts/verify.
+    pub fn verify(&self, client: &Client, customer_id: &str, amounts: [u32; 2]) -> Result<BankAccount, Error> {
+        let params = BankAccountVerifyParams { amounts: amounts.to_vec() };
+        client.post(&format!("/customers/{}/sources/{}/verify", customer_id, self.id), params)
+    }
 }
@@ -0,0 +1,231 @@
+//! Resources for the Stripe customer portal, where customers can manage their billing.
+
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::ProrationBehavior;
+
+/// The set of parameters that can be used when creating a customer portal session.
+///
+/// For more details see https://stripe.com/docs/api/customer_portal/sessions/create.
+#[derive(Debug, Default, Serialize)]
+pub struct SessionParams<'a> {
+    pub customer: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+}
+
+setters! {
+    SessionParams<'a> {
+        configuration: &'a str,
+        locale: &'a str,
+        on_behalf_of: &'a str,
+        return_url: &'a str,
+    }
+}
+
+/// The resource representing a Stripe customer portal session.
+///
+/// For more details see https://stripe.com/docs/api/customer_portal/sessions/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Session {
+    pub id: String,
+    pub object: String,
+    pub configuration: String,
+    pub created: Timestamp,
+    pub customer: String,
+    pub livemode: bool,
+    pub locale: Option<String>,
+    pub on_behalf_of: Option<String>,
+    pub return_url: Option<String>,
+    pub url: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Session {
+    /// Creates a session that a customer can be redirected to in order to manage their billing.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_portal/sessions/create.
+    pub fn create(client: &Client, params: SessionParams) -> Result<Session, Error> {
+        client.post("/billing_portal/sessions", params)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BusinessProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_policy_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct FeatureToggle {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CustomerUpdateFeature {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>, // (address, email, phone, shipping, tax_id)
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionCancelFeature {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>, // (immediately, at_period_end)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionUpdateProduct {
+    pub prices: Vec<String>,
+    pub product: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionUpdateFeature {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_allowed_updates: Option<Vec<String>>, // (price, quantity, promotion_code)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<SubscriptionUpdateProduct>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+}
+
+/// The features available to customers in the portal.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Features {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_update: Option<CustomerUpdateFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_history: Option<FeatureToggle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_update: Option<FeatureToggle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_cancel: Option<SubscriptionCancelFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_pause: Option<FeatureToggle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update: Option<SubscriptionUpdateFeature>,
+}
+
+/// The set of parameters that can be used when creating or updating a customer portal configuration.
+///
+/// For more details see https://stripe.com/docs/api/customer_portal/configurations/create.
+#[derive(Debug, Default, Serialize)]
+pub struct ConfigurationParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_profile: Option<BusinessProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_return_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<Features>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    ConfigurationParams<'a> {
+        active: bool,
+        business_profile: BusinessProfile,
+        default_return_url: &'a str,
+        features: Features,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing customer portal configurations.
+///
+/// For more details see https://stripe.com/docs/api/customer_portal/configurations/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ConfigurationListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ConfigurationListParams<'a> {
+        active: bool,
+        ending_before: &'a str,
+        is_default: bool,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe customer portal configuration.
+///
+/// For more details see https://stripe.com/docs/api/customer_portal/configuration.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Configuration {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub business_profile: BusinessProfile,
+    pub created: Timestamp,
+    pub default_return_url: Option<String>,
+    pub features: Features,
+    pub is_default: bool,
+    pub livemode: bool,
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub updated: Timestamp,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Configuration {
+    /// Creates a new customer portal configuration.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_portal/configurations/create.
+    pub fn create(client: &Client, params: ConfigurationParams) -> Result<Configuration, Error> {
+        client.post("/billing_portal/configurations", params)
+    }
+
+    /// Retrieves the details of a customer portal configuration.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_portal/configurations/retrieve.
+    pub fn retrieve(client: &Client, configuration_id: &str) -> Result<Configuration, Error> {
+        client.get(&format!("/billing_portal/configurations/{}", configuration_id))
+    }
+
+    /// Updates a customer portal configuration.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_portal/configurations/update.
+    pub fn update(client: &Client, configuration_id: &str, params: ConfigurationParams) -> Result<Configuration, Error> {
+        client.post(&format!("/billing_portal/configurations/{}", configuration_id), params)
+    }
+
+    /// Lists all customer portal configurations.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_portal/configurations/list.
+    pub fn list(client: &Client, params: ConfigurationListParams) -> Result<List<Configuration>, Error> {
+        client.get(&format!("/billing_portal/configurations?{}", to_form(&params)?))
+    }
+}
@@ -0,0 +1,89 @@
+use error::Error;
+use client::Client;
+use params::{List, Timestamp};
+
+string_enum! {
+    /// The status of a capability on an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/object#capability_object-status.
+    pub enum CapabilityStatus {
+        Active => "active",
+        Disabled => "disabled",
+        Inactive => "inactive",
+        Pending => "pending",
+        Unrequested => "unrequested",
+    }
+}
+
+/// The information Stripe still needs before a capability can become active.
+///
+/// For more details see https://stripe.com/docs/api/capabilities/object#capability_object-requirements.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CapabilityRequirements {
+    pub current_deadline: Option<Timestamp>,
+    #[serde(default)]
+    pub currently_due: Vec<String>,
+    pub disabled_reason: Option<String>,
+    #[serde(default)]
+    pub eventually_due: Vec<String>,
+    #[serde(default)]
+    pub past_due: Vec<String>,
+    #[serde(default)]
+    pub pending_verification: Vec<String>,
+}
+
+/// The set of parameters that can be used when updating a capability.
+///
+/// For more details see https://stripe.com/docs/api/capabilities/update.
+#[derive(Debug, Default, Serialize)]
+pub struct CapabilityParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<bool>,
+}
+
+setters! {
+    CapabilityParams {
+        requested: bool,
+    }
+}
+
+/// The resource representing a capability of a Stripe account, eg. `card_payments` or `transfers`.
+///
+/// For more details see https://stripe.com/docs/api/capabilities/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Capability {
+    pub id: String,
+    pub object: String,
+    pub account: String,
+    pub requested: bool,
+    pub requested_at: Option<Timestamp>,
+    #[serde(default)]
+    pub requirements: Option<CapabilityRequirements>,
+    pub status: CapabilityStatus,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Capability {
+    /// Retrieves a capability of an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str, capability: &str) -> Result<Capability, Error> {
+        client.get(&format!("/accounts/{}/capabilities/{}", account_id, capability))
+    }
+
+    /// Requests or unrequests a capability of an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/update.
+    pub fn update(client: &Client, account_id: &str, capability: &str, params: CapabilityParams) -> Result<Capability, Error> {
+        client.post(&format!("/accounts/{}/capabilities/{}", account_id, capability), params)
+    }
+
+    /// Lists the capabilities of an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/list.
+    pub fn list(client: &Client, account_id: &str) -> Result<List<Capability>, Error> {
+        client.get(&format!("/accounts/{}/capabilities", account_id))
+    }
+}
@@ -1,3 +1,5 @@
+use params::Metadata;
+use resources::Currency;
 use serde::ser::SerializeStruct;
 
 #[derive(Debug, Deserialize /*, Serialize */)]
@@ -10,6 +12,13 @@ pub struct CardParams<'a> {
     pub cvc: Option<&'a str>, // card security code
 }
 
+setters! {
+    CardParams<'a> {
+        name: &'a str,
+        cvc: &'a str,
+    }
+}
+
 impl<'a> Default for CardParams<'a> {
     fn default() -> Self {
         CardParams {
@@ -41,6 +50,8 @@ impl<'a> ::serde::Serialize for CardParams<'a> {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Card {
     pub id: String,
+    #[serde(default)]
+    pub account: Option<String>, // (only set for external accounts of a connected account)
     pub address_city: Option<String>,
     pub address_country: Option<String>,
     pub address_line1: Option<String>,
@@ -51,11 +62,20 @@ pub struct Card {
     pub address_zip_check: Option<String>, // (pass, fail, unavailable, unchecked)
     pub brand: String, // (Visa, American Express, MasterCard, Discover, JCB, Diners Club, or Unknown)
     pub country: String, // eg. "US"
+    #[serde(default)]
+    pub currency: Option<Currency>, // (only set for external accounts of a connected account)
     pub customer: Option<String>,
     pub cvc_check: Option<String>, // (pass, fail, unavailable, unchecked)
+    #[serde(default)]
+    pub default_for_currency: Option<bool>,
     pub exp_month: u32,
     pub exp_year: u32,
     pub fingerprint: String,
     pub funding: String, // (credit, debit, prepaid, unknown)
     pub last4: String,
+    #[serde(default)]
+    pub metadata: Metadata,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
@@ -0,0 +1,282 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Timestamp};
+use path::Path;
+use resources::Currency;
+use std::collections::HashMap;
+
+string_enum! {
+    /// How the funds received by bank transfer are applied to the payments of a customer.
+    ///
+    /// For more details see https://stripe.com/docs/payments/customer-balance/reconciliation.
+    pub enum ReconciliationMode {
+        Automatic => "automatic",
+        Manual => "manual",
+        MerchantDefault => "merchant_default",
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CashBalanceSettings {
+    pub reconciliation_mode: ReconciliationMode,
+    #[serde(default)]
+    pub using_merchant_default: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CashBalanceSettingsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciliation_mode: Option<ReconciliationMode>,
+}
+
+setters! {
+    CashBalanceSettingsParams {
+        reconciliation_mode: ReconciliationMode,
+    }
+}
+
+/// The set of parameters that can be used when updating a customer's cash balance.
+///
+/// For more details see https://stripe.com/docs/api/cash_balance/update.
+#[derive(Debug, Default, Serialize)]
+pub struct CashBalanceParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<CashBalanceSettingsParams>,
+}
+
+setters! {
+    CashBalanceParams {
+        settings: CashBalanceSettingsParams,
+    }
+}
+
+/// The resource representing the cash balance of a Stripe customer,
+/// funded by the customer's bank transfers.
+///
+/// For more details see https://stripe.com/docs/api/cash_balance/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CashBalance {
+    pub available: Option<HashMap<Currency, i64>>,
+    pub customer: String,
+    pub livemode: bool,
+    pub settings: CashBalanceSettings,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl CashBalance {
+    /// Retrieves a customer's cash balance.
+    ///
+    /// For more details see https://stripe.com/docs/api/cash_balance/retrieve.
+    pub fn retrieve(client: &Client, customer_id: &str) -> Result<CashBalance, Error> {
+        client.get(&Path::new("/customers").id(customer_id)?.segment("cash_balance"))
+    }
+
+    /// Updates the settings of a customer's cash balance.
+    ///
+    /// For more details see https://stripe.com/docs/api/cash_balance/update.
+    pub fn update(client: &Client, customer_id: &str, params: CashBalanceParams) -> Result<CashBalance, Error> {
+        client.post(&Path::new("/customers").id(customer_id)?.segment("cash_balance"), params)
+    }
+}
+
+string_enum! {
+    /// The type of a customer cash balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/cash_balance_transactions/object#customer_cash_balance_transaction_object-type.
+    pub enum CustomerCashBalanceTransactionType {
+        AdjustedForOverdraft => "adjusted_for_overdraft",
+        AppliedToPayment => "applied_to_payment",
+        Funded => "funded",
+        FundingReversed => "funding_reversed",
+        RefundedFromPayment => "refunded_from_payment",
+        ReturnCanceled => "return_canceled",
+        ReturnInitiated => "return_initiated",
+        UnappliedFromPayment => "unapplied_from_payment",
+    }
+}
+
+/// The set of parameters that can be used when listing customer cash balance transactions.
+///
+/// For more details see https://stripe.com/docs/api/cash_balance_transactions/list.
+#[derive(Debug, Default, Serialize)]
+pub struct CustomerCashBalanceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    CustomerCashBalanceTransactionListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe customer cash balance transaction.
+///
+/// For more details see https://stripe.com/docs/api/cash_balance_transactions/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CustomerCashBalanceTransaction {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub customer: String,
+    pub ending_balance: i64,
+    pub livemode: bool,
+    pub net_amount: i64,
+    #[serde(rename = "type")]
+    pub transaction_type: CustomerCashBalanceTransactionType,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl CustomerCashBalanceTransaction {
+    /// Retrieves the details of a customer cash balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/cash_balance_transactions/retrieve.
+    pub fn retrieve(client: &Client, customer_id: &str, transaction_id: &str) -> Result<CustomerCashBalanceTransaction, Error> {
+        client.get(&Path::new("/customers").id(customer_id)?.segment("cash_balance_transactions").id(transaction_id)?)
+    }
+
+    /// Lists the transactions which modified a customer's cash balance.
+    ///
+    /// For more details see https://stripe.com/docs/api/cash_balance_transactions/list.
+    pub fn list(client: &Client, customer_id: &str, params: CustomerCashBalanceTransactionListParams) -> Result<List<CustomerCashBalanceTransaction>, Error> {
+        let query = to_form(&params)?;
+        client.get(&Path::new("/customers").id(customer_id)?.segment("cash_balance_transactions").query(&query))
+    }
+}
+
+string_enum! {
+    /// The type of bank transfer used to fund a customer's cash balance.
+    pub enum BankTransferType {
+        EuBankTransfer => "eu_bank_transfer",
+        GbBankTransfer => "gb_bank_transfer",
+        JpBankTransfer => "jp_bank_transfer",
+        MxBankTransfer => "mx_bank_transfer",
+        UsBankTransfer => "us_bank_transfer",
+    }
+}
+
+string_enum! {
+    /// The method used to fund a customer's cash balance.
+    pub enum FundingType {
+        BankTransfer => "bank_transfer",
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct EuBankTransferParams<'a> {
+    pub country: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BankTransferParams<'a> {
+    #[serde(rename = "type")]
+    pub transfer_type: BankTransferType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eu_bank_transfer: Option<EuBankTransferParams<'a>>, // (required for `EuBankTransfer`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_address_types: Option<Vec<&'a str>>, // (iban, sort_code, spei, zengin, ...)
+}
+
+setters! {
+    BankTransferParams<'a> {
+        eu_bank_transfer: EuBankTransferParams<'a>,
+        requested_address_types: Vec<&'a str>,
+    }
+}
+
+/// The set of parameters that can be used when creating the funding instructions of a customer.
+///
+/// For more details see https://stripe.com/docs/api/customers/create_funding_instructions.
+#[derive(Debug, Serialize)]
+pub struct FundingInstructionsParams<'a> {
+    pub bank_transfer: BankTransferParams<'a>,
+    pub currency: Currency,
+    pub funding_type: FundingType,
+}
+
+impl<'a> FundingInstructionsParams<'a> {
+    /// Creates the parameters for funding a cash balance in `currency` with a bank transfer of `transfer_type`.
+    pub fn new(currency: Currency, transfer_type: BankTransferType) -> Self {
+        FundingInstructionsParams {
+            bank_transfer: BankTransferParams {
+                transfer_type: transfer_type,
+                eu_bank_transfer: None,
+                requested_address_types: None,
+            },
+            currency: currency,
+            funding_type: FundingType::BankTransfer,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AbaAddress {
+    pub account_number: String,
+    pub bank_name: String,
+    pub routing_number: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IbanAddress {
+    pub account_holder_name: String,
+    pub bic: String,
+    pub country: String,
+    pub iban: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SortCodeAddress {
+    pub account_holder_name: String,
+    pub account_number: String,
+    pub sort_code: String,
+}
+
+/// A bank account the customer can send funds to.
+///
+/// Only the details matching the address' `address_type` are set.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FinancialAddress {
+    #[serde(rename = "type")]
+    pub address_type: String, // (aba, iban, sort_code, spei, swift, zengin)
+    #[serde(default)]
+    pub supported_networks: Vec<String>,
+    #[serde(default)]
+    pub aba: Option<AbaAddress>,
+    #[serde(default)]
+    pub iban: Option<IbanAddress>,
+    #[serde(default)]
+    pub sort_code: Option<SortCodeAddress>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransfer {
+    pub country: String,
+    pub financial_addresses: Vec<FinancialAddress>,
+    #[serde(rename = "type")]
+    pub transfer_type: BankTransferType,
+}
+
+/// The instructions a customer follows to fund their cash balance by bank transfer.
+///
+/// For more details see https://stripe.com/docs/api/customers/create_funding_instructions.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FundingInstructions {
+    pub bank_transfer: FundingInstructionsBankTransfer,
+    pub currency: Currency,
+    pub funding_type: FundingType,
+    pub livemode: bool,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
@@ -1,41 +1,298 @@
 use client::Client;
-use error::{Error, ErrorCode};
-use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Address, Currency, Refund, PaymentSourceParams, PaymentSource};
-use serde_qs as qs;
+use error::{Error, ErrorCode, Violation};
+use params::{to_form, List, Metadata, RangeQuery, Timestamp, Validate};
+use resources::{BillingDetails, Currency, Refund, PaymentSourceParams, PaymentSource, Shipping};
+use serde_json as json;
 
+string_enum! {
+    /// The status of a charge.
+    pub enum ChargeStatus {
+        Failed => "failed",
+        Pending => "pending",
+        Succeeded => "succeeded",
+    }
+}
+
+string_enum! {
+    /// The kind of outcome of a charge.
+    pub enum OutcomeType {
+        Authorized => "authorized",
+        Blocked => "blocked",
+        Invalid => "invalid",
+        IssuerDeclined => "issuer_declined",
+        ManualReview => "manual_review",
+    }
+}
+
+string_enum! {
+    /// Whether a charge was sent to, and approved by, the card network.
+    pub enum NetworkStatus {
+        ApprovedByNetwork => "approved_by_network",
+        DeclinedByNetwork => "declined_by_network",
+        NotSentToNetwork => "not_sent_to_network",
+        ReversedAfterApproval => "reversed_after_approval",
+    }
+}
+
+string_enum! {
+    /// The risk level Stripe Radar assigned to a charge.
+    pub enum RiskLevel {
+        Elevated => "elevated",
+        Highest => "highest",
+        Normal => "normal",
+        NotAssessed => "not_assessed",
+        Unknown => "unknown",
+    }
+}
+
+/// The result of the authorization and risk evaluation of a charge.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-outcome.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ChargeOutcome {
     #[serde(rename = "type")]
-    pub outcome_type: String, // (authorized, manual_review, issuer_declined, blocked, invalid)
-    pub network_status: String, // (approved_by_network, declined_by_network, not_sent_to_network, reversed_after_approval)
+    pub outcome_type: OutcomeType,
+    pub network_status: Option<NetworkStatus>,
     #[serde(default)]
     pub reason: Option<String>,
     #[serde(default)]
-    pub risk_level: Option<String>, // (normal, elevated, highest, not_assessed, unknown)
+    pub risk_level: Option<RiskLevel>,
+    #[serde(default)]
+    pub risk_score: Option<u64>, // (0-100, only with Radar for Fraud Teams)
     #[serde(default)]
     pub seller_message: Option<String>,
     #[serde(default)]
     pub rule: Option<String>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
-pub struct FraudDetails {
-    pub user_report: Option<String>,
-    #[serde(skip_serializing)]
-    pub stripe_report: Option<String>,
+string_enum! {
+    /// How a 3D Secure authentication was completed.
+    pub enum ThreeDSecureAuthenticationFlow {
+        Challenge => "challenge",
+        Frictionless => "frictionless",
+    }
 }
 
+string_enum! {
+    /// The outcome of a 3D Secure authentication.
+    pub enum ThreeDSecureResult {
+        AttemptAcknowledged => "attempt_acknowledged",
+        Authenticated => "authenticated",
+        Exempted => "exempted",
+        Failed => "failed",
+        NotSupported => "not_supported",
+        ProcessingError => "processing_error",
+    }
+}
+
+/// The details of the 3D Secure authentication of a card payment.
+///
+/// For more details see https://stripe.com/docs/payments/3d-secure.
 #[derive(Debug, Deserialize, Serialize)]
-pub struct ShippingDetails {
-    pub name: String,
-    pub address: Address,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub carrier: Option<String>, // eg. Fedex, UPS, USPS
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone: Option<String>,
+pub struct ThreeDSecureDetails {
+    #[serde(default)]
+    pub authentication_flow: Option<ThreeDSecureAuthenticationFlow>,
+    #[serde(default)]
+    pub result: Option<ThreeDSecureResult>,
+    #[serde(default)]
+    pub result_reason: Option<String>, // (card_not_enrolled, network_not_supported, abandoned, canceled, rejected, bypassed, protocol_error)
+    #[serde(default)]
+    pub version: Option<String>, // eg. "2.1.0"
+}
+
+string_enum! {
+    /// The brand of a card.
+    pub enum CardBrand {
+        Amex => "amex",
+        Diners => "diners",
+        Discover => "discover",
+        Jcb => "jcb",
+        Mastercard => "mastercard",
+        Unionpay => "unionpay",
+        Visa => "visa",
+        Unknown => "unknown",
+    }
+}
+
+string_enum! {
+    /// How a card is funded.
+    pub enum CardFunding {
+        Credit => "credit",
+        Debit => "debit",
+        Prepaid => "prepaid",
+        Unknown => "unknown",
+    }
+}
+
+string_enum! {
+    /// The kind of wallet a card was used through.
+    pub enum WalletType {
+        AmexExpressCheckout => "amex_express_checkout",
+        ApplePay => "apple_pay",
+        GooglePay => "google_pay",
+        Masterpass => "masterpass",
+        SamsungPay => "samsung_pay",
+        VisaCheckout => "visa_checkout",
+    }
+}
+
+/// The wallet (eg. Apple Pay) a card was used through.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CardWallet {
+    #[serde(rename = "type")]
+    pub wallet_type: WalletType,
+    #[serde(default)]
+    pub dynamic_last4: Option<String>,
+}
+
+/// Details about a card used for a charge, online (`card`) or in person (`card_present`).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CardPaymentMethodDetails {
+    #[serde(default)]
+    pub brand: Option<CardBrand>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub exp_month: Option<u32>,
+    #[serde(default)]
+    pub exp_year: Option<u32>,
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    #[serde(default)]
+    pub funding: Option<CardFunding>,
+    #[serde(default)]
+    pub last4: Option<String>,
+    #[serde(default)]
+    pub network: Option<String>, // eg. visa, cartes_bancaires
+    #[serde(default)]
+    pub three_d_secure: Option<ThreeDSecureDetails>,
+    #[serde(default)]
+    pub wallet: Option<CardWallet>,
+}
+
+string_enum! {
+    /// The type of a payment method.
+    pub enum PaymentMethodType {
+        AchCreditTransfer => "ach_credit_transfer",
+        AchDebit => "ach_debit",
+        AcssDebit => "acss_debit",
+        Alipay => "alipay",
+        AuBecsDebit => "au_becs_debit",
+        Bancontact => "bancontact",
+        Card => "card",
+        CardPresent => "card_present",
+        Eps => "eps",
+        Giropay => "giropay",
+        Ideal => "ideal",
+        Klarna => "klarna",
+        P24 => "p24",
+        SepaDebit => "sepa_debit",
+        Sofort => "sofort",
+        UsBankAccount => "us_bank_account",
+        WechatPay => "wechat_pay",
+    }
+}
+
+/// Details about the payment method used for a charge, depending on its type.
+///
+/// Payment methods other than cards are kept as `Other` with their raw JSON details.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details.
+#[derive(Debug)]
+pub enum PaymentMethodDetails {
+    Card(CardPaymentMethodDetails),
+    CardPresent(CardPaymentMethodDetails),
+    Other(PaymentMethodType, json::Value),
+}
+
+impl PaymentMethodDetails {
+    /// The type of the payment method.
+    pub fn payment_method_type(&self) -> PaymentMethodType {
+        match *self {
+            PaymentMethodDetails::Card(_) => PaymentMethodType::Card,
+            PaymentMethodDetails::CardPresent(_) => PaymentMethodType::CardPresent,
+            PaymentMethodDetails::Other(ref payment_method_type, _) => payment_method_type.clone(),
+        }
+    }
+
+    /// The details of the card used for the charge, whether it was used online or in person.
+    pub fn card(&self) -> Option<&CardPaymentMethodDetails> {
+        match *self {
+            PaymentMethodDetails::Card(ref card) | PaymentMethodDetails::CardPresent(ref card) => Some(card),
+            PaymentMethodDetails::Other(..) => None,
+        }
+    }
+}
+
+impl ::serde::Serialize for PaymentMethodDetails {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::Serializer
+    {
+        use serde::ser::SerializeMap;
+
+        let payment_method_type = self.payment_method_type();
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("type", &payment_method_type)?;
+        match *self {
+            PaymentMethodDetails::Card(ref card) | PaymentMethodDetails::CardPresent(ref card) => {
+                map.serialize_entry(payment_method_type.as_str(), card)?
+            }
+            PaymentMethodDetails::Other(_, ref details) => map.serialize_entry(payment_method_type.as_str(), details)?,
+        }
+        map.end()
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PaymentMethodDetails {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::de::Deserializer<'de>
+    {
+        use serde::de::Error;
+
+        // NOTE: The details are in a field named after the type, eg. `{"type": "card", "card": {...}}`
+        let mut value = json::Map::deserialize(deserializer)?;
+        let payment_method_type = match value.remove("type") {
+            Some(json::Value::String(payment_method_type)) => PaymentMethodType::from(payment_method_type.as_str()),
+            _ => return Err(D::Error::missing_field("type")),
+        };
+        let details = value.remove(payment_method_type.as_str()).unwrap_or(json::Value::Null);
+        let details = match payment_method_type {
+            PaymentMethodType::Card => json::from_value(details).map(PaymentMethodDetails::Card),
+            PaymentMethodType::CardPresent => json::from_value(details).map(PaymentMethodDetails::CardPresent),
+            _ => Ok(PaymentMethodDetails::Other(payment_method_type, details)),
+        };
+        details.map_err(D::Error::custom)
+    }
+}
+
+string_enum! {
+    /// A report about whether a charge is fraudulent, made by the merchant.
+    pub enum UserReport {
+        Fraudulent => "fraudulent",
+        Safe => "safe",
+    }
+}
+
+string_enum! {
+    /// A report that a charge is fraudulent, made by Stripe.
+    pub enum StripeReport {
+        Fraudulent => "fraudulent",
+    }
+}
+
+/// The fraud reports about a charge.
+///
+/// Only `user_report` can be set when updating a charge.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-fraud_details.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct FraudDetails {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tracking_number: Option<String>,
+    pub user_report: Option<UserReport>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub stripe_report: Option<StripeReport>,
 }
 
 /// The set of parameters that can be used when capturing a charge.
@@ -53,12 +310,111 @@ pub struct CaptureParams<'a> {
     pub statement_descriptor: Option<&'a str>,
 }
 
+setters! {
+    CaptureParams<'a> {
+        amount: u64,
+        application_fee: u64,
+        receipt_email: &'a str,
+        statement_descriptor: &'a str,
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DestinationParams<'a> {
     pub account: &'a str,
     pub amount: u64,
 }
 
+/// The connected account a destination charge or payment intent transfers funds to.
+///
+/// If `amount` is omitted, the full amount minus the application fee is transferred.
+///
+/// For more details see https://stripe.com/docs/connect/destination-charges.
+#[derive(Debug, Serialize)]
+pub struct TransferDataParams<'a> {
+    pub destination: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+}
+
+setters! {
+    TransferDataParams<'a> {
+        amount: u64,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransferData {
+    pub amount: Option<u64>,
+    pub destination: String,
+}
+
+/// Checks that the Connect parameters of a charge or payment intent are consistent with each other.
+pub(crate) fn validate_connect_params(
+    amount: Option<u64>,
+    application_fee_amount: Option<u64>,
+    transfer_data: Option<&TransferDataParams>,
+    on_behalf_of: Option<&str>,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    if let (Some(amount), Some(fee)) = (amount, application_fee_amount) {
+        if fee > amount {
+            violations.push(Violation::new("application_fee_amount", "must not exceed `amount`"));
+        }
+    }
+    if let Some(transfer_data) = transfer_data {
+        if transfer_data.destination.is_empty() {
+            violations.push(Violation::new("transfer_data[destination]", "must not be empty"));
+        }
+        if let Some(transfer_amount) = transfer_data.amount {
+            if application_fee_amount.is_some() {
+                violations.push(Violation::new(
+                    "transfer_data[amount]",
+                    "cannot be combined with `application_fee_amount`",
+                ));
+            }
+            if amount.map_or(false, |amount| transfer_amount > amount) {
+                violations.push(Violation::new("transfer_data[amount]", "must not exceed `amount`"));
+            }
+        }
+        if let Some(on_behalf_of) = on_behalf_of {
+            if on_behalf_of != transfer_data.destination {
+                violations.push(Violation::new("on_behalf_of", "must match `transfer_data[destination]`"));
+            }
+        }
+    }
+    violations
+}
+
+/// Checks a statement descriptor and its suffix against Stripe's rules.
+///
+/// Only the documented 22 character limit is checked for the suffix; Stripe may
+/// still truncate it to fit the combined descriptor shown on statements.
+///
+/// For more details see https://stripe.com/docs/statement-descriptors#requirements.
+pub(crate) fn validate_statement_descriptors(descriptor: Option<&str>, suffix: Option<&str>) -> Vec<Violation> {
+    fn check(param: &'static str, value: &str, min_len: usize, needs_letter: bool, violations: &mut Vec<Violation>) {
+        let len = value.chars().count();
+        if len < min_len || len > 22 {
+            violations.push(Violation::new(param, format!("must be {} to 22 characters long", min_len)));
+        }
+        if needs_letter && !value.chars().any(|c| c.is_ascii_alphabetic()) {
+            violations.push(Violation::new(param, "must contain at least one letter"));
+        }
+        if value.chars().any(|c| !c.is_ascii() || c.is_ascii_control() || "<>\\'\"*".contains(c)) {
+            violations.push(Violation::new(param, "must only contain latin characters, and none of < > \\ ' \" *"));
+        }
+    }
+
+    let mut violations = Vec::new();
+    if let Some(descriptor) = descriptor {
+        check("statement_descriptor", descriptor, 5, true, &mut violations);
+    }
+    if let Some(suffix) = suffix {
+        check("statement_descriptor_suffix", suffix, 1, false, &mut violations);
+    }
+    violations
+}
 
 /// The set of parameters that can be used when creating or updating a charge.
 ///
@@ -72,6 +428,8 @@ pub struct ChargeParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub capture: Option<bool>, // NOTE: if None, Stripe assumes true
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
@@ -80,6 +438,8 @@ pub struct ChargeParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fraud_details: Option<FraudDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_group: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_behalf_of: Option<&'a str>,
@@ -88,23 +448,93 @@ pub struct ChargeParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<ShippingDetails>,
+    pub shipping: Option<Shipping>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<PaymentSourceParams<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix: Option<&'a str>, // (combined with the account's prefix)
+}
+
+setters! {
+    ChargeParams<'a> {
+        amount: u64,
+        currency: Currency,
+        application_fee: u64,
+        application_fee_amount: u64,
+        capture: bool,
+        description: &'a str,
+        destination: DestinationParams<'a>,
+        fraud_details: FraudDetails,
+        transfer_data: TransferDataParams<'a>,
+        transfer_group: &'a str,
+        on_behalf_of: &'a str,
+        metadata: Metadata,
+        receipt_email: &'a str,
+        shipping: Shipping,
+        customer: String,
+        source: PaymentSourceParams<'a>,
+        statement_descriptor: &'a str,
+        statement_descriptor_suffix: &'a str,
+    }
+}
+
+impl<'a> ChargeParams<'a> {
+    /// Creates the parameters for a new charge of `amount` in `currency`.
+    pub fn new(amount: u64, currency: Currency) -> Self {
+        ChargeParams {
+            amount: Some(amount),
+            currency: Some(currency),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Validate for ChargeParams<'a> {
+    fn violations(&self) -> Vec<Violation> {
+        let mut violations = validate_connect_params(
+            self.amount,
+            self.application_fee_amount,
+            self.transfer_data.as_ref(),
+            self.on_behalf_of,
+        );
+        violations.extend(validate_statement_descriptors(self.statement_descriptor, self.statement_descriptor_suffix));
+        if self.application_fee.is_some() && self.application_fee_amount.is_some() {
+            violations.push(Violation::new("application_fee", "cannot be combined with `application_fee_amount`"));
+        }
+        if self.destination.is_some() && self.transfer_data.is_some() {
+            violations.push(Violation::new("destination", "cannot be combined with `transfer_data`"));
+        }
+        match self.amount {
+            Some(0) => violations.push(Violation::new("amount", "must be greater than 0")),
+            Some(_) => {}
+            None => violations.push(Violation::new("amount", "is required")),
+        }
+        if self.currency.is_none() {
+            violations.push(Violation::new("currency", "is required"));
+        }
+        match self.source {
+            Some(PaymentSourceParams::Token(_)) | Some(PaymentSourceParams::Card(_)) if self.customer.is_some() => {
+                // NOTE: When charging a customer, the source must be one already attached to the customer
+                violations.push(Violation::new("source", "must be the id of a source of `customer`, not a token or card details"));
+            }
+            _ => {}
+        }
+        violations
+    }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-#[serde(rename_all = "snake_case")]
-pub enum SourceType {
-    All,
-    AlipayAccount,
-    BankAccount,
-    BitcoinReceiver,
-    Card,
+string_enum! {
+    pub enum SourceType {
+        All => "all",
+        AlipayAccount => "alipay_account",
+        BankAccount => "bank_account",
+        BitcoinReceiver => "bitcoin_receiver",
+        Card => "card",
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -151,6 +581,18 @@ pub struct ChargeListParams<'a> {
     pub transfer_group: Option<&'a str>,
 }
 
+setters! {
+    ChargeListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        customer: &'a str,
+        ending_before: &'a str,
+        limit: i64,
+        source: SourceFilter,
+        starting_after: &'a str,
+        transfer_group: &'a str,
+    }
+}
+
 /// The resource representing a Stripe charge.
 ///
 /// For more details see https://stripe.com/docs/api#charges.
@@ -161,7 +603,11 @@ pub struct Charge {
     pub amount_refunded: u64,
     pub application: Option<String>,
     pub application_fee: Option<String>,
+    #[serde(default)]
+    pub application_fee_amount: Option<u64>,
     pub balance_transaction: Option<String>,
+    #[serde(default)]
+    pub billing_details: Option<BillingDetails>,
     pub captured: bool,
     pub created: Timestamp,
     pub currency: Currency,
@@ -179,16 +625,27 @@ pub struct Charge {
     pub order: Option<String>,
     pub outcome: Option<ChargeOutcome>,
     pub paid: bool,
+    #[serde(default)]
+    pub payment_intent: Option<String>,
+    #[serde(default)]
+    pub payment_method_details: Option<PaymentMethodDetails>,
     pub receipt_email: Option<String>,
     pub receipt_number: Option<String>,
+    #[serde(default)]
+    pub receipt_url: Option<String>,
     pub refunded: bool,
     pub refunds: List<Refund>,
-    pub shipping: Option<ShippingDetails>,
+    pub shipping: Option<Shipping>,
     pub source: PaymentSource,
     pub source_transfer: Option<String>,
     pub statement_descriptor: Option<String>,
-    pub status: String, // (succeeded, pending, failed)
+    pub status: ChargeStatus,
+    #[serde(default)]
+    pub transfer_data: Option<TransferData>,
     pub transfer_group: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 impl Charge {
@@ -196,6 +653,7 @@ impl Charge {
     ///
     /// For more details see https://stripe.com/docs/api#create_charge.
     pub fn create(client: &Client, params: ChargeParams) -> Result<Charge, Error> {
+        params.validate()?;
         client.post("/charges", params)
     }
 
@@ -213,6 +671,25 @@ impl Charge {
         client.post(&format!("/charges/{}", charge_id), params)
     }
 
+    /// Reports whether a charge is fraudulent or safe, which helps Stripe Radar improve its fraud detection.
+    ///
+    /// For more details see https://stripe.com/docs/api/charges/update#update_charge-fraud_details.
+    pub fn update_fraud_details(client: &Client, charge_id: &str, user_report: UserReport) -> Result<Charge, Error> {
+        let mut params = ChargeParams::default();
+        params.fraud_details = Some(FraudDetails { user_report: Some(user_report), stripe_report: None });
+        Charge::update(client, charge_id, params)
+    }
+
+    /// Reports a charge as safe, eg. after reviewing a charge Radar flagged.
+    pub fn mark_safe(client: &Client, charge_id: &str) -> Result<Charge, Error> {
+        Charge::update_fraud_details(client, charge_id, UserReport::Safe)
+    }
+
+    /// Reports a charge as fraudulent, which also blocks future payments from its card or email.
+    pub fn mark_fraudulent(client: &Client, charge_id: &str) -> Result<Charge, Error> {
+        Charge::update_fraud_details(client, charge_id, UserReport::Fraudulent)
+    }
+
     /// Capture captures a previously created charge with capture set to false.
     ///
     /// For more details see https://stripe.com/docs/api#charge_capture.
@@ -223,7 +700,7 @@ impl Charge {
     /// List all charges.
     ///
     /// For more details see https://stripe.com/docs/api#list_charges.
-    pub fn list(client: &Client, params: ChargeListParams) -> Result<Vec<Charge>, Error> {
-        client.get(&format!("/charges?{}", qs::to_string(&params)?))
+    pub fn list(client: &Client, params: ChargeListParams) -> Result<List<Charge>, Error> {
+        client.get(&format!("/charges?{}", to_form(&params)?))
     }
 }
@@ -0,0 +1,165 @@
+//! Resources for Stripe Checkout, the hosted payment page.
+
+use error::Error;
+use client::Client;
+use params::{to_form, Expand, Expandable, Metadata, Timestamp};
+use resources::{Currency, PaymentLink, PriceDataParams, Shipping};
+
+string_enum! {
+    /// The mode a Checkout session is used in.
+    pub enum SessionMode {
+        Payment => "payment",
+        Setup => "setup",
+        Subscription => "subscription",
+    }
+}
+
+/// The set of parameters that can be used to define a line item of a Checkout session.
+#[derive(Debug, Default, Serialize)]
+pub struct SessionLineItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<PriceDataParams<'a>>, // (instead of `price`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+setters! {
+    SessionLineItemParams<'a> {
+        price: &'a str,
+        price_data: PriceDataParams<'a>,
+        quantity: u64,
+    }
+}
+
+/// A coupon or promotion code to apply to a Checkout session.
+#[derive(Debug, Default, Serialize)]
+pub struct SessionDiscountParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<&'a str>,
+}
+
+setters! {
+    SessionDiscountParams<'a> {
+        coupon: &'a str,
+        promotion_code: &'a str,
+    }
+}
+
+/// A shipping rate to offer on a Checkout session.
+#[derive(Debug, Default, Serialize)]
+pub struct SessionShippingOptionParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<&'a str>,
+}
+
+setters! {
+    SessionShippingOptionParams<'a> {
+        shipping_rate: &'a str,
+    }
+}
+
+/// The set of parameters that can be used when creating a Checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/create.
+#[derive(Debug, Default, Serialize)]
+pub struct SessionParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<SessionMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<SessionDiscountParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<SessionLineItemParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<SessionShippingOptionParams<'a>>>,
+}
+
+setters! {
+    SessionParams<'a> {
+        cancel_url: &'a str,
+        success_url: &'a str,
+        mode: SessionMode,
+        allow_promotion_codes: bool,
+        client_reference_id: &'a str,
+        customer: &'a str,
+        customer_email: &'a str,
+        discounts: Vec<SessionDiscountParams<'a>>,
+        line_items: Vec<SessionLineItemParams<'a>>,
+        metadata: Metadata,
+        payment_method_types: Vec<&'a str>,
+        shipping_options: Vec<SessionShippingOptionParams<'a>>,
+    }
+}
+
+/// The resource representing a Stripe Checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Session {
+    pub id: String,
+    pub object: String,
+    pub amount_total: Option<i64>,
+    pub cancel_url: String,
+    pub client_reference_id: Option<String>,
+    pub currency: Option<Currency>,
+    pub customer: Option<String>,
+    pub customer_email: Option<String>,
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub mode: SessionMode,
+    pub payment_intent: Option<String>,
+    #[serde(default)]
+    pub payment_link: Option<Expandable<PaymentLink>>,
+    pub payment_status: String, // (paid, unpaid, no_payment_required)
+    #[serde(default)]
+    pub shipping: Option<Shipping>, // (set once shipping details have been collected)
+    #[serde(default)]
+    pub shipping_rate: Option<String>,
+    pub subscription: Option<String>,
+    pub success_url: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Session {
+    /// Creates a new Checkout session.
+    ///
+    /// For more details see https://stripe.com/docs/api/checkout/sessions/create.
+    pub fn create(client: &Client, params: SessionParams) -> Result<Session, Error> {
+        client.post("/checkout/sessions", params)
+    }
+
+    /// Retrieves the details of a Checkout session.
+    ///
+    /// Fields like `payment_link` can be expanded by passing them in `expand`.
+    ///
+    /// For more details see https://stripe.com/docs/api/checkout/sessions/retrieve.
+    pub fn retrieve(client: &Client, session_id: &str, expand: &[&str]) -> Result<Session, Error> {
+        client.get(&format!("/checkout/sessions/{}?{}", session_id, to_form(&Expand { expand })?))
+    }
+}
@@ -0,0 +1,74 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List};
+use resources::Currency;
+use std::collections::HashMap;
+
+/// The fields needed to verify an account of a given business type.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VerificationFieldsDetails {
+    pub additional: Vec<String>,
+    pub minimum: Vec<String>,
+}
+
+/// The verification fields required by a country, per business type.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VerificationFields {
+    pub company: VerificationFieldsDetails,
+    pub individual: VerificationFieldsDetails,
+}
+
+/// The set of parameters that can be used when listing country specs.
+///
+/// For more details see https://stripe.com/docs/api/country_specs/list.
+#[derive(Debug, Default, Serialize)]
+pub struct CountrySpecListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    CountrySpecListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing the Stripe configuration of a country.
+///
+/// For more details see https://stripe.com/docs/api/country_specs/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CountrySpec {
+    pub id: String, // eg. "US"
+    pub object: String,
+    pub default_currency: Currency,
+    pub supported_bank_account_currencies: HashMap<String, Vec<String>>, // (currency => countries)
+    pub supported_payment_currencies: Vec<String>,
+    pub supported_payment_methods: Vec<String>,
+    pub supported_transfer_countries: Vec<String>,
+    pub verification_fields: VerificationFields,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl CountrySpec {
+    /// Retrieves the country spec of a country, eg. "US".
+    ///
+    /// For more details see https://stripe.com/docs/api/country_specs/retrieve.
+    pub fn retrieve(client: &Client, country: &str) -> Result<CountrySpec, Error> {
+        client.get(&format!("/country_specs/{}", country))
+    }
+
+    /// Lists all country specs.
+    ///
+    /// For more details see https://stripe.com/docs/api/country_specs/list.
+    pub fn list(client: &Client, params: CountrySpecListParams) -> Result<List<CountrySpec>, Error> {
+        client.get(&format!("/country_specs?{}", to_form(&params)?))
+    }
+}
@@ -1,5 +1,85 @@
-use params::{Metadata, Timestamp};
-use resources::Currency;
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, Deleted};
+
+string_enum! {
+    /// How long a discount created from a coupon will apply.
+    pub enum CouponDuration {
+        Forever => "forever",
+        Once => "once",
+        Repeating => "repeating",
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a coupon.
+///
+/// Only `metadata` and `name` can be changed once a coupon has been created.
+///
+/// For more details see https://stripe.com/docs/api#create_coupon and https://stripe.com/docs/api#update_coupon.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CouponParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<CouponDuration>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_off: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>, // (required if amount_off is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_in_months: Option<u64>, // (required if duration is repeating)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_redemptions: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_off: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeem_by: Option<Timestamp>,
+}
+
+setters! {
+    CouponParams<'a> {
+        id: &'a str,
+        duration: CouponDuration,
+        amount_off: u64,
+        currency: Currency,
+        duration_in_months: u64,
+        max_redemptions: u64,
+        metadata: Metadata,
+        name: &'a str,
+        percent_off: f64,
+        redeem_by: Timestamp,
+    }
+}
+
+/// The set of parameters that can be used when listing coupons.
+///
+/// For more details see https://stripe.com/docs/api#list_coupons.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CouponListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    CouponListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
 
 /// The resource representing a Stripe coupon.
 ///
@@ -11,14 +91,57 @@ pub struct Coupon {
     pub amount_off: Option<u64>,
     pub created: Timestamp,
     pub currency: Option<Currency>,
-    pub duration: String, // (forever, once, repeating)
+    pub duration: CouponDuration,
     pub duration_in_months: Option<u64>,
     pub livemode: bool,
     pub max_redemptions: Option<u64>,
     pub metadata: Metadata,
-    pub percent_off: u64, // eg. 50 => 50%
-    pub redeem_by: Timestamp,
-    pub redeemed: u64,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub percent_off: Option<f64>, // eg. 50 => 50%
+    pub redeem_by: Option<Timestamp>,
+    pub times_redeemed: u64,
     pub valid: bool,
+    #[serde(default)]
     pub deleted: bool,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Coupon {
+    /// Creates a new coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_coupon.
+    pub fn create(client: &Client, params: CouponParams) -> Result<Coupon, Error> {
+        client.post("/coupons", params)
+    }
+
+    /// Retrieves the details of a coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_coupon.
+    pub fn retrieve(client: &Client, coupon_id: &str) -> Result<Coupon, Error> {
+        client.get(&format!("/coupons/{}", coupon_id))
+    }
+
+    /// Updates a coupon's metadata or name.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_coupon.
+    pub fn update(client: &Client, coupon_id: &str, params: CouponParams) -> Result<Coupon, Error> {
+        client.post(&format!("/coupons/{}", coupon_id), params)
+    }
+
+    /// Deletes a coupon; existing discounts created from it are not affected.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_coupon.
+    pub fn delete(client: &Client, coupon_id: &str) -> Result<Deleted<Coupon>, Error> {
+        client.delete(&format!("/coupons/{}", coupon_id))
+    }
+
+    /// Lists all coupons.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_coupons.
+    pub fn list(client: &Client, params: CouponListParams) -> Result<List<Coupon>, Error> {
+        client.get(&format!("/coupons?{}", to_form(&params)?))
+    }
 }
@@ -0,0 +1,219 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::Currency;
+
+string_enum! {
+    /// The reason a credit note was issued.
+    pub enum CreditNoteReason {
+        Duplicate => "duplicate",
+        Fraudulent => "fraudulent",
+        OrderChange => "order_change",
+        ProductUnsatisfactory => "product_unsatisfactory",
+    }
+}
+
+string_enum! {
+    /// The kind of a credit note line item.
+    pub enum CreditNoteLineItemType {
+        CustomLineItem => "custom_line_item",
+        InvoiceLineItem => "invoice_line_item",
+    }
+}
+
+/// The set of parameters that can be used to define a line item of a credit note.
+#[derive(Debug, Serialize)]
+pub struct CreditNoteLineParams<'a> {
+    #[serde(rename = "type")]
+    pub line_type: CreditNoteLineItemType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>, // (required for custom line items)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_line_item: Option<&'a str>, // (required for invoice line items)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+setters! {
+    CreditNoteLineParams<'a> {
+        amount: i64,
+        description: &'a str,
+        invoice_line_item: &'a str,
+        quantity: u64,
+        unit_amount: i64,
+    }
+}
+
+/// The set of parameters that can be used when creating, previewing, or updating a credit note.
+///
+/// Only `memo` and `metadata` can be changed once a credit note has been created.
+///
+/// For more details see https://stripe.com/docs/api/credit_notes/create and https://stripe.com/docs/api/credit_notes/update.
+#[derive(Debug, Default, Serialize)]
+pub struct CreditNoteParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<Vec<CreditNoteLineParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_of_band_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CreditNoteReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_amount: Option<i64>,
+}
+
+setters! {
+    CreditNoteParams<'a> {
+        invoice: &'a str,
+        amount: i64,
+        credit_amount: i64,
+        lines: Vec<CreditNoteLineParams<'a>>,
+        memo: &'a str,
+        metadata: Metadata,
+        out_of_band_amount: i64,
+        reason: CreditNoteReason,
+        refund: &'a str,
+        refund_amount: i64,
+    }
+}
+
+/// The set of parameters that can be used when listing credit notes.
+///
+/// For more details see https://stripe.com/docs/api/credit_notes/list.
+#[derive(Debug, Default, Serialize)]
+pub struct CreditNoteListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    CreditNoteListParams<'a> {
+        customer: &'a str,
+        ending_before: &'a str,
+        invoice: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe credit note line item.
+///
+/// For more details see https://stripe.com/docs/api/credit_notes/line_item.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditNoteLineItem {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub description: Option<String>,
+    pub discount_amount: i64,
+    pub invoice_line_item: Option<String>,
+    pub livemode: bool,
+    pub quantity: Option<u64>,
+    #[serde(rename = "type")]
+    pub line_type: CreditNoteLineItemType,
+    pub unit_amount: Option<i64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+/// The resource representing a Stripe credit note.
+///
+/// For more details see https://stripe.com/docs/api/credit_notes/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreditNote {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub customer: String,
+    pub customer_balance_transaction: Option<String>,
+    pub invoice: String,
+    pub lines: List<CreditNoteLineItem>,
+    pub livemode: bool,
+    pub memo: Option<String>,
+    pub metadata: Metadata,
+    pub number: String,
+    pub out_of_band_amount: Option<i64>,
+    pub pdf: String,
+    pub reason: Option<CreditNoteReason>,
+    pub refund: Option<String>,
+    pub status: String, // (issued, void)
+    pub subtotal: i64,
+    pub total: i64,
+    #[serde(rename = "type")]
+    pub credit_note_type: String, // (pre_payment, post_payment)
+    pub voided_at: Option<Timestamp>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl CreditNote {
+    /// Issues a credit note to adjust the amount of a finalized invoice.
+    ///
+    /// For more details see https://stripe.com/docs/api/credit_notes/create.
+    pub fn create(client: &Client, params: CreditNoteParams) -> Result<CreditNote, Error> {
+        client.post("/credit_notes", params)
+    }
+
+    /// Previews a credit note without creating it.
+    ///
+    /// For more details see https://stripe.com/docs/api/credit_notes/preview.
+    pub fn preview(client: &Client, params: CreditNoteParams) -> Result<CreditNote, Error> {
+        client.get(&format!("/credit_notes/preview?{}", to_form(&params)?))
+    }
+
+    /// Retrieves the details of a credit note.
+    ///
+    /// For more details see https://stripe.com/docs/api/credit_notes/retrieve.
+    pub fn retrieve(client: &Client, credit_note_id: &str) -> Result<CreditNote, Error> {
+        client.get(&format!("/credit_notes/{}", credit_note_id))
+    }
+
+    /// Updates a credit note's memo or metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api/credit_notes/update.
+    pub fn update(client: &Client, credit_note_id: &str, params: CreditNoteParams) -> Result<CreditNote, Error> {
+        client.post(&format!("/credit_notes/{}", credit_note_id), params)
+    }
+
+    /// Marks a credit note as void.
+    ///
+    /// For more details see https://stripe.com/docs/api/credit_notes/void.
+    pub fn void(client: &Client, credit_note_id: &str) -> Result<CreditNote, Error> {
+        client.post_empty(&format!("/credit_notes/{}/void", credit_note_id))
+    }
+
+    /// Lists all credit notes.
+    ///
+    /// For more details see https://stripe.com/docs/api/credit_notes/list.
+    pub fn list(client: &Client, params: CreditNoteListParams) -> Result<List<CreditNote>, Error> {
+        client.get(&format!("/credit_notes?{}", to_form(&params)?))
+    }
+}
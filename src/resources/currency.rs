@@ -33,6 +33,8 @@ pub enum Currency {
     BDT, // Bangladeshi Taka
     #[serde(rename = "bgn")]
     BGN, // Bulgarian Lev
+    #[serde(rename = "bhd")]
+    BHD, // Bahraini Dinar
     #[serde(rename = "bif")]
     BIF, // Burundian Franc
     #[serde(rename = "bmd")]
@@ -47,6 +49,8 @@ pub enum Currency {
     BSD, // Bahamian Dollar
     #[serde(rename = "bwp")]
     BWP, // Botswana Pula
+    #[serde(rename = "byn")]
+    BYN, // Belarusian Ruble
     #[serde(rename = "bzd")]
     BZD, // Belize Dollar
     #[serde(rename = "cad")]
@@ -121,6 +125,8 @@ pub enum Currency {
     ISK, // Icelandic Króna
     #[serde(rename = "jmd")]
     JMD, // Jamaican Dollar
+    #[serde(rename = "jod")]
+    JOD, // Jordanian Dinar
     #[serde(rename = "jpy")]
     JPY, // Japanese Yen
     #[serde(rename = "kes")]
@@ -133,6 +139,8 @@ pub enum Currency {
     KMF, // Comorian Franc
     #[serde(rename = "krw")]
     KRW, // South Korean Won
+    #[serde(rename = "kwd")]
+    KWD, // Kuwaiti Dinar
     #[serde(rename = "kyd")]
     KYD, // Cayman Islands Dollar
     #[serde(rename = "kzt")]
@@ -159,6 +167,8 @@ pub enum Currency {
     MGA, // Malagasy Ariary
     #[serde(rename = "mkd")]
     MKD, // Macedonian Denar
+    #[serde(rename = "mmk")]
+    MMK, // Myanmar Kyat
     #[serde(rename = "mnt")]
     MNT, // Mongolian Tögrög
     #[serde(rename = "mop")]
@@ -189,6 +199,8 @@ pub enum Currency {
     NPR, // Nepalese Rupee
     #[serde(rename = "nzd")]
     NZD, // New Zealand Dollar
+    #[serde(rename = "omr")]
+    OMR, // Omani Rial
     #[serde(rename = "pab")]
     PAB, // Panamanian Balboa
     #[serde(rename = "pen")]
@@ -225,6 +237,8 @@ pub enum Currency {
     SGD, // Singapore Dollar
     #[serde(rename = "shp")]
     SHP, // Saint Helenian Pound
+    #[serde(rename = "sle")]
+    SLE, // Sierra Leonean Leone
     #[serde(rename = "sll")]
     SLL, // Sierra Leonean Leone
     #[serde(rename = "sos")]
@@ -241,6 +255,8 @@ pub enum Currency {
     THB, // Thai Baht
     #[serde(rename = "tjs")]
     TJS, // Tajikistani Somoni
+    #[serde(rename = "tnd")]
+    TND, // Tunisian Dinar
     #[serde(rename = "top")]
     TOP, // Tongan Paʻanga
     #[serde(rename = "try")]
@@ -285,6 +301,44 @@ pub enum Currency {
     ZMW, // Zambian Kwacha
 }
 
+impl Currency {
+    /// Returns the number of decimal places in the currency's minor unit.
+    ///
+    /// Stripe amounts are always given in the minor unit, eg. cents for `USD`, but
+    /// zero-decimal currencies like `JPY` have no minor unit, so `100` means ¥100.
+    ///
+    /// For more details see https://stripe.com/docs/currencies#zero-decimal.
+    pub fn minor_units(&self) -> u32 {
+        match *self {
+            Currency::BIF | Currency::CLP | Currency::DJF | Currency::GNF | Currency::JPY |
+            Currency::KMF | Currency::KRW | Currency::MGA | Currency::PYG | Currency::RWF |
+            Currency::UGX | Currency::VND | Currency::VUV | Currency::XAF | Currency::XOF |
+            Currency::XPF => 0,
+            Currency::BHD | Currency::JOD | Currency::KWD | Currency::OMR | Currency::TND => 3,
+            _ => 2,
+        }
+    }
+
+    /// Returns whether the currency has no minor unit.
+    pub fn is_zero_decimal(&self) -> bool {
+        self.minor_units() == 0
+    }
+}
+
+/// Formats an amount given in the currency's minor unit, eg. `format_amount(1050, Currency::USD)` is `"10.50 USD"`.
+pub fn format_amount(amount: i64, currency: Currency) -> String {
+    let code = currency.to_string().to_uppercase();
+    let units = currency.minor_units();
+    if units == 0 {
+        return format!("{} {}", amount, code);
+    }
+
+    let sign = if amount < 0 { "-" } else { "" };
+    let scale = 10u64.pow(units);
+    let abs = amount.wrapping_abs() as u64;
+    format!("{}{}.{:0width$} {}", sign, abs / scale, abs % scale, code, width = units as usize)
+}
+
 impl Default for Currency {
     fn default() -> Self {
         Currency::USD
@@ -300,7 +354,7 @@ impl ::std::fmt::Display for Currency {
 impl ::std::str::FromStr for Currency {
     type Err = ParseCurrencyError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+        match s.to_lowercase().as_str() {
             "aed" => Ok(Currency::AED),
             "afn" => Ok(Currency::AFN),
             "all" => Ok(Currency::ALL),
@@ -315,6 +369,7 @@ impl ::std::str::FromStr for Currency {
             "bbd" => Ok(Currency::BBD),
             "bdt" => Ok(Currency::BDT),
             "bgn" => Ok(Currency::BGN),
+            "bhd" => Ok(Currency::BHD),
             "bif" => Ok(Currency::BIF),
             "bmd" => Ok(Currency::BMD),
             "bnd" => Ok(Currency::BND),
@@ -322,6 +377,7 @@ impl ::std::str::FromStr for Currency {
             "brl" => Ok(Currency::BRL),
             "bsd" => Ok(Currency::BSD),
             "bwp" => Ok(Currency::BWP),
+            "byn" => Ok(Currency::BYN),
             "bzd" => Ok(Currency::BZD),
             "cad" => Ok(Currency::CAD),
             "cdf" => Ok(Currency::CDF),
@@ -359,12 +415,14 @@ impl ::std::str::FromStr for Currency {
             "inr" => Ok(Currency::INR),
             "isk" => Ok(Currency::ISK),
             "jmd" => Ok(Currency::JMD),
+            "jod" => Ok(Currency::JOD),
             "jpy" => Ok(Currency::JPY),
             "kes" => Ok(Currency::KES),
             "kgs" => Ok(Currency::KGS),
             "khr" => Ok(Currency::KHR),
             "kmf" => Ok(Currency::KMF),
             "krw" => Ok(Currency::KRW),
+            "kwd" => Ok(Currency::KWD),
             "kyd" => Ok(Currency::KYD),
             "kzt" => Ok(Currency::KZT),
             "lak" => Ok(Currency::LAK),
@@ -378,6 +436,7 @@ impl ::std::str::FromStr for Currency {
             "mdl" => Ok(Currency::MDL),
             "mga" => Ok(Currency::MGA),
             "mkd" => Ok(Currency::MKD),
+            "mmk" => Ok(Currency::MMK),
             "mnt" => Ok(Currency::MNT),
             "mop" => Ok(Currency::MOP),
             "mro" => Ok(Currency::MRO),
@@ -393,6 +452,7 @@ impl ::std::str::FromStr for Currency {
             "nok" => Ok(Currency::NOK),
             "npr" => Ok(Currency::NPR),
             "nzd" => Ok(Currency::NZD),
+            "omr" => Ok(Currency::OMR),
             "pab" => Ok(Currency::PAB),
             "pen" => Ok(Currency::PEN),
             "pgk" => Ok(Currency::PGK),
@@ -411,6 +471,7 @@ impl ::std::str::FromStr for Currency {
             "sek" => Ok(Currency::SEK),
             "sgd" => Ok(Currency::SGD),
             "shp" => Ok(Currency::SHP),
+            "sle" => Ok(Currency::SLE),
             "sll" => Ok(Currency::SLL),
             "sos" => Ok(Currency::SOS),
             "srd" => Ok(Currency::SRD),
@@ -419,6 +480,7 @@ impl ::std::str::FromStr for Currency {
             "szl" => Ok(Currency::SZL),
             "thb" => Ok(Currency::THB),
             "tjs" => Ok(Currency::TJS),
+            "tnd" => Ok(Currency::TND),
             "top" => Ok(Currency::TOP),
             "try" => Ok(Currency::TRY),
             "ttd" => Ok(Currency::TTD),
@@ -459,3 +521,23 @@ impl ::std::error::Error for ParseCurrencyError {
         "unknown currency code"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{format_amount, Currency};
+
+    #[test]
+    fn format_amount_uses_minor_units() {
+        assert_eq!(format_amount(1050, Currency::USD), "10.50 USD");
+        assert_eq!(format_amount(-5, Currency::EUR), "-0.05 EUR");
+        assert_eq!(format_amount(1050, Currency::JPY), "1050 JPY");
+        assert_eq!(format_amount(1050, Currency::KWD), "1.050 KWD");
+    }
+
+    #[test]
+    fn parse_currency() {
+        assert_eq!("jpy".parse::<Currency>().unwrap(), Currency::JPY);
+        assert_eq!("JPY".parse::<Currency>().unwrap(), Currency::JPY);
+        assert!("xxx".parse::<Currency>().is_err());
+    }
+}
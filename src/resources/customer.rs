@@ -1,15 +1,50 @@
 use client::Client;
 use error::Error;
 use ids::SourceId;
-use resources::{Address, Currency, Deleted, Discount, PaymentSource, PaymentSourceParams, Subscription};
-use params::{List, Metadata, RangeQuery, Timestamp};
-use serde_qs as qs;
+use resources::{Currency, Deleted, Discount, FundingInstructions, FundingInstructionsParams, PaymentSource, PaymentSourceParams, Shipping, Subscription};
+use params::{to_form, List, Metadata, RangeQuery, Timestamp, UpdateValue};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct CustomerShippingDetails {
-    pub address: Address,
+/// A custom field displayed on the invoices of a customer, eg. a purchase order number.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceCustomField {
     pub name: String,
-    pub phone: String,
+    pub value: String,
+}
+
+/// The default settings of the invoices of a customer.
+///
+/// For more details see https://stripe.com/docs/api/customers/object#customer_object-invoice_settings.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceSettings {
+    pub custom_fields: Option<Vec<InvoiceCustomField>>,
+    pub default_payment_method: Option<String>,
+    pub footer: Option<String>,
+}
+
+/// The set of parameters that can be used to update the default settings of the invoices of a customer.
+#[derive(Debug, Default, Serialize)]
+pub struct InvoiceSettingsParams<'a> {
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub custom_fields: UpdateValue<Vec<InvoiceCustomField>>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub default_payment_method: UpdateValue<&'a str>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub footer: UpdateValue<&'a str>,
+}
+
+setters! {
+    InvoiceSettingsParams<'a> {
+        default_payment_method: &'a str,
+        footer: &'a str,
+    }
+}
+
+impl<'a> InvoiceSettingsParams<'a> {
+    /// Sets the custom fields, or clears them if `custom_fields` is empty.
+    pub fn custom_fields(mut self, custom_fields: Vec<InvoiceCustomField>) -> Self {
+        self.custom_fields = custom_fields.into();
+        self
+    }
 }
 
 /// The set of parameters that can be used when creating or updating a customer.
@@ -23,18 +58,53 @@ pub struct CustomerParams<'a> {
     pub business_vat_id: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_source: Option<SourceId>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub coupon: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub coupon: UpdateValue<&'a str>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub description: UpdateValue<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<InvoiceSettingsParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<CustomerShippingDetails>,
+    pub preferred_locales: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<PaymentSourceParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<&'a str>, // (create only)
+}
+
+setters! {
+    CustomerParams<'a> {
+        account_balance: i64,
+        business_vat_id: &'a str,
+        default_source: SourceId,
+        coupon: &'a str,
+        description: &'a str,
+        email: &'a str,
+        invoice_settings: InvoiceSettingsParams<'a>,
+        metadata: Metadata,
+        shipping: Shipping,
+        source: PaymentSourceParams<'a>,
+        test_clock: &'a str,
+    }
+}
+
+impl<'a> CustomerParams<'a> {
+    /// Creates an empty set of parameters, to be filled in with its setters.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the languages of the customer's receipts, invoices and billing portal, in order of preference (eg. `vec!["fr-CA", "fr"]`).
+    pub fn preferred_locales(mut self, preferred_locales: Vec<&'a str>) -> Self {
+        self.preferred_locales = Some(preferred_locales);
+        self
+    }
 }
 
 /// The set of parameters that can be used when listing customers.
@@ -52,6 +122,15 @@ pub struct CustomerListParams<'a> {
     pub starting_after: Option<&'a str>,
 }
 
+setters! {
+    CustomerListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: i64,
+        starting_after: &'a str,
+    }
+}
+
 /// The resource representing a Stripe customer.
 ///
 /// For more details see https://stripe.com/docs/api#customers.
@@ -67,11 +146,20 @@ pub struct Customer {
     pub desc: Option<String>,
     pub discount: Option<Discount>,
     pub email: Option<String>,
+    #[serde(default)]
+    pub invoice_settings: InvoiceSettings,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub shipping: Option<CustomerShippingDetails>,
+    #[serde(default)]
+    pub preferred_locales: Vec<String>,
+    pub shipping: Option<Shipping>,
     pub sources: List<PaymentSource>,
     pub subscriptions: List<Subscription>,
+    #[serde(default)]
+    pub test_clock: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 impl Customer {
@@ -96,17 +184,47 @@ impl Customer {
         client.post(&format!("/customers/{}", customer_id), params)
     }
 
+    /// Sets the payment method used by default for the customer's invoices and subscriptions.
+    ///
+    /// The payment method must already be attached to the customer.
+    pub fn set_default_payment_method(client: &Client, customer_id: &str, payment_method_id: &str) -> Result<Customer, Error> {
+        let invoice_settings = InvoiceSettingsParams {
+            default_payment_method: UpdateValue::Set(payment_method_id),
+            ..Default::default()
+        };
+        Customer::update(client, customer_id, CustomerParams::new().invoice_settings(invoice_settings))
+    }
+
+    /// Creates (or retrieves the existing) bank account details the customer can fund their cash balance with.
+    ///
+    /// For more details see https://stripe.com/docs/api/customers/create_funding_instructions.
+    pub fn create_funding_instructions(client: &Client, customer_id: &str, params: FundingInstructionsParams) -> Result<FundingInstructions, Error> {
+        client.post(&format!("/customers/{}/funding_instructions", customer_id), params)
+    }
+
     /// Deletes a customer.
     ///
     /// For more details see https://stripe.com/docs/api#delete_customer.
-    pub fn delete(client: &Client, customer_id: &str) -> Result<Deleted, Error> {
+    pub fn delete(client: &Client, customer_id: &str) -> Result<Deleted<Customer>, Error> {
         client.delete(&format!("/customers/{}", customer_id))
     }
 
+    /// Retrieves the discount currently applied to a customer, if any.
+    pub fn retrieve_discount(client: &Client, customer_id: &str) -> Result<Option<Discount>, Error> {
+        Customer::retrieve(client, customer_id).map(|customer| customer.discount)
+    }
+
+    /// Removes the currently applied discount on a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_discount.
+    pub fn delete_discount(client: &Client, customer_id: &str) -> Result<Deleted<Discount>, Error> {
+        client.delete(&format!("/customers/{}/discount", customer_id))
+    }
+
     /// List customers.
     ///
     /// For more details see https://stripe.com/docs/api#list_customers.
     pub fn list(client: &Client, params: CustomerListParams) -> Result<List<Customer>, Error> {
-        client.get(&format!("/customers?{}", qs::to_string(&params)?))
+        client.get(&format!("/customers?{}", to_form(&params)?))
     }
 }
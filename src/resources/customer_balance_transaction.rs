@@ -0,0 +1,137 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::Currency;
+
+string_enum! {
+    /// The type of a customer balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_balance_transactions/object#customer_balance_transaction_object-type.
+    pub enum CustomerBalanceTransactionType {
+        Adjustment => "adjustment",
+        AppliedToInvoice => "applied_to_invoice",
+        CreditNote => "credit_note",
+        Initial => "initial",
+        InvoiceTooLarge => "invoice_too_large",
+        InvoiceTooSmall => "invoice_too_small",
+        MigrationUpdate => "migration_update",
+        UnappliedFromInvoice => "unapplied_from_invoice",
+        UnspentReceiverCredit => "unspent_receiver_credit",
+    }
+}
+
+/// The set of parameters that can be used when creating a customer balance transaction.
+///
+/// A negative `amount` credits the customer; the credit is applied to their next invoices.
+///
+/// For more details see https://stripe.com/docs/api/customer_balance_transactions/create.
+#[derive(Debug, Serialize)]
+pub struct CustomerBalanceTransactionParams<'a> {
+    pub amount: i64,
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    CustomerBalanceTransactionParams<'a> {
+        description: &'a str,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when updating a customer balance transaction.
+///
+/// For more details see https://stripe.com/docs/api/customer_balance_transactions/update.
+#[derive(Debug, Default, Serialize)]
+pub struct CustomerBalanceTransactionUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    CustomerBalanceTransactionUpdateParams<'a> {
+        description: &'a str,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing customer balance transactions.
+///
+/// For more details see https://stripe.com/docs/api/customer_balance_transactions/list.
+#[derive(Debug, Default, Serialize)]
+pub struct CustomerBalanceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    CustomerBalanceTransactionListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe customer balance transaction.
+///
+/// For more details see https://stripe.com/docs/api/customer_balance_transactions/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CustomerBalanceTransaction {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub created: Timestamp,
+    pub credit_note: Option<String>,
+    pub currency: Currency,
+    pub customer: String,
+    pub description: Option<String>,
+    pub ending_balance: i64,
+    pub invoice: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(rename = "type")]
+    pub transaction_type: CustomerBalanceTransactionType,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl CustomerBalanceTransaction {
+    /// Creates a new balance transaction, adjusting the customer's credit balance.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_balance_transactions/create.
+    pub fn create(client: &Client, customer_id: &str, params: CustomerBalanceTransactionParams) -> Result<CustomerBalanceTransaction, Error> {
+        client.post(&format!("/customers/{}/balance_transactions", customer_id), params)
+    }
+
+    /// Retrieves the details of a customer balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_balance_transactions/retrieve.
+    pub fn retrieve(client: &Client, customer_id: &str, transaction_id: &str) -> Result<CustomerBalanceTransaction, Error> {
+        client.get(&format!("/customers/{}/balance_transactions/{}", customer_id, transaction_id))
+    }
+
+    /// Updates the description or metadata of a customer balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_balance_transactions/update.
+    pub fn update(client: &Client, customer_id: &str, transaction_id: &str, params: CustomerBalanceTransactionUpdateParams) -> Result<CustomerBalanceTransaction, Error> {
+        client.post(&format!("/customers/{}/balance_transactions/{}", customer_id, transaction_id), params)
+    }
+
+    /// Lists a customer's balance transactions.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_balance_transactions/list.
+    pub fn list(client: &Client, customer_id: &str, params: CustomerBalanceTransactionListParams) -> Result<List<CustomerBalanceTransaction>, Error> {
+        client.get(&format!("/customers/{}/balance_transactions?{}", customer_id, to_form(&params)?))
+    }
+}
@@ -1,5 +1,50 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+/// The response to deleting an object of type `T`.
+///
+/// Stripe only returns the id of the deleted object, so `T` is just a marker
+/// for the kind of object that was deleted.
 #[derive(Deserialize)]
-pub struct Deleted {
+pub struct Deleted<T> {
     pub deleted: bool,
+    #[serde(default)]
+    // NOTE: Missing in response to discount deletions
     pub id: String,
+    #[serde(skip)]
+    object: PhantomData<T>,
+}
+
+impl<T> fmt::Debug for Deleted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Deleted").field("deleted", &self.deleted).field("id", &self.id).finish()
+    }
+}
+
+/// An object of type `T` which may have been deleted.
+///
+/// Some responses (eg. detaching a customer's source) return either the object
+/// itself or, if it was deleted, only its id.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MaybeDeleted<T> {
+    Object(T),
+    Deleted(Deleted<T>),
+}
+
+impl<T> MaybeDeleted<T> {
+    pub fn is_deleted(&self) -> bool {
+        match *self {
+            MaybeDeleted::Object(_) => false,
+            MaybeDeleted::Deleted(_) => true,
+        }
+    }
+
+    /// Returns the object, if it wasn't deleted.
+    pub fn as_object(&self) -> Option<&T> {
+        match *self {
+            MaybeDeleted::Object(ref obj) => Some(obj),
+            MaybeDeleted::Deleted(_) => None,
+        }
+    }
 }
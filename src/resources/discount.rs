@@ -3,13 +3,30 @@ use resources::Coupon;
 
 /// The resource representing a Stripe discount.
 ///
+/// Discounts aren't retrieved on their own but as the `discount` of a customer or subscription,
+/// see `Customer::retrieve_discount` and `Subscription::retrieve_discount`.
+///
 /// For more details see https://stripe.com/docs/api#discounts.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Discount {
+    #[serde(default)]
+    // NOTE: Missing on discounts created before API version 2020-08-27
+    pub id: String,
     pub coupon: Coupon,
-    pub customer: String,
+    pub customer: Option<String>,
+    #[serde(default)]
+    pub checkout_session: Option<String>,
+    #[serde(default)]
+    pub invoice: Option<String>,
+    #[serde(default)]
+    pub invoice_item: Option<String>,
+    #[serde(default)]
+    pub promotion_code: Option<String>,
     pub subscription: Option<String>,
 
     pub start: Timestamp,
     pub end: Option<Timestamp>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
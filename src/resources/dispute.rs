@@ -90,4 +90,7 @@ pub struct Dispute {
     pub metadata: Metadata,
     pub reason: Option<String>,
     pub status: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
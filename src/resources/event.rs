@@ -1,171 +1,216 @@
 use chrono::{Utc};
-use error::{WebhookError};
+use client::Client;
+use error::{Error, WebhookError};
+use params::{to_form, List, RangeQuery, Timestamp};
 use resources::*;
-use hmac::{Hmac, Mac, MacResult};
+use hmac::{Hmac, Mac};
 use serde_json as json;
 use sha2::Sha256;
 use std::str;
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-pub enum EventType {
-    #[serde(rename = "account.updated")]
-    AccountUpdated,
-    #[serde(rename = "account.application.deauthorized")]
-    AccountApplicationDeauthorized,
-    #[serde(rename = "account.external_account.created")]
-    AccountExternalAccountCreated,
-    #[serde(rename = "account.external_account.deleted")]
-    AccountExternalAccountDeleted,
-    #[serde(rename = "account.external_account.updated")]
-    AccountExternalAccountUpdated,
-    #[serde(rename = "application_fee.created")]
-    ApplicationFeeCreated,
-    #[serde(rename = "application_fee.refunded")]
-    ApplicationFeeRefunded,
-    #[serde(rename = "application_fee.refund.updated")]
-    ApplicationFeeRefundUpdated,
-    #[serde(rename = "balance.available")]
-    BalanceAvailable,
-    #[serde(rename = "charge.captured")]
-    ChargeCaptured,
-    #[serde(rename = "charge.failed")]
-    ChargeFailed,
-    #[serde(rename = "charge.pending")]
-    ChargePending,
-    #[serde(rename = "charge.refunded")]
-    ChargeRefunded,
-    #[serde(rename = "charge.succeeded")]
-    ChargeSucceeded,
-    #[serde(rename = "charge.updated")]
-    ChargeUpdated,
-    #[serde(rename = "charge.dispute.closed")]
-    ChargeDisputeClosed,
-    #[serde(rename = "charge.dispute.created")]
-    ChargeDisputeCreated,
-    #[serde(rename = "charge.dispute.funds_reinstated")]
-    ChargeDisputeFundsReinstated,
-    #[serde(rename = "charge.dispute.funds_withdrawn")]
-    ChargeDisputeFundsWithdrawn,
-    #[serde(rename = "charge.dispute.updated")]
-    ChargeDisputeUpdated,
-    #[serde(rename = "charge.refund.updated")]
-    ChargeRefundUpdated,
-    #[serde(rename = "coupon.created")]
-    CouponCreated,
-    #[serde(rename = "coupon.deleted")]
-    CouponDeleted,
-    #[serde(rename = "coupon.updated")]
-    CouponUpdated,
-    #[serde(rename = "customer.created")]
-    CustomerCreated,
-    #[serde(rename = "customer.deleted")]
-    CustomerDeleted,
-    #[serde(rename = "customer.updated")]
-    CustomerUpdated,
-    #[serde(rename = "customer.discount.created")]
-    CustomerDiscountCreated,
-    #[serde(rename = "customer.discount.deleted")]
-    CustomerDiscountDeleted,
-    #[serde(rename = "customer.discount.updated")]
-    CustomerDiscountUpdated,
-    #[serde(rename = "customer.source.created")]
-    CustomerSourceCreated,
-    #[serde(rename = "customer.source.deleted")]
-    CustomerSourceDeleted,
-    #[serde(rename = "customer.source.updated")]
-    CustomerSourceUpdated,
-    #[serde(rename = "customer.subscription.created")]
-    CustomerSubscriptionCreated,
-    #[serde(rename = "customer.subscription.deleted")]
-    CustomerSubscriptionDeleted,
-    #[serde(rename = "customer.subscription.trial_will_end")]
-    CustomerSubscriptionTrialWillEnd,
-    #[serde(rename = "customer.subscription.updated")]
-    CustomerSubscriptionUpdated,
-    #[serde(rename = "file.created")]
-    FileCreated,
-    #[serde(rename = "invoice.created")]
-    InvoiceCreated,
-    #[serde(rename = "invoice.payment_failed")]
-    InvoicePaymentFailed,
-    #[serde(rename = "invoice.payment_succeeded")]
-    InvoicePaymentSucceeded,
-    #[serde(rename = "invoice.updated")]
-    InvoiceUpdated,
-    #[serde(rename = "invoice.upcoming")]
-    InvoiceUpcoming,
-    #[serde(rename = "invoiceitem.created")]
-    InvoiceItemCreated,
-    #[serde(rename = "invoiceitem.deleted")]
-    InvoiceItemDeleted,
-    #[serde(rename = "invoiceitem.updated")]
-    InvoiceItemUpdated,
-    #[serde(rename = "order.created")]
-    OrderCreated,
-    #[serde(rename = "order.payment_failed")]
-    OrderPaymentFailed,
-    #[serde(rename = "order.payment_succeeded")]
-    OrderPaymentSucceeded,
-    #[serde(rename = "order.updated")]
-    OrderUpdated,
-    #[serde(rename = "order_return.updated")]
-    OrderReturnUpdated,
-    #[serde(rename = "payout.canceled")]
-    PayoutCanceled,
-    #[serde(rename = "payout.created")]
-    PayoutCreated,
-    #[serde(rename = "payout.failed")]
-    PayoutFailed,
-    #[serde(rename = "payout.paid")]
-    PayoutPaid,
-    #[serde(rename = "payout.updated")]
-    PayoutUpdated,
-    #[serde(rename = "plan.created")]
-    PlanCreated,
-    #[serde(rename = "plan.deleted")]
-    PlanDeleted,
-    #[serde(rename = "plan.updated")]
-    PlanUpdated,
-    #[serde(rename = "product.created")]
-    ProductCreated,
-    #[serde(rename = "product.deleted")]
-    ProductDeleted,
-    #[serde(rename = "product.updated")]
-    ProductUpdated,
-    #[serde(rename = "review.closed")]
-    ReviewClosed,
-    #[serde(rename = "review.opened")]
-    ReviewOpened,
-    #[serde(rename = "sigma.scheduled_query_run.created")]
-    SigmaScheduledQueryRunCreated,
-    #[serde(rename = "sku.created")]
-    SkuCreated,
-    #[serde(rename = "sku.deleted")]
-    SkuDeleted,
-    #[serde(rename = "sku.updated")]
-    SkuUpdated,
-    #[serde(rename = "source.canceled")]
-    SourceCanceled,
-    #[serde(rename = "source.chargeable")]
-    Sourcechargeable,
-    #[serde(rename = "source.failed")]
-    SourceFailed,
-    #[serde(rename = "source.transaction.created")]
-    SourceTransactionCreated,
-    #[serde(rename = "transfer.created")]
-    TransferCreated,
-    #[serde(rename = "transfer.reversed")]
-    TransferReversed,
-    #[serde(rename = "transfer.updated")]
-    TransferUpdated,
+string_enum! {
+    /// The type of an event.
+    ///
+    /// For more details see https://stripe.com/docs/api/events/types.
+    pub enum EventType {
+        AccountUpdated => "account.updated",
+        AccountApplicationDeauthorized => "account.application.deauthorized",
+        AccountExternalAccountCreated => "account.external_account.created",
+        AccountExternalAccountDeleted => "account.external_account.deleted",
+        AccountExternalAccountUpdated => "account.external_account.updated",
+        ApplicationFeeCreated => "application_fee.created",
+        ApplicationFeeRefunded => "application_fee.refunded",
+        ApplicationFeeRefundUpdated => "application_fee.refund.updated",
+        BalanceAvailable => "balance.available",
+        CapabilityUpdated => "capability.updated",
+        ChargeCaptured => "charge.captured",
+        ChargeFailed => "charge.failed",
+        ChargePending => "charge.pending",
+        ChargeRefunded => "charge.refunded",
+        ChargeSucceeded => "charge.succeeded",
+        ChargeUpdated => "charge.updated",
+        ChargeDisputeClosed => "charge.dispute.closed",
+        ChargeDisputeCreated => "charge.dispute.created",
+        ChargeDisputeFundsReinstated => "charge.dispute.funds_reinstated",
+        ChargeDisputeFundsWithdrawn => "charge.dispute.funds_withdrawn",
+        ChargeDisputeUpdated => "charge.dispute.updated",
+        ChargeRefundUpdated => "charge.refund.updated",
+        CouponCreated => "coupon.created",
+        CouponDeleted => "coupon.deleted",
+        CouponUpdated => "coupon.updated",
+        CreditNoteCreated => "credit_note.created",
+        CreditNoteUpdated => "credit_note.updated",
+        CreditNoteVoided => "credit_note.voided",
+        CustomerCreated => "customer.created",
+        CustomerDeleted => "customer.deleted",
+        CustomerUpdated => "customer.updated",
+        CustomerDiscountCreated => "customer.discount.created",
+        CustomerDiscountDeleted => "customer.discount.deleted",
+        CustomerDiscountUpdated => "customer.discount.updated",
+        CustomerSourceCreated => "customer.source.created",
+        CustomerSourceDeleted => "customer.source.deleted",
+        CustomerSourceUpdated => "customer.source.updated",
+        CustomerSubscriptionCreated => "customer.subscription.created",
+        CustomerSubscriptionDeleted => "customer.subscription.deleted",
+        CustomerSubscriptionTrialWillEnd => "customer.subscription.trial_will_end",
+        CustomerSubscriptionUpdated => "customer.subscription.updated",
+        CustomerTaxIdCreated => "customer.tax_id.created",
+        CustomerTaxIdDeleted => "customer.tax_id.deleted",
+        CustomerTaxIdUpdated => "customer.tax_id.updated",
+        FileCreated => "file.created",
+        InvoiceCreated => "invoice.created",
+        InvoicePaymentFailed => "invoice.payment_failed",
+        InvoicePaymentSucceeded => "invoice.payment_succeeded",
+        InvoiceUpdated => "invoice.updated",
+        InvoiceUpcoming => "invoice.upcoming",
+        InvoiceItemCreated => "invoiceitem.created",
+        InvoiceItemDeleted => "invoiceitem.deleted",
+        InvoiceItemUpdated => "invoiceitem.updated",
+        IssuingAuthorizationCreated => "issuing_authorization.created",
+        IssuingAuthorizationRequest => "issuing_authorization.request",
+        IssuingAuthorizationUpdated => "issuing_authorization.updated",
+        IssuingCardCreated => "issuing_card.created",
+        IssuingCardUpdated => "issuing_card.updated",
+        IssuingCardholderCreated => "issuing_cardholder.created",
+        IssuingCardholderUpdated => "issuing_cardholder.updated",
+        IssuingDisputeClosed => "issuing_dispute.closed",
+        IssuingDisputeCreated => "issuing_dispute.created",
+        IssuingDisputeFundsReinstated => "issuing_dispute.funds_reinstated",
+        IssuingDisputeSubmitted => "issuing_dispute.submitted",
+        IssuingDisputeUpdated => "issuing_dispute.updated",
+        IssuingTransactionCreated => "issuing_transaction.created",
+        IssuingTransactionUpdated => "issuing_transaction.updated",
+        OrderCreated => "order.created",
+        OrderPaymentFailed => "order.payment_failed",
+        OrderPaymentSucceeded => "order.payment_succeeded",
+        OrderUpdated => "order.updated",
+        OrderReturnUpdated => "order_return.updated",
+        PaymentIntentAmountCapturableUpdated => "payment_intent.amount_capturable_updated",
+        PaymentIntentCanceled => "payment_intent.canceled",
+        PaymentIntentCreated => "payment_intent.created",
+        PaymentIntentPaymentFailed => "payment_intent.payment_failed",
+        PaymentIntentProcessing => "payment_intent.processing",
+        PaymentIntentRequiresAction => "payment_intent.requires_action",
+        PaymentIntentSucceeded => "payment_intent.succeeded",
+        PayoutCanceled => "payout.canceled",
+        PayoutCreated => "payout.created",
+        PayoutFailed => "payout.failed",
+        PayoutPaid => "payout.paid",
+        PayoutUpdated => "payout.updated",
+        PersonCreated => "person.created",
+        PersonDeleted => "person.deleted",
+        PersonUpdated => "person.updated",
+        PlanCreated => "plan.created",
+        PlanDeleted => "plan.deleted",
+        PlanUpdated => "plan.updated",
+        PriceCreated => "price.created",
+        PriceDeleted => "price.deleted",
+        PriceUpdated => "price.updated",
+        ProductCreated => "product.created",
+        ProductDeleted => "product.deleted",
+        ProductUpdated => "product.updated",
+        RadarEarlyFraudWarningCreated => "radar.early_fraud_warning.created",
+        RadarEarlyFraudWarningUpdated => "radar.early_fraud_warning.updated",
+        ReviewClosed => "review.closed",
+        ReviewOpened => "review.opened",
+        SigmaScheduledQueryRunCreated => "sigma.scheduled_query_run.created",
+        SkuCreated => "sku.created",
+        SkuDeleted => "sku.deleted",
+        SkuUpdated => "sku.updated",
+        SourceCanceled => "source.canceled",
+        Sourcechargeable => "source.chargeable",
+        SourceFailed => "source.failed",
+        SourceTransactionCreated => "source.transaction.created",
+        SubscriptionScheduleAborted => "subscription_schedule.aborted",
+        SubscriptionScheduleCanceled => "subscription_schedule.canceled",
+        SubscriptionScheduleCompleted => "subscription_schedule.completed",
+        SubscriptionScheduleCreated => "subscription_schedule.created",
+        SubscriptionScheduleExpiring => "subscription_schedule.expiring",
+        SubscriptionScheduleReleased => "subscription_schedule.released",
+        SubscriptionScheduleUpdated => "subscription_schedule.updated",
+        TaxRateCreated => "tax_rate.created",
+        TaxRateUpdated => "tax_rate.updated",
+        TransferCreated => "transfer.created",
+        TransferReversed => "transfer.reversed",
+        TransferUpdated => "transfer.updated",
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Event {
+    pub id: String,
+    #[serde(default)]
+    pub account: Option<String>, // (only set on events of connected accounts, sent to Connect webhooks)
     #[serde(rename = "type")]
     pub event_type: EventType,
     pub data: EventData,
     // ...
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+/// The set of parameters that can be used when listing events.
+///
+/// For more details see https://stripe.com/docs/api/events/list.
+#[derive(Debug, Default, Serialize)]
+pub struct EventListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_success: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<EventType>>,
+}
+
+setters! {
+    EventListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        delivery_success: bool,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        types: Vec<EventType>,
+    }
+}
+
+/// The account an event happened on, see `Event::origin`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EventOrigin<'a> {
+    /// The event happened on the platform's own account.
+    Platform,
+    /// The event happened on a connected account, with the given id.
+    ConnectedAccount(&'a str),
+}
+
+impl Event {
+    /// Retrieves the details of an event.
+    ///
+    /// For more details see https://stripe.com/docs/api/events/retrieve.
+    pub fn retrieve(client: &Client, event_id: &str) -> Result<Event, Error> {
+        client.get(&format!("/events/{}", event_id))
+    }
+
+    /// Lists the events of the last 30 days, most recent first.
+    ///
+    /// For more details see https://stripe.com/docs/api/events/list.
+    pub fn list(client: &Client, params: EventListParams) -> Result<List<Event>, Error> {
+        client.get(&format!("/events?{}", to_form(&params)?))
+    }
+
+    /// Whether the event happened on the platform's account or on one of its connected accounts.
+    pub fn origin<'a>(&'a self) -> EventOrigin<'a> {
+        match self.account {
+            Some(ref account) => EventOrigin::ConnectedAccount(account),
+            None => EventOrigin::Platform,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -183,55 +228,80 @@ pub enum EventObject {
     ApplicationFeeRefund(ApplicationFeeRefund),
     Balance(Balance),
     BankAccount(BankAccount),
+    Capability(Capability),
     Charge(Charge),
+    CreditNote(CreditNote),
+    Discount(Discount),
     Dispute(Dispute),
     File(File),
     Invoice(Invoice),
     InvoiceItem(InvoiceItem),
+    #[serde(rename = "issuing.authorization")]
+    IssuingAuthorization(issuing::Authorization),
+    #[serde(rename = "issuing.card")]
+    IssuingCard(issuing::Card),
+    #[serde(rename = "issuing.cardholder")]
+    IssuingCardholder(issuing::Cardholder),
+    #[serde(rename = "issuing.dispute")]
+    IssuingDispute(issuing::Dispute),
+    #[serde(rename = "issuing.transaction")]
+    IssuingTransaction(issuing::Transaction),
     Order(Order),
     OrderReturn(OrderReturn),
+    PaymentIntent(PaymentIntent),
     Payout(Payout),
+    Person(Person),
     Plan(Plan),
+    Price(Price),
     Product(Product),
+    #[serde(rename = "radar.early_fraud_warning")]
+    RadarEarlyFraudWarning(radar::EarlyFraudWarning),
     Refund(Refund),
     Review(Review),
     Sku(Sku),
+    SourceTransaction(SourceTransaction),
     Subscription(Subscription),
+    SubscriptionSchedule(SubscriptionSchedule),
+    TaxId(TaxId),
+    TaxRate(TaxRate),
     Transaction(Transaction),
     Transfer(Transfer),
+    /// An object of a type this crate doesn't know yet.
+    #[serde(other)]
+    Unknown,
 }
 
 pub struct Webhook {}
 
 impl Webhook {
     pub fn construct_event(payload: String, sig: String, secret: String) -> Result<Event, WebhookError> {
-        let mut headers: Vec<String> = sig.split(",").map(|s| s.trim().to_string()).collect();
+        let header = SignatureHeader::parse(&sig)?;
 
         // Prepare the signed payload
-        let ref mut timestamp: Vec<String> = headers[0].split("=").map(|s| s.to_string()).collect();
-        let signed_payload = format!("{}{}{}", timestamp[1], ".", payload);
-
-        // Get Stripe signature from header
-        let ref mut signature: Vec<String> = headers[1].split("=").map(|s| s.to_string()).collect();
+        let signed_payload = format!("{}{}{}", header.timestamp, ".", payload);
 
         // Compute HMAC with the SHA256 hash function, using endpoing secret as key and signed_payload string as the message
         let mut mac = Hmac::<Sha256>::new(secret.as_bytes());
         mac.input(signed_payload.as_bytes());
 
         let result = mac.result();
-
-        let bytes_signature = MacResult::from_slice(signature[1].as_bytes());
+        let expected = result.code();
 
         // Get current timestamp to compare to signature timestamp
         let current = Utc::now().timestamp();
-        let num_timestamp = timestamp[1].parse::<i64>()
-            .map_err(|err| WebhookError::BadHeader(err))?;
+        let num_timestamp = header.timestamp;
 
-        if bytes_signature != result {
+        // Any of the header's signatures may match (there are several while the endpoint secret is being rolled)
+        let matches = |signature: &&str| match decode_hex(signature) {
+            Some(signature) => constant_time_eq(&signature, expected),
+            None => false,
+        };
+        if !header.signatures.iter().any(matches) {
             return Err(WebhookError::BadSignature);
         }
 
-        if current - num_timestamp > 300 {
+        // Reject timestamps outside the tolerance in either direction, so a signature can't be replayed later
+        if (current - num_timestamp).abs() > 300 {
             return Err(WebhookError::BadTimestamp(num_timestamp));
         }
 
@@ -239,3 +309,139 @@ impl Webhook {
         return json::from_str(&payload).map_err(|err| WebhookError::BadParse(err));
     }
 }
+
+/// Decodes a hex-encoded signature, eg. the value of a `v1` element.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    fn digit(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    hex.chunks(2).map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?)).collect()
+}
+
+/// Compares two byte strings in time which depends only on their lengths, not their contents.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// The parsed `Stripe-Signature` header of a webhook request, eg. `t=1492774577,v1=5257a869...`.
+#[derive(Debug, PartialEq)]
+struct SignatureHeader<'a> {
+    timestamp: i64,
+    signatures: Vec<&'a str>,
+}
+
+impl<'a> SignatureHeader<'a> {
+    fn parse(header: &'a str) -> Result<SignatureHeader<'a>, WebhookError> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for element in header.split(',').map(|s| s.trim()) {
+            let mut parts = element.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some("t"), Some(value)) => {
+                    let value = value.parse::<i64>().map_err(|_| {
+                        WebhookError::MalformedHeader(format!("invalid timestamp `{}`", value))
+                    })?;
+                    timestamp = Some(value);
+                }
+                (Some("v1"), Some(value)) => signatures.push(value),
+                // Other schemes (eg. the `v0` test signatures) aren't verified
+                (Some(_), Some(_)) => {}
+                _ => return Err(WebhookError::MalformedHeader(format!("expected `key=value`, got `{}`", element))),
+            }
+        }
+
+        let timestamp = timestamp.ok_or(WebhookError::MissingTimestamp)?;
+        if signatures.is_empty() {
+            return Err(WebhookError::MissingSignature);
+        }
+        Ok(SignatureHeader { timestamp, signatures })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_hex, SignatureHeader, Webhook};
+    use chrono::Utc;
+    use error::WebhookError;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    const SECRET: &str = "whsec_test_secret";
+    const PAYLOAD: &str = r#"{
+        "id": "evt_123",
+        "type": "balance.available",
+        "data": {"object": {"object": "balance", "available": [], "livemode": false, "pending": []}}
+    }"#;
+
+    fn sign(timestamp: i64, payload: &str, secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new(secret.as_bytes());
+        mac.input(format!("{}.{}", timestamp, payload).as_bytes());
+        mac.result().code().iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn parse_signature_header() {
+        let header = SignatureHeader::parse("t=1492774577, v1=abc,v0=def,v1=ghi").unwrap();
+        assert_eq!(header, SignatureHeader { timestamp: 1492774577, signatures: vec!["abc", "ghi"] });
+    }
+
+    #[test]
+    fn parse_malformed_signature_headers() {
+        let missing_timestamp = SignatureHeader::parse("v1=abc");
+        assert!(matches!(missing_timestamp, Err(WebhookError::MissingTimestamp)));
+        let missing_signature = SignatureHeader::parse("t=1492774577,v0=abc");
+        assert!(matches!(missing_signature, Err(WebhookError::MissingSignature)));
+
+        for header in &["", "t=1492774577,", "t=1492774577,v1", "t=now,v1=abc", "t=,v1=abc"] {
+            match SignatureHeader::parse(header) {
+                Err(WebhookError::MalformedHeader(_)) => {}
+                other => panic!("expected `{}` to be malformed, got {:?}", header, other),
+            }
+        }
+    }
+
+    #[test]
+    fn decode_hex_signatures() {
+        assert_eq!(decode_hex("00ff7A"), Some(vec![0x00, 0xff, 0x7a]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+
+    #[test]
+    fn construct_signed_event() {
+        let now = Utc::now().timestamp();
+        let header = format!("t={},v1={},v1={}", now, sign(now, PAYLOAD, "whsec_old_secret"), sign(now, PAYLOAD, SECRET));
+        let event = Webhook::construct_event(PAYLOAD.to_string(), header, SECRET.to_string()).unwrap();
+        assert_eq!(event.id, "evt_123");
+    }
+
+    #[test]
+    fn reject_bad_signatures_and_timestamps() {
+        let now = Utc::now().timestamp();
+        let construct = |timestamp: i64, secret: &str| {
+            let header = format!("t={},v1={}", timestamp, sign(timestamp, PAYLOAD, secret));
+            Webhook::construct_event(PAYLOAD.to_string(), header, SECRET.to_string())
+        };
+
+        assert!(matches!(construct(now, "whsec_other"), Err(WebhookError::BadSignature)));
+        assert!(matches!(construct(now - 301, SECRET), Err(WebhookError::BadTimestamp(_))));
+        assert!(matches!(construct(now + 301, SECRET), Err(WebhookError::BadTimestamp(_))));
+
+        let tampered = format!("t={},v1={}", now, sign(now, PAYLOAD, SECRET));
+        let result = Webhook::construct_event(PAYLOAD.replace("evt_123", "evt_456"), tampered, SECRET.to_string());
+        assert!(matches!(result, Err(WebhookError::BadSignature)));
+    }
+}
@@ -0,0 +1,60 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List};
+use resources::Currency;
+use std::collections::HashMap;
+
+/// The set of parameters that can be used when listing exchange rates.
+///
+/// For more details see https://stripe.com/docs/api/exchange_rates/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ExchangeRateListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ExchangeRateListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing the Stripe exchange rates from one currency to all others.
+///
+/// For more details see https://stripe.com/docs/api/exchange_rates/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExchangeRate {
+    pub id: String, // (the source currency, eg. "usd")
+    pub object: String,
+    pub rates: HashMap<String, f64>, // (keyed by currency code, including ones `Currency` doesn't know)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ExchangeRate {
+    /// Retrieves the exchange rates from the given currency to every supported currency.
+    ///
+    /// For more details see https://stripe.com/docs/api/exchange_rates/retrieve.
+    pub fn retrieve(client: &Client, currency: Currency) -> Result<ExchangeRate, Error> {
+        client.get(&format!("/exchange_rates/{}", currency))
+    }
+
+    /// Lists the exchange rates of all supported currencies.
+    ///
+    /// For more details see https://stripe.com/docs/api/exchange_rates/list.
+    pub fn list(client: &Client, params: ExchangeRateListParams) -> Result<List<ExchangeRate>, Error> {
+        client.get(&format!("/exchange_rates?{}", to_form(&params)?))
+    }
+
+    /// The exchange rate to the given currency, if Stripe supports converting to it.
+    pub fn rate(&self, currency: Currency) -> Option<f64> {
+        self.rates.get(&currency.to_string()).cloned()
+    }
+}
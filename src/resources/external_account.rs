@@ -0,0 +1,269 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata};
+use path::Path;
+use resources::{BankAccount, Card, Currency, Deleted};
+use std::collections::HashMap;
+use std::iter::Chain;
+use std::{option, slice};
+
+string_enum! {
+    /// The kind of an external account.
+    pub enum ExternalAccountType {
+        BankAccount => "bank_account",
+        Card => "card",
+    }
+}
+
+/// The set of parameters that can be used when creating an external account.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/create.
+#[derive(Debug, Serialize)]
+pub struct ExternalAccountParams<'a> {
+    pub external_account: &'a str, // (a bank account or debit card token)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    ExternalAccountParams<'a> {
+        default_for_currency: bool,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when updating an external account.
+///
+/// Setting `default_for_currency` makes the account the payout destination for its currency.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/update.
+#[derive(Debug, Default, Serialize)]
+pub struct ExternalAccountUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual or company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<u32>, // (cards only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<u32>, // (cards only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    ExternalAccountUpdateParams<'a> {
+        account_holder_name: &'a str,
+        account_holder_type: &'a str,
+        default_for_currency: bool,
+        exp_month: u32,
+        exp_year: u32,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing external accounts.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ExternalAccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<ExternalAccountType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ExternalAccountListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        object: ExternalAccountType,
+        starting_after: &'a str,
+    }
+}
+
+/// A payout destination of a connected account, either a bank account or a debit card.
+///
+/// For more details see https://stripe.com/docs/api/external_accounts.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum ExternalAccount {
+    BankAccount(BankAccount),
+    Card(Card),
+}
+
+impl ExternalAccount {
+    pub fn id(&self) -> &str {
+        match *self {
+            ExternalAccount::BankAccount(ref account) => &account.id,
+            ExternalAccount::Card(ref card) => &card.id,
+        }
+    }
+
+    /// The currency paid out to the account.
+    pub fn currency(&self) -> Option<Currency> {
+        match *self {
+            ExternalAccount::BankAccount(ref account) => Some(account.currency),
+            ExternalAccount::Card(ref card) => card.currency,
+        }
+    }
+
+    /// Whether the account is the payout destination for its currency.
+    pub fn is_default_for_currency(&self) -> bool {
+        let default = match *self {
+            ExternalAccount::BankAccount(ref account) => account.default_for_currency,
+            ExternalAccount::Card(ref card) => card.default_for_currency,
+        };
+        default.unwrap_or(false)
+    }
+
+    /// Creates a new external account on a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/create.
+    pub fn create(client: &Client, account_id: &str, params: ExternalAccountParams) -> Result<ExternalAccount, Error> {
+        client.post(&format!("/accounts/{}/external_accounts", account_id), params)
+    }
+
+    /// Retrieves the details of an external account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str, external_account_id: &str) -> Result<ExternalAccount, Error> {
+        client.get(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id))
+    }
+
+    /// Updates an external account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/update.
+    pub fn update(client: &Client, account_id: &str, external_account_id: &str, params: ExternalAccountUpdateParams) -> Result<ExternalAccount, Error> {
+        client.post(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id), params)
+    }
+
+    /// Deletes an external account.
+    ///
+    /// The default external account for a currency can't be deleted.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/delete.
+    pub fn delete(client: &Client, account_id: &str, external_account_id: &str) -> Result<Deleted<ExternalAccount>, Error> {
+        client.delete(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id))
+    }
+
+    /// Lists the external accounts of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/list.
+    pub fn list(client: &Client, account_id: &str, params: ExternalAccountListParams) -> Result<List<ExternalAccount>, Error> {
+        client.get(&format!("/accounts/{}/external_accounts?{}", account_id, to_form(&params)?))
+    }
+
+    /// Makes an external account the payout destination for its currency.
+    ///
+    /// The previous default account of the currency stays attached to the connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/update#account_update_bank_account-default_for_currency.
+    pub fn set_default_for_currency(client: &Client, account_id: &str, external_account_id: &str) -> Result<ExternalAccount, Error> {
+        let params = ExternalAccountUpdateParams { default_for_currency: Some(true), ..Default::default() };
+        client.post(&Path::new("/accounts").id(account_id)?.segment("external_accounts").id(external_account_id)?, params)
+    }
+
+    /// Lists all the external accounts of a connected account, grouped by currency.
+    pub fn list_by_currency(client: &Client, account_id: &str) -> Result<HashMap<Currency, CurrencyExternalAccounts>, Error> {
+        let params = ExternalAccountListParams { limit: Some(100), ..Default::default() };
+        let path = Path::new("/accounts").id(account_id)?.segment("external_accounts").query(&to_form(&params)?);
+        let accounts: List<ExternalAccount> = client.get(&path)?;
+
+        let mut by_currency: HashMap<Currency, CurrencyExternalAccounts> = HashMap::new();
+        for account in accounts.paginate(client, &params)? {
+            let account = account?;
+            let currency = match account.currency() {
+                Some(currency) => currency,
+                None => continue,
+            };
+            by_currency.entry(currency).or_insert_with(CurrencyExternalAccounts::default).push(account);
+        }
+        Ok(by_currency)
+    }
+}
+
+/// The external accounts of a connected account in one currency, see `ExternalAccount::list_by_currency`.
+#[derive(Debug, Default)]
+pub struct CurrencyExternalAccounts {
+    /// The payout destination of the currency.
+    pub default: Option<ExternalAccount>,
+    /// The other accounts in the currency, which can be made the default with `ExternalAccount::set_default_for_currency`.
+    pub others: Vec<ExternalAccount>,
+}
+
+impl CurrencyExternalAccounts {
+    fn push(&mut self, account: ExternalAccount) {
+        if account.is_default_for_currency() && self.default.is_none() {
+            self.default = Some(account);
+        } else {
+            self.others.push(account);
+        }
+    }
+
+    /// All the accounts in the currency, starting with the default.
+    pub fn iter<'a>(&'a self) -> Chain<option::Iter<'a, ExternalAccount>, slice::Iter<'a, ExternalAccount>> {
+        self.default.iter().chain(self.others.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurrencyExternalAccounts, ExternalAccount};
+    use client::testing::stub;
+    use resources::Currency;
+    use serde_json as json;
+
+    fn bank_account(id: &str, currency: &str, default: bool) -> String {
+        format!(
+            r#"{{"id": "{}", "object": "bank_account", "account_holder_name": null, "account_holder_type": null,
+                "bank_name": null, "country": "US", "currency": "{}", "default_for_currency": {},
+                "fingerprint": null, "last4": "6789", "routing_number": null, "status": "new"}}"#,
+            id, currency, default
+        )
+    }
+
+    fn page(accounts: &[String], has_more: bool) -> String {
+        format!(r#"{{"data": [{}], "has_more": {}, "url": "/v1/accounts/acct_1/external_accounts"}}"#, accounts.join(", "), has_more)
+    }
+
+    #[test]
+    fn push_keeps_the_first_default() {
+        let mut accounts = CurrencyExternalAccounts::default();
+        for &(id, default) in &[("ba_1", false), ("ba_2", true), ("ba_3", true)] {
+            accounts.push(json::from_str::<ExternalAccount>(&bank_account(id, "usd", default)).unwrap());
+        }
+        assert_eq!(accounts.default.as_ref().map(|account| account.id()), Some("ba_2"));
+        assert_eq!(accounts.iter().map(|account| account.id()).collect::<Vec<_>>(), vec!["ba_2", "ba_1", "ba_3"]);
+    }
+
+    #[test]
+    fn list_by_currency_groups_every_page() {
+        let (client, paths) = stub(2, |path| {
+            if path.contains("starting_after=ba_2") {
+                (200, page(&[bank_account("ba_3", "eur", true)], false))
+            } else {
+                (200, page(&[bank_account("ba_1", "usd", false), bank_account("ba_2", "usd", true)], true))
+            }
+        });
+        let by_currency = ExternalAccount::list_by_currency(&client, "acct_1").unwrap();
+        assert_eq!(by_currency.len(), 2);
+        let usd = &by_currency[&Currency::USD];
+        assert_eq!(usd.default.as_ref().map(|account| account.id()), Some("ba_2"));
+        assert_eq!(usd.others.iter().map(|account| account.id()).collect::<Vec<_>>(), vec!["ba_1"]);
+        assert_eq!(by_currency[&Currency::EUR].default.as_ref().map(|account| account.id()), Some("ba_3"));
+        assert_eq!(
+            paths.join().unwrap(),
+            vec!["/v1/accounts/acct_1/external_accounts?limit=100", "/v1/accounts/acct_1/external_accounts?limit=100&starting_after=ba_2"]
+        );
+    }
+}
@@ -1,5 +1,24 @@
+use error::Error;
+use client::Client;
 use params::Timestamp;
 
+string_enum! {
+    /// The purpose of an uploaded file.
+    ///
+    /// For more details see https://stripe.com/docs/file-upload#uploading-a-file.
+    pub enum FilePurpose {
+        AccountRequirement => "account_requirement",
+        AdditionalVerification => "additional_verification",
+        BusinessIcon => "business_icon",
+        BusinessLogo => "business_logo",
+        CustomerSignature => "customer_signature",
+        DisputeEvidence => "dispute_evidence",
+        IdentityDocument => "identity_document",
+        PciDocument => "pci_document",
+        TaxDocumentUserUpload => "tax_document_user_upload",
+    }
+}
+
 /// The resource representing a Stripe file.
 ///
 /// For more details see https://stripe.com/docs/api#file_object.
@@ -8,10 +27,29 @@ pub struct File {
     pub id: String,
     pub object: String,
     pub created: Timestamp,
-    pub purpose: String,
+    pub purpose: FilePurpose,
     pub size: u64,
     pub title: Option<String>,
     #[serde(rename = "type")]
     pub file_type: String, // (csv, pdf, jpg, png)
-    pub url: String,
+    pub url: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl File {
+    /// Uploads a file, eg. an identity document or dispute evidence.
+    ///
+    /// For more details see https://stripe.com/docs/api/files/create.
+    pub fn create(client: &Client, purpose: FilePurpose, file_name: &str, contents: &[u8]) -> Result<File, Error> {
+        client.upload("https://files.stripe.com/v1/files", &[("purpose", purpose.as_str())], file_name, contents)
+    }
+
+    /// Downloads the contents of a file.
+    ///
+    /// For more details see https://stripe.com/docs/file-upload#download-file-contents.
+    pub fn download(&self, client: &Client) -> Result<Vec<u8>, Error> {
+        client.download(&format!("https://files.stripe.com/v1/files/{}/contents", self.id))
+    }
 }
@@ -23,4 +23,7 @@ pub struct InvoiceItem {
     pub quantity: u64,
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
@@ -1,8 +1,7 @@
 use error::Error;
 use client::Client;
-use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Currency, Discount, Plan};
-use serde_qs as qs;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{CollectionMethod, Currency, Discount, ItemParams, Plan, ProrationBehavior, TaxRate};
 
 /// The set of parameters that can be used when creating or updating an invoice.
 ///
@@ -12,10 +11,22 @@ pub struct InvoiceParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<&'a str>,
@@ -28,6 +39,35 @@ pub struct InvoiceParams<'a> {
     pub forgiven: Option<bool>,
 }
 
+setters! {
+    InvoiceParams<'a> {
+        application_fee: u64,
+        auto_advance: bool,
+        collection_method: CollectionMethod,
+        customer: &'a str,
+        days_until_due: u32,
+        default_tax_rates: Vec<&'a str>,
+        description: &'a str,
+        due_date: Timestamp,
+        metadata: Metadata,
+        statement_descriptor: &'a str,
+        subscription: &'a str,
+        tax_percent: f64,
+        closed: bool,
+        forgiven: bool,
+    }
+}
+
+impl<'a> InvoiceParams<'a> {
+    /// Creates the parameters for a new invoice of `customer`.
+    pub fn new(customer: &'a str) -> Self {
+        InvoiceParams {
+            customer: Some(customer),
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct InvoiceLineItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,48 +83,76 @@ pub struct InvoiceLineItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<bool>,
+    pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<&'a str>>,
 }
 
-/*
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InvoiceListLinesParams {
-    #[serde(skip_serializing_if = "Option::is_none")] pub limit: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub ending_before: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub starting_after: Option<String>,
+setters! {
+    InvoiceLineItemParams<'a> {
+        amount: i64,
+        currency: Currency,
+        customer: &'a str,
+        description: &'a str,
+        discountable: bool,
+        invoice: &'a str,
+        metadata: Metadata,
+        subscription: bool,
+        tax_rates: Vec<&'a str>,
+    }
+}
 
-    ..
+/// The set of parameters that can be used when finalizing a draft invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/finalize.
+#[derive(Debug, Default, Serialize)]
+pub struct InvoiceFinalizeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+}
+
+setters! {
+    InvoiceFinalizeParams {
+        auto_advance: bool,
+    }
+}
+
+/// The set of parameters that can be used when listing the line items of an invoice.
+///
+/// For more details see https://stripe.com/docs/api#invoice_lines.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceListLinesParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    InvoiceListLinesParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
 }
-*/
 
 #[derive(Debug, Default, Serialize)]
 pub struct InvoiceUpcomingParams<'a> {
     pub customer: &'a str,   // this is a required param
     #[serde(skip_serializing_if = "Option::is_none")] pub coupon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub subscription_items: Option<SubscriptionItemParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub subscription_items: Option<Vec<ItemParams<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub subscription_proration_behavior: Option<ProrationBehavior>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_tax_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_trial_end: Option<Timestamp>,
 }
 
-#[derive(Debug, Serialize)]
-pub struct SubscriptionItemParams<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<Metadata>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<u64>,
-}
-
 /// Period is a structure representing a start and end dates.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Period {
@@ -111,9 +179,14 @@ pub struct InvoiceLineItem {
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
     #[serde(default)]
+    pub tax_rates: Vec<TaxRate>,
+    #[serde(default)]
     // NOTE: Missing in response to InvoiceLineItem create
     #[serde(rename = "type")]
     pub item_type: String, // (invoiceitem, subscription)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 /// The resource representing a Stripe invoice.
@@ -126,15 +199,27 @@ pub struct Invoice {
     pub application_fee: Option<u64>,
     pub attempt_count: u64,
     pub attempted: bool,
+    #[serde(default)]
+    pub auto_advance: Option<bool>,
     pub charge: Option<String>,
     pub closed: bool,
+    #[serde(default)]
+    pub collection_method: Option<CollectionMethod>,
     pub currency: Currency,
     pub customer: String,
     pub date: Timestamp,
+    #[serde(default)]
+    pub default_tax_rates: Vec<TaxRate>,
     pub description: Option<String>,
     pub discount: Option<Discount>,
+    #[serde(default)]
+    pub due_date: Option<Timestamp>, // only for invoices sent to the customer
     pub ending_balance: Option<i64>,
     pub forgiven: bool,
+    #[serde(default)]
+    pub hosted_invoice_url: Option<String>, // not present on drafts
+    #[serde(default)]
+    pub invoice_pdf: Option<String>, // not present on drafts
     pub lines: List<InvoiceLineItem>,
     pub livemode: bool,
     pub metadata: Metadata,
@@ -152,6 +237,9 @@ pub struct Invoice {
     pub tax_percent: Option<f64>,
     pub total: i64,
     pub webhooks_delivered_at: Option<Timestamp>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -170,6 +258,17 @@ pub struct InvoiceListParams<'a> {
     pub subscription: Option<&'a str>,
 }
 
+setters! {
+    InvoiceListParams<'a> {
+        customer: &'a str,
+        date: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        subscription: &'a str,
+    }
+}
+
 impl Invoice {
     /// Creates a new invoice.
     ///
@@ -178,6 +277,16 @@ impl Invoice {
         client.post("/invoices", params)
     }
 
+    /// Downloads the PDF of an invoice, following its `invoice_pdf` url.
+    ///
+    /// Returns `None` if the invoice has no PDF yet (e.g. it is still a draft).
+    pub fn download_pdf(&self, client: &Client) -> Result<Option<Vec<u8>>, Error> {
+        match self.invoice_pdf {
+            Some(ref url) => client.download(url).map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// Retrieves the details of an invoice.
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_invoice.
@@ -185,16 +294,44 @@ impl Invoice {
         client.get(&format!("/invoices/{}", invoice_id))
     }
 
-    // TODO: Implement InvoiceListLinesParams
-    // pub fn get_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceLineItem>, Error> {
-    //     client.get(&format!("/invoices/{}/lines", invoice_id))
-    // }
+    /// Lists the line items of an invoice.
+    ///
+    /// Invoices only embed the first few line items in `lines`,
+    /// use this request to page through the remaining items.
+    ///
+    /// For more details see https://stripe.com/docs/api#invoice_lines.
+    pub fn list_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceLineItem>, Error> {
+        client.get(&format!("/invoices/{}/lines?{}", invoice_id, to_form(&params)?))
+    }
 
     /// Retrieves the details of an upcoming invoice_id
     ///
     /// For more details see https://stripe.com/docs/api#upcoming_invoice
     pub fn upcoming(client: &Client, params: InvoiceUpcomingParams) -> Result<Invoice, Error> {
-        client.get(&format!("/invoices/upcoming?{}", qs::to_string(&params)?))
+        client.get(&format!("/invoices/upcoming?{}", to_form(&params)?))
+    }
+
+    /// Finalizes a draft invoice, after which it can be paid or sent.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/finalize.
+    pub fn finalize(client: &Client, invoice_id: &str, params: InvoiceFinalizeParams) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/finalize", invoice_id), params)
+    }
+
+    /// Emails an invoice to the customer, with a link to its hosted payment page.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/send.
+    pub fn send(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&format!("/invoices/{}/send", invoice_id))
+    }
+
+    /// Finalizes a draft invoice and immediately emails it to the customer,
+    /// for invoices with the `send_invoice` collection method.
+    ///
+    /// Automatic advancement is turned off so Stripe doesn't send the invoice a second time.
+    pub fn finalize_and_send(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        Invoice::finalize(client, invoice_id, InvoiceFinalizeParams { auto_advance: Some(false) })?;
+        Invoice::send(client, invoice_id)
     }
 
     /// Pays an invoice.
@@ -215,7 +352,7 @@ impl Invoice {
     ///
     /// For more details see https://stripe.com/docs/api#list_invoices.
     pub fn list(client: &Client, params: InvoiceListParams) -> Result<List<Invoice>, Error> {
-        client.get(&format!("/invoices?{}", qs::to_string(&params)?))
+        client.get(&format!("/invoices?{}", to_form(&params)?))
     }
 }
 
@@ -0,0 +1,196 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::Currency;
+use resources::issuing::{Card, Transaction};
+
+string_enum! {
+    /// The status of an authorization.
+    pub enum AuthorizationStatus {
+        Closed => "closed",
+        Pending => "pending",
+        Reversed => "reversed",
+    }
+}
+
+string_enum! {
+    /// How the card details were provided to the merchant.
+    pub enum AuthorizationMethod {
+        Chip => "chip",
+        Contactless => "contactless",
+        KeyedIn => "keyed_in",
+        Online => "online",
+        Swipe => "swipe",
+    }
+}
+
+/// Details about the seller involved in an authorization or transaction.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MerchantData {
+    pub category: String,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub name: Option<String>,
+    pub network_id: String,
+    pub postal_code: Option<String>,
+    pub state: Option<String>,
+}
+
+/// The request awaiting an approval decision while an authorization is pending.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PendingRequest {
+    pub amount: u64,
+    pub currency: Currency,
+    pub is_amount_controllable: bool,
+    pub merchant_amount: u64,
+    pub merchant_currency: Currency,
+}
+
+/// A request made to approve or decline an authorization, and its outcome.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RequestHistory {
+    pub amount: u64,
+    pub approved: bool,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub merchant_amount: u64,
+    pub merchant_currency: Currency,
+    pub reason: String,
+}
+
+/// The set of parameters that can be used when approving an authorization.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/approve.
+#[derive(Debug, Default, Serialize)]
+pub struct AuthorizationApproveParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>, // (only for authorizations with is_amount_controllable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    AuthorizationApproveParams {
+        amount: u64,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when declining or updating an authorization.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/decline.
+#[derive(Debug, Default, Serialize)]
+pub struct AuthorizationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    AuthorizationParams {
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing authorizations.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/list.
+#[derive(Debug, Default, Serialize)]
+pub struct AuthorizationListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AuthorizationStatus>,
+}
+
+setters! {
+    AuthorizationListParams<'a> {
+        card: &'a str,
+        cardholder: &'a str,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        status: AuthorizationStatus,
+    }
+}
+
+/// The resource representing a Stripe Issuing authorization.
+///
+/// Pending authorizations can be approved or declined in response to the
+/// `issuing_authorization.request` webhook.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Authorization {
+    pub id: String,
+    pub object: String,
+    pub amount: u64,
+    pub approved: bool,
+    pub authorization_method: AuthorizationMethod,
+    pub card: Card,
+    pub cardholder: Option<String>,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub merchant_amount: u64,
+    pub merchant_currency: Currency,
+    pub merchant_data: MerchantData,
+    pub metadata: Metadata,
+    pub pending_request: Option<PendingRequest>,
+    #[serde(default)]
+    pub request_history: Vec<RequestHistory>,
+    pub status: AuthorizationStatus,
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+    pub wallet: Option<String>, // (apple_pay, google_pay, samsung_pay)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Authorization {
+    /// Retrieves the details of an authorization.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/retrieve.
+    pub fn retrieve(client: &Client, authorization_id: &str) -> Result<Authorization, Error> {
+        client.get(&format!("/issuing/authorizations/{}", authorization_id))
+    }
+
+    /// Updates the metadata of an authorization.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/update.
+    pub fn update(client: &Client, authorization_id: &str, params: AuthorizationParams) -> Result<Authorization, Error> {
+        client.post(&format!("/issuing/authorizations/{}", authorization_id), params)
+    }
+
+    /// Approves a pending authorization.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/approve.
+    pub fn approve(client: &Client, authorization_id: &str, params: AuthorizationApproveParams) -> Result<Authorization, Error> {
+        client.post(&format!("/issuing/authorizations/{}/approve", authorization_id), params)
+    }
+
+    /// Declines a pending authorization.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/decline.
+    pub fn decline(client: &Client, authorization_id: &str, params: AuthorizationParams) -> Result<Authorization, Error> {
+        client.post(&format!("/issuing/authorizations/{}/decline", authorization_id), params)
+    }
+
+    /// Lists all authorizations.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/list.
+    pub fn list(client: &Client, params: AuthorizationListParams) -> Result<List<Authorization>, Error> {
+        client.get(&format!("/issuing/authorizations?{}", to_form(&params)?))
+    }
+}
@@ -0,0 +1,341 @@
+use error::Error;
+use client::Client;
+use params::{to_form, Expand, List, Metadata, RangeQuery, Timestamp};
+use path::Path;
+use resources::{Address, Currency};
+use resources::issuing::{Cardholder, SpendingControls};
+use std::fmt;
+
+string_enum! {
+    /// The type of an issued card.
+    pub enum CardType {
+        Physical => "physical",
+        Virtual => "virtual",
+    }
+}
+
+string_enum! {
+    /// The status of an issued card.
+    ///
+    /// Canceled cards can't be reactivated.
+    pub enum CardStatus {
+        Active => "active",
+        Canceled => "canceled",
+        Inactive => "inactive",
+    }
+}
+
+string_enum! {
+    /// The shipping service used to deliver a physical card.
+    pub enum ShippingService {
+        Express => "express",
+        Priority => "priority",
+        Standard => "standard",
+    }
+}
+
+string_enum! {
+    /// The delivery status of a physical card.
+    pub enum ShippingStatus {
+        Canceled => "canceled",
+        Delivered => "delivered",
+        Failure => "failure",
+        Pending => "pending",
+        Returned => "returned",
+        Shipped => "shipped",
+        Submitted => "submitted",
+    }
+}
+
+string_enum! {
+    /// The carrier delivering a physical card.
+    pub enum ShippingCarrier {
+        Dhl => "dhl",
+        Fedex => "fedex",
+        RoyalMail => "royal_mail",
+        Usps => "usps",
+    }
+}
+
+string_enum! {
+    /// Why a card was replaced by another card.
+    pub enum ReplacementReason {
+        Damaged => "damaged",
+        Expired => "expired",
+        Lost => "lost",
+        Stolen => "stolen",
+    }
+}
+
+impl ReplacementReason {
+    /// Returns true if the card being replaced is canceled when its replacement is created.
+    pub fn cancels_card(&self) -> bool {
+        match *self {
+            ReplacementReason::Lost | ReplacementReason::Stolen => true,
+            ReplacementReason::Damaged | ReplacementReason::Expired | ReplacementReason::Other(_) => false,
+        }
+    }
+}
+
+/// Where and how a physical card is shipped.
+///
+/// The `carrier`, `eta`, `status`, `tracking_number` and `tracking_url` fields are
+/// set by Stripe once the card has been sent, and are never sent in requests.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CardShipping {
+    pub address: Address,
+    pub name: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<ShippingService>,
+
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub carrier: Option<ShippingCarrier>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub eta: Option<Timestamp>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub status: Option<ShippingStatus>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub tracking_number: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub tracking_url: Option<String>,
+}
+
+impl CardShipping {
+    /// Creates the shipping details for a new physical card.
+    pub fn new(name: String, address: Address) -> CardShipping {
+        CardShipping { name, address, ..CardShipping::default() }
+    }
+
+    /// Returns true once the card has reached a final shipping status.
+    pub fn is_finished(&self) -> bool {
+        match self.status {
+            Some(ShippingStatus::Canceled) |
+            Some(ShippingStatus::Delivered) |
+            Some(ShippingStatus::Failure) |
+            Some(ShippingStatus::Returned) => true,
+            Some(_) | None => false,
+        }
+    }
+}
+
+/// The set of parameters that can be used when creating an issued card.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cards/create.
+#[derive(Debug, Serialize)]
+pub struct CardParams<'a> {
+    pub currency: Currency,
+    #[serde(rename = "type")]
+    pub card_type: CardType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_for: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_reason: Option<ReplacementReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CardShipping>, // (physical cards only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardStatus>,
+}
+
+setters! {
+    CardParams<'a> {
+        cardholder: &'a str,
+        metadata: Metadata,
+        replacement_for: &'a str,
+        replacement_reason: ReplacementReason,
+        shipping: CardShipping,
+        spending_controls: SpendingControls,
+        status: CardStatus,
+    }
+}
+
+/// The set of parameters that can be used when updating an issued card.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cards/update.
+#[derive(Debug, Default, Serialize)]
+pub struct CardUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardStatus>,
+}
+
+setters! {
+    CardUpdateParams {
+        metadata: Metadata,
+        spending_controls: SpendingControls,
+        status: CardStatus,
+    }
+}
+
+/// The set of parameters that can be used when listing issued cards.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cards/list.
+#[derive(Debug, Default, Serialize)]
+pub struct CardListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardStatus>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_type: Option<CardType>,
+}
+
+setters! {
+    CardListParams<'a> {
+        cardholder: &'a str,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        last4: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        status: CardStatus,
+        card_type: CardType,
+    }
+}
+
+/// The resource representing a Stripe Issuing card.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cards/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Card {
+    pub id: String,
+    pub object: String,
+    pub brand: String,
+    pub cancellation_reason: Option<String>, // (lost, stolen)
+    pub cardholder: Cardholder,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub exp_month: u32,
+    pub exp_year: u32,
+    pub last4: String,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(default)]
+    pub replaced_by: Option<String>,
+    #[serde(default)]
+    pub replacement_for: Option<String>,
+    #[serde(default)]
+    pub replacement_reason: Option<ReplacementReason>,
+    pub shipping: Option<CardShipping>,
+    #[serde(default)]
+    pub spending_controls: Option<SpendingControls>,
+    pub status: CardStatus,
+    #[serde(rename = "type")]
+    pub card_type: CardType,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Card {
+    /// Creates a new card for a cardholder.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cards/create.
+    pub fn create(client: &Client, params: CardParams) -> Result<Card, Error> {
+        client.post("/issuing/cards", params)
+    }
+
+    /// Retrieves the details of an issued card.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cards/retrieve.
+    pub fn retrieve(client: &Client, card_id: &str) -> Result<Card, Error> {
+        client.get(&format!("/issuing/cards/{}", card_id))
+    }
+
+    /// Retrieves an issued card along with its full number and CVC.
+    ///
+    /// These fields are only returned when explicitly expanded, and only to accounts
+    /// which are PCI compliant; most integrations should display them with Issuing Elements.
+    ///
+    /// For more details see https://stripe.com/docs/issuing/cards/virtual#card-numbers.
+    pub fn retrieve_details(client: &Client, card_id: &str) -> Result<CardDetails, Error> {
+        let query = to_form(&Expand { expand: &["number", "cvc"] })?;
+        client.get(&Path::new("/issuing/cards").id(card_id)?.query(&query))
+    }
+
+    /// Updates an issued card, eg. to deactivate it.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cards/update.
+    pub fn update(client: &Client, card_id: &str, params: CardUpdateParams) -> Result<Card, Error> {
+        client.post(&format!("/issuing/cards/{}", card_id), params)
+    }
+
+    /// Lists all issued cards.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cards/list.
+    pub fn list(client: &Client, params: CardListParams) -> Result<List<Card>, Error> {
+        client.get(&format!("/issuing/cards?{}", to_form(&params)?))
+    }
+
+    /// Creates a new card to replace this one, with the same cardholder, type and shipping details.
+    ///
+    /// When the reason is `Lost` or `Stolen`, Stripe cancels this card.
+    ///
+    /// For more details see https://stripe.com/docs/issuing/cards/replacements.
+    pub fn replace(&self, client: &Client, reason: ReplacementReason) -> Result<Card, Error> {
+        let shipping = self.shipping.as_ref().map(|shipping| CardShipping {
+            address: shipping.address.clone(),
+            name: shipping.name.clone(),
+            service: shipping.service.clone(),
+            ..CardShipping::default()
+        });
+        Card::create(client, CardParams {
+            currency: self.currency,
+            card_type: self.card_type.clone(),
+            cardholder: Some(&self.cardholder.id),
+            metadata: None,
+            replacement_for: Some(&self.id),
+            replacement_reason: Some(reason),
+            shipping,
+            spending_controls: None,
+            status: None,
+        })
+    }
+}
+
+/// An issued card along with its sensitive details, see `Card::retrieve_details`.
+///
+/// The `Debug` output doesn't include the number or CVC.
+#[derive(Deserialize)]
+pub struct CardDetails {
+    #[serde(flatten)]
+    pub card: Card,
+    pub number: String,
+    pub cvc: String,
+}
+
+impl fmt::Debug for CardDetails {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CardDetails")
+            .field("card", &self.card)
+            .field("number", &"[REDACTED]")
+            .field("cvc", &"[REDACTED]")
+            .finish()
+    }
+}
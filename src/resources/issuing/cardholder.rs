@@ -0,0 +1,196 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Address, Currency};
+
+string_enum! {
+    /// The type of a cardholder.
+    pub enum CardholderType {
+        Company => "company",
+        Individual => "individual",
+    }
+}
+
+string_enum! {
+    /// The status of a cardholder.
+    pub enum CardholderStatus {
+        Active => "active",
+        Blocked => "blocked",
+        Inactive => "inactive",
+    }
+}
+
+string_enum! {
+    /// The interval a spending limit applies to.
+    pub enum SpendingLimitInterval {
+        AllTime => "all_time",
+        Daily => "daily",
+        Monthly => "monthly",
+        PerAuthorization => "per_authorization",
+        Weekly => "weekly",
+        Yearly => "yearly",
+    }
+}
+
+/// A limit on the amount that can be spent over an interval.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SpendingLimit {
+    pub amount: u64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>, // (merchant categories, eg. "bakeries")
+    pub interval: SpendingLimitInterval,
+}
+
+/// Rules that control the spending of a cardholder or card.
+///
+/// For more details see https://stripe.com/docs/issuing/controls/spending-controls.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SpendingControls {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_categories: Option<Vec<String>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_categories: Option<Vec<String>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits: Option<Vec<SpendingLimit>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits_currency: Option<Currency>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CardholderBilling {
+    pub address: Address,
+}
+
+/// The set of parameters that can be used when creating or updating a cardholder.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cardholders/create.
+#[derive(Debug, Default, Serialize)]
+pub struct CardholderParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<CardholderBilling>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>, // (required on create)
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder_type: Option<CardholderType>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<SpendingControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardholderStatus>,
+}
+
+setters! {
+    CardholderParams<'a> {
+        billing: CardholderBilling,
+        name: &'a str,
+        cardholder_type: CardholderType,
+        email: &'a str,
+        metadata: Metadata,
+        phone_number: &'a str,
+        spending_controls: SpendingControls,
+        status: CardholderStatus,
+    }
+}
+
+/// The set of parameters that can be used when listing cardholders.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cardholders/list.
+#[derive(Debug, Default, Serialize)]
+pub struct CardholderListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardholderStatus>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder_type: Option<CardholderType>,
+}
+
+setters! {
+    CardholderListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        email: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        phone_number: &'a str,
+        starting_after: &'a str,
+        status: CardholderStatus,
+        cardholder_type: CardholderType,
+    }
+}
+
+/// The resource representing a Stripe Issuing cardholder.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cardholders/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Cardholder {
+    pub id: String,
+    pub object: String,
+    pub billing: CardholderBilling,
+    pub created: Timestamp,
+    pub email: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub name: String,
+    pub phone_number: Option<String>,
+    #[serde(default)]
+    pub spending_controls: Option<SpendingControls>,
+    pub status: CardholderStatus,
+    #[serde(rename = "type")]
+    pub cardholder_type: CardholderType,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Cardholder {
+    /// Creates a new cardholder.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cardholders/create.
+    pub fn create(client: &Client, params: CardholderParams) -> Result<Cardholder, Error> {
+        client.post("/issuing/cardholders", params)
+    }
+
+    /// Retrieves the details of a cardholder.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cardholders/retrieve.
+    pub fn retrieve(client: &Client, cardholder_id: &str) -> Result<Cardholder, Error> {
+        client.get(&format!("/issuing/cardholders/{}", cardholder_id))
+    }
+
+    /// Updates a cardholder.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cardholders/update.
+    pub fn update(client: &Client, cardholder_id: &str, params: CardholderParams) -> Result<Cardholder, Error> {
+        client.post(&format!("/issuing/cardholders/{}", cardholder_id), params)
+    }
+
+    /// Lists all cardholders.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cardholders/list.
+    pub fn list(client: &Client, params: CardholderListParams) -> Result<List<Cardholder>, Error> {
+        client.get(&format!("/issuing/cardholders?{}", to_form(&params)?))
+    }
+}
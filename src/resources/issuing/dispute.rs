@@ -0,0 +1,170 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::Currency;
+
+string_enum! {
+    /// The status of an issuing dispute.
+    pub enum DisputeStatus {
+        Expired => "expired",
+        Lost => "lost",
+        Submitted => "submitted",
+        Unsubmitted => "unsubmitted",
+        Won => "won",
+    }
+}
+
+string_enum! {
+    /// The reason for disputing a transaction.
+    pub enum DisputeReason {
+        Canceled => "canceled",
+        Duplicate => "duplicate",
+        Fraudulent => "fraudulent",
+        MerchandiseNotAsDescribed => "merchandise_not_as_described",
+        NotReceived => "not_received",
+        /// The reason Stripe calls `other`, distinct from the `Other` fallback for unknown reasons.
+        OtherReason => "other",
+        ServiceNotAsDescribed => "service_not_as_described",
+    }
+}
+
+/// The evidence for one dispute reason.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DisputeEvidenceDetails {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<String>, // (a file id)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+/// The evidence for a dispute; only the details matching `reason` are used.
+///
+/// For more details see https://stripe.com/docs/api/issuing/disputes/object#issuing_dispute_object-evidence.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DisputeEvidence {
+    pub reason: DisputeReason,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canceled: Option<DisputeEvidenceDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate: Option<DisputeEvidenceDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fraudulent: Option<DisputeEvidenceDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchandise_not_as_described: Option<DisputeEvidenceDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_received: Option<DisputeEvidenceDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other: Option<DisputeEvidenceDetails>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_not_as_described: Option<DisputeEvidenceDetails>,
+}
+
+/// The set of parameters that can be used when creating an issuing dispute.
+///
+/// For more details see https://stripe.com/docs/api/issuing/disputes/create.
+#[derive(Debug, Serialize)]
+pub struct DisputeParams<'a> {
+    pub transaction: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<DisputeEvidence>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    DisputeParams<'a> {
+        evidence: DisputeEvidence,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing issuing disputes.
+///
+/// For more details see https://stripe.com/docs/api/issuing/disputes/list.
+#[derive(Debug, Default, Serialize)]
+pub struct DisputeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DisputeStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<&'a str>,
+}
+
+setters! {
+    DisputeListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        status: DisputeStatus,
+        transaction: &'a str,
+    }
+}
+
+/// The resource representing a Stripe Issuing dispute.
+///
+/// For more details see https://stripe.com/docs/api/issuing/disputes/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Dispute {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub evidence: DisputeEvidence,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub status: DisputeStatus,
+    pub transaction: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Dispute {
+    /// Creates a new dispute for an issuing transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/disputes/create.
+    pub fn create(client: &Client, params: DisputeParams) -> Result<Dispute, Error> {
+        client.post("/issuing/disputes", params)
+    }
+
+    /// Submits a dispute to the card network once its evidence is complete.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/disputes/submit.
+    pub fn submit(client: &Client, dispute_id: &str) -> Result<Dispute, Error> {
+        client.post_empty(&format!("/issuing/disputes/{}/submit", dispute_id))
+    }
+
+    /// Retrieves the details of an issuing dispute.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/disputes/retrieve.
+    pub fn retrieve(client: &Client, dispute_id: &str) -> Result<Dispute, Error> {
+        client.get(&format!("/issuing/disputes/{}", dispute_id))
+    }
+
+    /// Lists all issuing disputes.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/disputes/list.
+    pub fn list(client: &Client, params: DisputeListParams) -> Result<List<Dispute>, Error> {
+        client.get(&format!("/issuing/disputes?{}", to_form(&params)?))
+    }
+}
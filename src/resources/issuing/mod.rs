@@ -0,0 +1,13 @@
+//! Resources for Stripe Issuing, used to create and manage cards for your business.
+
+mod authorization;
+mod card;
+mod cardholder;
+mod dispute;
+mod transaction;
+
+pub use self::authorization::*;
+pub use self::card::*;
+pub use self::cardholder::*;
+pub use self::dispute::*;
+pub use self::transaction::*;
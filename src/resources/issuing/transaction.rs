@@ -0,0 +1,109 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::Currency;
+use resources::issuing::MerchantData;
+
+string_enum! {
+    /// The type of an issuing transaction.
+    pub enum TransactionType {
+        Capture => "capture",
+        Refund => "refund",
+    }
+}
+
+/// The set of parameters that can be used when updating an issuing transaction.
+///
+/// For more details see https://stripe.com/docs/api/issuing/transactions/update.
+#[derive(Debug, Default, Serialize)]
+pub struct TransactionParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    TransactionParams {
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing issuing transactions.
+///
+/// For more details see https://stripe.com/docs/api/issuing/transactions/list.
+#[derive(Debug, Default, Serialize)]
+pub struct TransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    TransactionListParams<'a> {
+        card: &'a str,
+        cardholder: &'a str,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe Issuing transaction.
+///
+/// For more details see https://stripe.com/docs/api/issuing/transactions/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Transaction {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub authorization: Option<String>,
+    pub balance_transaction: Option<String>,
+    pub card: String,
+    pub cardholder: Option<String>,
+    pub created: Timestamp,
+    pub currency: Currency,
+    #[serde(default)]
+    pub dispute: Option<String>,
+    pub livemode: bool,
+    pub merchant_amount: i64,
+    pub merchant_currency: Currency,
+    pub merchant_data: MerchantData,
+    pub metadata: Metadata,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Transaction {
+    /// Retrieves the details of an issuing transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/transactions/retrieve.
+    pub fn retrieve(client: &Client, transaction_id: &str) -> Result<Transaction, Error> {
+        client.get(&format!("/issuing/transactions/{}", transaction_id))
+    }
+
+    /// Updates the metadata of an issuing transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/transactions/update.
+    pub fn update(client: &Client, transaction_id: &str, params: TransactionParams) -> Result<Transaction, Error> {
+        client.post(&format!("/issuing/transactions/{}", transaction_id), params)
+    }
+
+    /// Lists all issuing transactions.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/transactions/list.
+    pub fn list(client: &Client, params: TransactionListParams) -> Result<List<Transaction>, Error> {
+        client.get(&format!("/issuing/transactions?{}", to_form(&params)?))
+    }
+}
@@ -1,63 +1,113 @@
 mod account;
+mod account_link;
 mod address;
 mod application_fee;
 mod application_refund;
 mod balance;
 mod bank_account;
+pub mod billing_portal;
+mod capability;
 mod card;
+mod cash_balance;
 mod charge;
+pub mod checkout;
+mod country_spec;
 mod coupon;
+mod credit_note;
 mod currency;
 mod customer;
+mod customer_balance_transaction;
 mod deleted;
 mod discount;
 mod dispute;
 mod event;
+mod exchange_rate;
+mod external_account;
 mod file;
 mod invoices;
 mod invoice_item;
+pub mod issuing;
+mod object;
 mod order;
 mod order_return;
+mod payment_intent;
+mod payment_link;
 mod payment_source;
 mod payout;
+mod person;
 mod plan;
+mod price;
 mod product;
+mod promotion_code;
+mod quote;
+pub mod radar;
 mod refund;
+pub mod reporting;
 mod review;
 mod scheduled_query;
+mod shipping_rate;
 mod sku;
 mod subscription;
+mod subscription_item;
+mod subscription_schedule;
+mod tax_id;
+mod tax_rate;
+pub mod terminal;
+mod test_clock;
 mod transaction;
 mod transfer;
+mod usage_record;
 
 pub use resources::account::*;
+pub use resources::account_link::*;
 pub use resources::address::*;
 pub use resources::application_fee::*;
 pub use resources::application_refund::*;
 pub use resources::balance::*;
 pub use resources::bank_account::*;
+pub use resources::capability::*;
 pub use resources::card::*;
+pub use resources::cash_balance::*;
 pub use resources::charge::*;
+pub use resources::country_spec::*;
 pub use resources::coupon::*;
+pub use resources::credit_note::*;
 pub use resources::currency::*;
 pub use resources::customer::*;
+pub use resources::customer_balance_transaction::*;
 pub use resources::deleted::*;
 pub use resources::discount::*;
 pub use resources::dispute::*;
 pub use resources::event::*;
+pub use resources::exchange_rate::*;
+pub use resources::external_account::*;
 pub use resources::file::*;
 pub use resources::invoices::*;
 pub use resources::invoice_item::*;
+pub use resources::object::*;
 pub use resources::order::*;
 pub use resources::order_return::*;
+pub use resources::payment_intent::*;
+pub use resources::payment_link::*;
 pub use resources::payment_source::*;
 pub use resources::payout::*;
+pub use resources::person::*;
 pub use resources::plan::*;
+pub use resources::price::*;
 pub use resources::product::*;
+pub use resources::promotion_code::*;
+pub use resources::quote::*;
 pub use resources::refund::*;
 pub use resources::review::*;
 pub use resources::scheduled_query::*;
+pub use resources::shipping_rate::*;
 pub use resources::sku::*;
 pub use resources::subscription::*;
+pub use resources::subscription_item::*;
+pub use resources::subscription_schedule::*;
+pub use resources::tax_id::*;
+pub use resources::tax_rate::*;
+pub use resources::test_clock::*;
 pub use resources::transaction::*;
 pub use resources::transfer::*;
+pub use resources::usage_record::*;
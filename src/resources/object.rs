@@ -0,0 +1,137 @@
+use resources::*;
+use resources::{billing_portal, checkout, issuing, radar, reporting, terminal};
+
+/// A Stripe object which has an id, eg. a `Charge` or a `Customer`.
+///
+/// This makes it possible to write generic code (eg. caching or logging) over different kinds of objects.
+pub trait Object {
+    /// The id of the object, eg. `"ch_1Df82k2eZvKYlo2C6jfzwtvg"`.
+    fn id(&self) -> &str;
+
+    /// The type of the object, as in its `object` field, eg. `"charge"`.
+    fn object(&self) -> &'static str;
+}
+
+macro_rules! impl_object {
+    ($($resource:ty => $object:expr,)*) => {
+        $(
+            impl Object for $resource {
+                fn id(&self) -> &str {
+                    &self.id
+                }
+
+                fn object(&self) -> &'static str {
+                    $object
+                }
+            }
+        )*
+    }
+}
+
+impl_object! {
+    Account => "account",
+    ApplicationFee => "application_fee",
+    ApplicationFeeRefund => "fee_refund",
+    BalanceTransaction => "balance_transaction",
+    BankAccount => "bank_account",
+    billing_portal::Configuration => "billing_portal.configuration",
+    billing_portal::Session => "billing_portal.session",
+    Capability => "capability",
+    Card => "card",
+    Charge => "charge",
+    checkout::Session => "checkout.session",
+    CountrySpec => "country_spec",
+    Coupon => "coupon",
+    CreditNote => "credit_note",
+    CreditNoteLineItem => "credit_note_line_item",
+    Customer => "customer",
+    CustomerBalanceTransaction => "customer_balance_transaction",
+    CustomerCashBalanceTransaction => "customer_cash_balance_transaction",
+    Discount => "discount",
+    Dispute => "dispute",
+    Event => "event",
+    ExchangeRate => "exchange_rate",
+    File => "file",
+    InvoiceItem => "invoiceitem",
+    InvoiceLineItem => "line_item",
+    issuing::Authorization => "issuing.authorization",
+    issuing::Card => "issuing.card",
+    issuing::Cardholder => "issuing.cardholder",
+    issuing::Dispute => "issuing.dispute",
+    issuing::Transaction => "issuing.transaction",
+    Order => "order",
+    OrderReturn => "order_return",
+    PaymentIntent => "payment_intent",
+    PaymentLink => "payment_link",
+    Payout => "payout",
+    Person => "person",
+    Plan => "plan",
+    Price => "price",
+    Product => "product",
+    PromotionCode => "promotion_code",
+    Quote => "quote",
+    radar::EarlyFraudWarning => "radar.early_fraud_warning",
+    radar::ValueList => "radar.value_list",
+    radar::ValueListItem => "radar.value_list_item",
+    Refund => "refund",
+    reporting::ReportRun => "reporting.report_run",
+    reporting::ReportType => "reporting.report_type",
+    Review => "review",
+    ScheduledQueryRun => "scheduled_query_run",
+    ShippingRate => "shipping_rate",
+    Sku => "sku",
+    Source => "source",
+    SourceTransaction => "source_transaction",
+    Subscription => "subscription",
+    SubscriptionItem => "subscription_item",
+    SubscriptionSchedule => "subscription_schedule",
+    TaxId => "tax_id",
+    TaxRate => "tax_rate",
+    terminal::Location => "terminal.location",
+    terminal::Reader => "terminal.reader",
+    TestClock => "test_helpers.test_clock",
+    Transfer => "transfer",
+    TransferReversal => "transfer_reversal",
+    UsageRecord => "usage_record",
+    UsageRecordSummary => "usage_record_summary",
+}
+
+impl Object for Invoice {
+    /// The id of the invoice, which is empty for upcoming invoices.
+    fn id(&self) -> &str {
+        self.id.as_ref().map(|id| id.as_str()).unwrap_or("")
+    }
+
+    fn object(&self) -> &'static str {
+        "invoice"
+    }
+}
+
+impl Object for ExternalAccount {
+    fn id(&self) -> &str {
+        ExternalAccount::id(self)
+    }
+
+    fn object(&self) -> &'static str {
+        match *self {
+            ExternalAccount::BankAccount(ref account) => account.object(),
+            ExternalAccount::Card(ref card) => card.object(),
+        }
+    }
+}
+
+impl Object for PaymentSource {
+    fn id(&self) -> &str {
+        match *self {
+            PaymentSource::Card(ref card) => card.id(),
+            PaymentSource::Source(ref source) => source.id(),
+        }
+    }
+
+    fn object(&self) -> &'static str {
+        match *self {
+            PaymentSource::Card(ref card) => card.object(),
+            PaymentSource::Source(ref source) => source.object(),
+        }
+    }
+}
@@ -1,6 +1,30 @@
-use params::{List, Metadata, Timestamp};
-use resources::{Currency, ShippingDetails};
-use serde_json as json;
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, OrderReturn, PaymentSourceParams, Shipping};
+
+string_enum! {
+    /// The status of an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#order_object-status.
+    pub enum OrderStatus {
+        Canceled => "canceled",
+        Created => "created",
+        Fulfilled => "fulfilled",
+        Paid => "paid",
+        Returned => "returned",
+    }
+}
+
+string_enum! {
+    /// The type of an order item.
+    pub enum OrderItemType {
+        Discount => "discount",
+        Shipping => "shipping",
+        Sku => "sku",
+        Tax => "tax",
+    }
+}
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct StatusTransitions {
@@ -14,6 +38,198 @@ pub struct StatusTransitions {
     pub returned: Option<Timestamp>,
 }
 
+/// The estimated delivery date of a shipping method.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShippingMethodDeliveryEstimate {
+    #[serde(rename = "type")]
+    pub estimate_type: String, // (exact, range)
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub earliest: Option<String>,
+    #[serde(default)]
+    pub latest: Option<String>,
+}
+
+/// A shipping method that can be selected for an order.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShippingMethod {
+    pub id: String,
+    pub amount: u64,
+    pub currency: Currency,
+    pub delivery_estimate: Option<ShippingMethodDeliveryEstimate>,
+    pub description: String,
+}
+
+/// The set of parameters that can be used to define an item of an order or order return.
+///
+/// For more details see https://stripe.com/docs/api#create_order-items.
+#[derive(Debug, Default, Serialize)]
+pub struct OrderItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<&'a str>, // the sku id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<OrderItemType>,
+}
+
+setters! {
+    OrderItemParams<'a> {
+        amount: u64,
+        currency: Currency,
+        description: &'a str,
+        parent: &'a str,
+        quantity: u64,
+        item_type: OrderItemType,
+    }
+}
+
+/// The set of parameters that can be used when creating an order.
+///
+/// For more details see https://stripe.com/docs/api#create_order.
+#[derive(Debug, Serialize)]
+pub struct OrderParams<'a> {
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<OrderItemParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+}
+
+setters! {
+    OrderParams<'a> {
+        coupon: &'a str,
+        customer: &'a str,
+        email: &'a str,
+        items: Vec<OrderItemParams<'a>>,
+        metadata: Metadata,
+        shipping: Shipping,
+    }
+}
+
+/// The set of parameters that can be used when updating an order.
+///
+/// For more details see https://stripe.com/docs/api#update_order.
+#[derive(Debug, Default, Serialize)]
+pub struct OrderUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_shipping_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+}
+
+setters! {
+    OrderUpdateParams<'a> {
+        coupon: &'a str,
+        metadata: Metadata,
+        selected_shipping_method: &'a str,
+        shipping: Shipping,
+        status: OrderStatus,
+    }
+}
+
+/// The set of parameters that can be used when paying an order.
+///
+/// Either `customer` or `source` must be provided.
+///
+/// For more details see https://stripe.com/docs/api#pay_order.
+#[derive(Debug, Default, Serialize)]
+pub struct OrderPayParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PaymentSourceParams<'a>>,
+}
+
+setters! {
+    OrderPayParams<'a> {
+        application_fee: u64,
+        customer: &'a str,
+        email: &'a str,
+        metadata: Metadata,
+        source: PaymentSourceParams<'a>,
+    }
+}
+
+/// The set of parameters that can be used when returning an order.
+///
+/// If `items` is omitted, all of the order's items are returned.
+///
+/// For more details see https://stripe.com/docs/api#return_order.
+#[derive(Debug, Default, Serialize)]
+pub struct OrderReturnParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<OrderItemParams<'a>>>,
+}
+
+setters! {
+    OrderReturnParams<'a> {
+        items: Vec<OrderItemParams<'a>>,
+    }
+}
+
+/// The set of parameters that can be used when listing orders.
+///
+/// For more details see https://stripe.com/docs/api#list_orders.
+#[derive(Debug, Default, Serialize)]
+pub struct OrderListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+}
+
+setters! {
+    OrderListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        customer: &'a str,
+        ending_before: &'a str,
+        ids: Vec<&'a str>,
+        limit: u64,
+        starting_after: &'a str,
+        status: OrderStatus,
+    }
+}
+
 /// The resource representing a Stripe order item.
 ///
 /// For more details see https://stripe.com/docs/api#order_item_object.
@@ -26,7 +242,7 @@ pub struct OrderItem {
     pub parent: Option<String>,
     pub quantity: Option<u64>,
     #[serde(rename = "type")]
-    pub item_type: String,
+    pub item_type: OrderItemType,
 }
 
 /// The resource representing a Stripe order.
@@ -37,24 +253,71 @@ pub struct Order {
     pub id: String,
     pub object: String,
     pub amount: u64,
-    pub amount_returned: u64,
-    pub application: String,
-    pub application_fee: u64,
+    pub amount_returned: Option<u64>,
+    pub application: Option<String>,
+    pub application_fee: Option<u64>,
     pub charge: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
-    pub customer: String,
-    pub email: String,
-    pub external_coupon_code: String,
-    pub items: List<OrderItem>,
+    pub customer: Option<String>,
+    pub email: Option<String>,
+    pub external_coupon_code: Option<String>,
+    pub items: Vec<OrderItem>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub returns: List<json::Value>,
+    pub returns: List<OrderReturn>,
     pub selected_shipping_method: Option<String>,
-    pub shipping: Option<ShippingDetails>,
-    pub shipping_methods: List<json::Value>,
-    pub status: String, // (created, paid, canceled, fulfilled, returned)
+    pub shipping: Option<Shipping>,
+    pub shipping_methods: Option<Vec<ShippingMethod>>,
+    pub status: OrderStatus,
     pub status_transitions: StatusTransitions,
-    pub udpated: Timestamp,
+    pub updated: Timestamp,
     pub upstream_id: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Order {
+    /// Creates a new order.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_order.
+    pub fn create(client: &Client, params: OrderParams) -> Result<Order, Error> {
+        client.post("/orders", params)
+    }
+
+    /// Retrieves the details of an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_order.
+    pub fn retrieve(client: &Client, order_id: &str) -> Result<Order, Error> {
+        client.get(&format!("/orders/{}", order_id))
+    }
+
+    /// Updates an order, e.g. to select one of its `shipping_methods`.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_order.
+    pub fn update(client: &Client, order_id: &str, params: OrderUpdateParams) -> Result<Order, Error> {
+        client.post(&format!("/orders/{}", order_id), params)
+    }
+
+    /// Pays an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#pay_order.
+    pub fn pay(client: &Client, order_id: &str, params: OrderPayParams) -> Result<Order, Error> {
+        client.post(&format!("/orders/{}/pay", order_id), params)
+    }
+
+    /// Returns all or part of a paid order.
+    ///
+    /// For more details see https://stripe.com/docs/api#return_order.
+    pub fn return_order(client: &Client, order_id: &str, params: OrderReturnParams) -> Result<OrderReturn, Error> {
+        client.post(&format!("/orders/{}/returns", order_id), params)
+    }
+
+    /// Lists all orders.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_orders.
+    pub fn list(client: &Client, params: OrderListParams) -> Result<List<Order>, Error> {
+        client.get(&format!("/orders?{}", to_form(&params)?))
+    }
 }
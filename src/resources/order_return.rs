@@ -1,6 +1,35 @@
-use params::{List, Timestamp};
+use error::Error;
+use client::Client;
+use params::{to_form, List, RangeQuery, Timestamp};
 use resources::{Currency, OrderItem};
 
+/// The set of parameters that can be used when listing order returns.
+///
+/// For more details see https://stripe.com/docs/api#list_order_returns.
+#[derive(Debug, Default, Serialize)]
+pub struct OrderReturnListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    OrderReturnListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        order: &'a str,
+        starting_after: &'a str,
+    }
+}
+
 /// The resource representing a Stripe order return.
 ///
 /// For more details see https://stripe.com/docs/api#order_return_object.
@@ -11,8 +40,27 @@ pub struct OrderReturn {
     pub amount: u64,
     pub created: Timestamp,
     pub currency: Currency,
-    pub items: List<OrderItem>,
+    pub items: Vec<OrderItem>,
     pub livemode: bool,
-    pub order: String,
-    pub refund: String,
+    pub order: Option<String>,
+    pub refund: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl OrderReturn {
+    /// Retrieves the details of an order return.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_order_return.
+    pub fn retrieve(client: &Client, order_return_id: &str) -> Result<OrderReturn, Error> {
+        client.get(&format!("/order_returns/{}", order_return_id))
+    }
+
+    /// Lists all order returns.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_order_returns.
+    pub fn list(client: &Client, params: OrderReturnListParams) -> Result<List<OrderReturn>, Error> {
+        client.get(&format!("/order_returns?{}", to_form(&params)?))
+    }
 }
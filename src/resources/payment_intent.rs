@@ -0,0 +1,360 @@
+use error::{Error, Violation};
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp, Validate};
+use resources::{validate_connect_params, validate_statement_descriptors, Charge, Currency, Shipping, TransferData, TransferDataParams};
+use serde_json as json;
+
+string_enum! {
+    /// The status of a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/payments/intents#intent-statuses.
+    pub enum PaymentIntentStatus {
+        Canceled => "canceled",
+        Processing => "processing",
+        RequiresAction => "requires_action",
+        RequiresCapture => "requires_capture",
+        RequiresConfirmation => "requires_confirmation",
+        RequiresPaymentMethod => "requires_payment_method",
+        Succeeded => "succeeded",
+    }
+}
+
+string_enum! {
+    /// Whether funds are captured automatically or must be captured explicitly.
+    pub enum CaptureMethod {
+        Automatic => "automatic",
+        Manual => "manual",
+    }
+}
+
+string_enum! {
+    /// Whether a payment intent is confirmed with its publishable or secret key.
+    pub enum ConfirmationMethod {
+        Automatic => "automatic",
+        Manual => "manual",
+    }
+}
+
+string_enum! {
+    /// The reason a payment intent was canceled.
+    pub enum PaymentIntentCancellationReason {
+        Abandoned => "abandoned",
+        Automatic => "automatic",
+        Duplicate => "duplicate",
+        FailedInvoice => "failed_invoice",
+        Fraudulent => "fraudulent",
+        RequestedByCustomer => "requested_by_customer",
+        VoidInvoice => "void_invoice",
+    }
+}
+
+string_enum! {
+    /// The type of action a customer must take to complete a payment intent.
+    pub enum NextActionType {
+        RedirectToUrl => "redirect_to_url",
+        UseStripeSdk => "use_stripe_sdk",
+    }
+}
+
+/// A url to redirect the customer to, eg. to complete a 3D Secure challenge.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RedirectToUrl {
+    pub return_url: Option<String>,
+    pub url: Option<String>,
+}
+
+/// The action required of the customer while the payment intent has status `requires_action`.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NextAction {
+    #[serde(rename = "type")]
+    pub action_type: NextActionType,
+    #[serde(default)]
+    pub redirect_to_url: Option<RedirectToUrl>,
+    #[serde(default)]
+    pub use_stripe_sdk: Option<json::Value>, // (opaque; handled by Stripe.js or the mobile SDKs)
+}
+
+/// The set of parameters that can be used when creating or updating a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentIntentParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<CaptureMethod>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_method: Option<ConfirmationMethod>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>, // (ignored for card payments, see `statement_descriptor_suffix`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix: Option<&'a str>, // (combined with the account's prefix)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+setters! {
+    PaymentIntentParams<'a> {
+        amount: u64,
+        currency: Currency,
+        application_fee_amount: u64,
+        capture_method: CaptureMethod,
+        confirm: bool,
+        confirmation_method: ConfirmationMethod,
+        customer: &'a str,
+        description: &'a str,
+        metadata: Metadata,
+        on_behalf_of: &'a str,
+        payment_method: &'a str,
+        payment_method_types: Vec<&'a str>,
+        receipt_email: &'a str,
+        shipping: Shipping,
+        statement_descriptor: &'a str,
+        statement_descriptor_suffix: &'a str,
+        transfer_data: TransferDataParams<'a>,
+        transfer_group: &'a str,
+    }
+}
+
+impl<'a> PaymentIntentParams<'a> {
+    /// Creates the parameters for a new payment intent of `amount` in `currency`.
+    pub fn new(amount: u64, currency: Currency) -> Self {
+        PaymentIntentParams {
+            amount: Some(amount),
+            currency: Some(currency),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Validate for PaymentIntentParams<'a> {
+    fn violations(&self) -> Vec<Violation> {
+        let mut violations = validate_connect_params(
+            self.amount,
+            self.application_fee_amount,
+            self.transfer_data.as_ref(),
+            self.on_behalf_of,
+        );
+        violations.extend(validate_statement_descriptors(self.statement_descriptor, self.statement_descriptor_suffix));
+        if self.amount == Some(0) {
+            violations.push(Violation::new("amount", "must be greater than 0"));
+        }
+        violations
+    }
+}
+
+/// The set of parameters that can be used when confirming a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentIntentConfirmParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>, // (where the customer returns after authenticating)
+}
+
+setters! {
+    PaymentIntentConfirmParams<'a> {
+        off_session: bool,
+        payment_method: &'a str,
+        return_url: &'a str,
+    }
+}
+
+/// The set of parameters that can be used when capturing a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/capture.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentIntentCaptureParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_to_capture: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+}
+
+setters! {
+    PaymentIntentCaptureParams {
+        amount_to_capture: u64,
+        application_fee_amount: u64,
+    }
+}
+
+/// The set of parameters that can be used when canceling a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentIntentCancelParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<PaymentIntentCancellationReason>,
+}
+
+setters! {
+    PaymentIntentCancelParams {
+        cancellation_reason: PaymentIntentCancellationReason,
+    }
+}
+
+/// The set of parameters that can be used when listing payment intents.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/list.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentIntentListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    PaymentIntentListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        customer: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentIntent {
+    pub id: String,
+    pub object: String,
+    pub amount: u64,
+    #[serde(default)]
+    pub amount_capturable: u64,
+    #[serde(default)]
+    pub amount_received: u64,
+    pub application_fee_amount: Option<u64>,
+    pub canceled_at: Option<Timestamp>,
+    pub cancellation_reason: Option<PaymentIntentCancellationReason>,
+    pub capture_method: CaptureMethod,
+    #[serde(default)]
+    pub charges: Option<List<Charge>>,
+    pub client_secret: Option<String>,
+    pub confirmation_method: ConfirmationMethod,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub customer: Option<String>,
+    pub description: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(default)]
+    pub next_action: Option<NextAction>,
+    pub on_behalf_of: Option<String>,
+    pub payment_method: Option<String>,
+    #[serde(default)]
+    pub payment_method_types: Vec<String>,
+    #[serde(default)]
+    pub receipt_email: Option<String>,
+    #[serde(default)]
+    pub shipping: Option<Shipping>,
+    pub status: PaymentIntentStatus,
+    pub transfer_data: Option<TransferData>,
+    pub transfer_group: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl PaymentIntent {
+    /// The url of the receipt of the latest charge, once the payment intent has succeeded.
+    pub fn receipt_url(&self) -> Option<&str> {
+        self.charges
+            .as_ref()
+            .and_then(|charges| charges.data.first())
+            .and_then(|charge| charge.receipt_url.as_ref())
+            .map(|url| url.as_str())
+    }
+
+    /// Creates a new payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/create.
+    pub fn create(client: &Client, params: PaymentIntentParams) -> Result<PaymentIntent, Error> {
+        params.validate()?;
+        client.post("/payment_intents", params)
+    }
+
+    /// Retrieves the details of a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/retrieve.
+    pub fn retrieve(client: &Client, payment_intent_id: &str) -> Result<PaymentIntent, Error> {
+        client.get(&format!("/payment_intents/{}", payment_intent_id))
+    }
+
+    /// Updates a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/update.
+    pub fn update(client: &Client, payment_intent_id: &str, params: PaymentIntentParams) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}", payment_intent_id), params)
+    }
+
+    /// Confirms that the customer intends to pay with the payment intent's payment method.
+    ///
+    /// If authentication is required, the returned intent has status `requires_action`
+    /// and describes the challenge in `next_action`.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+    pub fn confirm(client: &Client, payment_intent_id: &str, params: PaymentIntentConfirmParams) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
+    }
+
+    /// Captures a payment intent created with `capture_method: manual`.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/capture.
+    pub fn capture(client: &Client, payment_intent_id: &str, params: PaymentIntentCaptureParams) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}/capture", payment_intent_id), params)
+    }
+
+    /// Cancels a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+    pub fn cancel(client: &Client, payment_intent_id: &str, params: PaymentIntentCancelParams) -> Result<PaymentIntent, Error> {
+        client.post(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
+    }
+
+    /// Lists all payment intents.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/list.
+    pub fn list(client: &Client, params: PaymentIntentListParams) -> Result<List<PaymentIntent>, Error> {
+        client.get(&format!("/payment_intents?{}", to_form(&params)?))
+    }
+}
@@ -0,0 +1,153 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata};
+use resources::{Currency, PriceDataParams};
+
+string_enum! {
+    /// The behavior after a purchase through a payment link is complete.
+    pub enum AfterCompletionType {
+        HostedConfirmation => "hosted_confirmation",
+        Redirect => "redirect",
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HostedConfirmation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AfterCompletionRedirect {
+    pub url: String,
+}
+
+/// What happens after a customer completes a purchase through a payment link.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AfterCompletion {
+    #[serde(rename = "type")]
+    pub completion_type: AfterCompletionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_confirmation: Option<HostedConfirmation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<AfterCompletionRedirect>,
+}
+
+/// The set of parameters that can be used to define a line item of a payment link.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentLinkLineItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<PriceDataParams<'a>>, // (instead of `price`)
+    pub quantity: u64,
+}
+
+setters! {
+    PaymentLinkLineItemParams<'a> {
+        price: &'a str,
+        price_data: PriceDataParams<'a>,
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a payment link.
+///
+/// For more details see https://stripe.com/docs/api/payment_links/payment_links/create.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentLinkParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<PaymentLinkLineItemParams<'a>>>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_completion: Option<AfterCompletion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    PaymentLinkParams<'a> {
+        line_items: Vec<PaymentLinkLineItemParams<'a>>,
+        active: bool,
+        after_completion: AfterCompletion,
+        allow_promotion_codes: bool,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing payment links.
+///
+/// For more details see https://stripe.com/docs/api/payment_links/payment_links/list.
+#[derive(Debug, Default, Serialize)]
+pub struct PaymentLinkListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    PaymentLinkListParams<'a> {
+        active: bool,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe payment link.
+///
+/// For more details see https://stripe.com/docs/api/payment_links/payment_links/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentLink {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub after_completion: AfterCompletion,
+    pub allow_promotion_codes: bool,
+    #[serde(default)]
+    pub currency: Option<Currency>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub url: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl PaymentLink {
+    /// Creates a new payment link.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_links/payment_links/create.
+    pub fn create(client: &Client, params: PaymentLinkParams) -> Result<PaymentLink, Error> {
+        client.post("/payment_links", params)
+    }
+
+    /// Retrieves the details of a payment link.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_links/payment_links/retrieve.
+    pub fn retrieve(client: &Client, payment_link_id: &str) -> Result<PaymentLink, Error> {
+        client.get(&format!("/payment_links/{}", payment_link_id))
+    }
+
+    /// Updates a payment link, e.g. to deactivate it with `active: Some(false)`.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_links/payment_links/update.
+    pub fn update(client: &Client, payment_link_id: &str, params: PaymentLinkParams) -> Result<PaymentLink, Error> {
+        client.post(&format!("/payment_links/{}", payment_link_id), params)
+    }
+
+    /// Lists all payment links.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_links/payment_links/list.
+    pub fn list(client: &Client, params: PaymentLinkListParams) -> Result<List<PaymentLink>, Error> {
+        client.get(&format!("/payment_links?{}", to_form(&params)?))
+    }
+}
@@ -1,8 +1,8 @@
 use client::Client;
 use error::Error;
 use ids::{SourceId, TokenId};
-use resources::{Address, Card, CardParams, Currency};
-use params::{Metadata, Timestamp};
+use resources::{Address, Card, CardParams, Currency, MaybeDeleted};
+use params::{to_form, List, Metadata, Timestamp};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OwnerParams<'a> {
@@ -16,22 +16,21 @@ pub struct OwnerParams<'a> {
     pub phone: Option<&'a str>,
 }
 
+setters! {
+    OwnerParams<'a> {
+        address: Address,
+        email: &'a str,
+        name: &'a str,
+        phone: &'a str,
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CodeVerification {
     pub attempts_remaining: i64,
     pub status: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct VerifiedAddress {
-    pub city: Address,
-    pub country: String,
-    pub line1: String,
-    pub line2: String,
-    pub postal_code: String,
-    pub state: String,
-}
-
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Receiver {
     pub address: String,
@@ -54,7 +53,7 @@ pub struct Owner {
     pub email: String,
     pub name: String,
     pub phone: String,
-    pub verified_address: VerifiedAddress,
+    pub verified_address: Option<Address>,
     pub verified_email: String,
     pub verified_name: String,
     pub verified_phone: String,
@@ -92,6 +91,21 @@ pub struct SourceParams<'a> {
     pub usage: Option<&'a str>, // (reusable, single-use)
 }
 
+setters! {
+    SourceParams<'a> {
+        source_type: &'a str,
+        amount: u64,
+        currency: Currency,
+        flow: &'a str,
+        metadata: Metadata,
+        owner: OwnerParams<'a>,
+        statement_descriptor: &'a str,
+        redirect: RedirectParams<'a>,
+        token: TokenId,
+        usage: &'a str,
+    }
+}
+
 /// The resource representing a Stripe source.
 ///
 /// For more details see https://stripe.com/docs/api#sources.
@@ -113,6 +127,66 @@ pub struct Source {
     #[serde(rename = "type")]
     pub source_type: String, // (ach_credit_transfer, card, alipay etc.)
     pub usage: String, // (reusable, single-use)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Source {
+    /// Lists the transactions received by a source in the receiver flow, eg. the transfers to an ACH credit transfer source.
+    ///
+    /// For more details see https://stripe.com/docs/sources/ach-credit-transfer#source-transactions.
+    pub fn list_source_transactions(client: &Client, source_id: &str, params: SourceTransactionListParams) -> Result<List<SourceTransaction>, Error> {
+        client.get(&format!("/sources/{}/source_transactions?{}", source_id, to_form(&params)?))
+    }
+}
+
+/// The set of parameters that can be used when listing the transactions of a source.
+#[derive(Debug, Default, Serialize)]
+pub struct SourceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    SourceTransactionListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SourceTransactionAchCreditTransfer {
+    pub customer_data: Option<String>,
+    pub fingerprint: Option<String>,
+    pub last4: Option<String>,
+    pub routing_number: Option<String>,
+}
+
+/// The resource representing funds received by a Stripe source, eg. a customer's transfer to an ACH credit transfer source.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SourceTransaction {
+    pub id: String,
+    #[serde(default)] // NOTE: Consumed as the tag when deserialized as an `EventObject`
+    pub object: String,
+    #[serde(default)]
+    pub ach_credit_transfer: Option<SourceTransactionAchCreditTransfer>,
+    pub amount: i64,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub source: String,
+    pub status: String, // (pending, succeeded, failed)
+    #[serde(rename = "type")]
+    pub source_type: String, // (ach_credit_transfer, ...)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 #[derive(Debug)]
@@ -212,8 +286,10 @@ impl PaymentSource {
 
     /// Detaches a source from a customer
     ///
+    /// Cards and bank accounts are deleted when detached, other sources are returned as consumed.
+    ///
     /// For more details see https://stripe.com/docs/api#detach_source.
-    pub fn detach_source(client: &Client, customer_id: &str, source_id: &str) -> Result<PaymentSource, Error> {
+    pub fn detach_source(client: &Client, customer_id: &str, source_id: &str) -> Result<MaybeDeleted<PaymentSource>, Error> {
         client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 }
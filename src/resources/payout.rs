@@ -1,5 +1,209 @@
-use params::{Metadata, Timestamp};
-use resources::Currency;
+use error::{Error, Violation};
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp, Validate};
+use resources::{BalanceTransaction, BalanceTransactionListParams, Currency};
+
+string_enum! {
+    /// The method used to send a payout.
+    ///
+    /// Instant payouts arrive within minutes but are only supported for some debit cards.
+    pub enum PayoutMethod {
+        Instant => "instant",
+        Standard => "standard",
+    }
+}
+
+string_enum! {
+    /// How often funds are automatically paid out.
+    pub enum PayoutInterval {
+        Daily => "daily",
+        Manual => "manual",
+        Monthly => "monthly",
+        Weekly => "weekly",
+    }
+}
+
+string_enum! {
+    /// The day of the week weekly payouts are sent on.
+    pub enum WeeklyAnchor {
+        Monday => "monday",
+        Tuesday => "tuesday",
+        Wednesday => "wednesday",
+        Thursday => "thursday",
+        Friday => "friday",
+        Saturday => "saturday",
+        Sunday => "sunday",
+    }
+}
+
+/// The number of days charges are held for before they are paid out.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DelayDays {
+    Days(u32),
+    /// The lowest delay permitted for the account.
+    Minimum,
+}
+
+impl ::serde::Serialize for DelayDays {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::ser::Serializer
+    {
+        match *self {
+            DelayDays::Days(days) => serializer.serialize_u32(days),
+            DelayDays::Minimum => serializer.serialize_str("minimum"),
+        }
+    }
+}
+
+/// The automatic payout schedule of an account.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-settings-payouts-schedule.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PayoutSchedule {
+    pub delay_days: u32,
+    pub interval: PayoutInterval,
+    #[serde(default)]
+    pub monthly_anchor: Option<u32>,
+    #[serde(default)]
+    pub weekly_anchor: Option<WeeklyAnchor>,
+}
+
+/// The set of parameters that can be used to update an account's payout schedule.
+#[derive(Debug, Default, Serialize)]
+pub struct PayoutScheduleParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay_days: Option<DelayDays>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<PayoutInterval>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monthly_anchor: Option<u32>, // (required when interval is monthly)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_anchor: Option<WeeklyAnchor>, // (required when interval is weekly)
+}
+
+setters! {
+    PayoutScheduleParams {
+        delay_days: DelayDays,
+        interval: PayoutInterval,
+        monthly_anchor: u32,
+        weekly_anchor: WeeklyAnchor,
+    }
+}
+
+/// The set of parameters that can be used when creating a payout.
+///
+/// For more details see https://stripe.com/docs/api/payouts/create.
+#[derive(Debug, Serialize)]
+pub struct PayoutParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>, // (an external account id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<PayoutMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<&'a str>, // (bank_account, card)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+setters! {
+    PayoutParams<'a> {
+        description: &'a str,
+        destination: &'a str,
+        metadata: Metadata,
+        method: PayoutMethod,
+        source_type: &'a str,
+        statement_descriptor: &'a str,
+    }
+}
+
+impl<'a> Validate for PayoutParams<'a> {
+    fn violations(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self.amount == 0 {
+            violations.push(Violation::new("amount", "must be greater than 0"));
+        }
+        if self.destination.map_or(false, str::is_empty) {
+            violations.push(Violation::new("destination", "must not be empty"));
+        }
+        violations
+    }
+}
+
+string_enum! {
+    /// The status of a payout.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/object#payout_object-status.
+    pub enum PayoutStatus {
+        Canceled => "canceled",
+        Failed => "failed",
+        InTransit => "in_transit",
+        Paid => "paid",
+        Pending => "pending",
+    }
+}
+
+string_enum! {
+    /// Why a payout failed.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/failures.
+    pub enum PayoutFailureCode {
+        AccountClosed => "account_closed",
+        AccountFrozen => "account_frozen",
+        BankAccountRestricted => "bank_account_restricted",
+        BankOwnershipChanged => "bank_ownership_changed",
+        CouldNotProcess => "could_not_process",
+        DebitNotAuthorized => "debit_not_authorized",
+        Declined => "declined",
+        IncorrectAccountHolderAddress => "incorrect_account_holder_address",
+        IncorrectAccountHolderName => "incorrect_account_holder_name",
+        IncorrectAccountHolderTaxId => "incorrect_account_holder_tax_id",
+        InsufficientFunds => "insufficient_funds",
+        InvalidAccountNumber => "invalid_account_number",
+        InvalidCurrency => "invalid_currency",
+        NoAccount => "no_account",
+        UnsupportedCard => "unsupported_card",
+    }
+}
+
+/// The set of parameters that can be used when listing payouts.
+///
+/// For more details see https://stripe.com/docs/api/payouts/list.
+#[derive(Debug, Default, Serialize)]
+pub struct PayoutListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrival_date: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>, // (pending, paid, failed, canceled)
+}
+
+setters! {
+    PayoutListParams<'a> {
+        arrival_date: RangeQuery<Timestamp>,
+        created: RangeQuery<Timestamp>,
+        destination: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        status: &'a str,
+    }
+}
 
 /// The resource representing a Stripe payout.
 ///
@@ -16,14 +220,57 @@ pub struct Payout {
     pub description: String,
     pub destination: Option<String>,
     pub failure_balance_transaction: Option<String>,
-    pub failure_code: Option<String>,
+    pub failure_code: Option<PayoutFailureCode>,
     pub failure_message: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub method: String, // (standard, instant)
+    pub method: PayoutMethod,
     pub source_type: String, // (card, bank_account, bitcoin_receiver, alipay_account)
     pub statement_descriptor: Option<String>,
-    pub status: String,
+    pub status: PayoutStatus,
     #[serde(rename = "type")]
     pub payout_type: String, // (bank_account, card)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Payout {
+    /// Creates a new payout to the account's bank account or debit card.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/create.
+    pub fn create(client: &Client, params: PayoutParams) -> Result<Payout, Error> {
+        params.validate()?;
+        client.post("/payouts", params)
+    }
+
+    /// Retrieves the details of a payout.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/retrieve.
+    pub fn retrieve(client: &Client, payout_id: &str) -> Result<Payout, Error> {
+        client.get(&format!("/payouts/{}", payout_id))
+    }
+
+    /// Cancels a pending payout.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/cancel.
+    pub fn cancel(client: &Client, payout_id: &str) -> Result<Payout, Error> {
+        client.post_empty(&format!("/payouts/{}/cancel", payout_id))
+    }
+
+    /// Lists the balance transactions paid out by this (automatic) payout, eg. to reconcile it with the charges it contains.
+    ///
+    /// Use `List::paginate` to go through all of them.
+    ///
+    /// For more details see https://stripe.com/docs/reports/payout-reconciliation.
+    pub fn list_balance_transactions(&self, client: &Client) -> Result<List<BalanceTransaction>, Error> {
+        BalanceTransaction::list(client, BalanceTransactionListParams { payout: Some(&self.id), ..Default::default() })
+    }
+
+    /// Lists all payouts.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/list.
+    pub fn list(client: &Client, params: PayoutListParams) -> Result<List<Payout>, Error> {
+        client.get(&format!("/payouts?{}", to_form(&params)?))
+    }
 }
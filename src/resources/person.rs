@@ -0,0 +1,268 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::{Address, Deleted, Dob, File, FilePurpose};
+
+/// A person's relationship to the account they belong to.
+///
+/// For more details see https://stripe.com/docs/api/persons/object#person_object-relationship.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PersonRelationship {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub director: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_ownership: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub representative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// The information Stripe still needs to verify a person.
+///
+/// Each entry is the name of a field, e.g. `verification.document`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PersonRequirements {
+    #[serde(default)]
+    pub currently_due: Vec<String>,
+    #[serde(default)]
+    pub eventually_due: Vec<String>,
+    #[serde(default)]
+    pub past_due: Vec<String>,
+    #[serde(default)]
+    pub pending_verification: Vec<String>,
+}
+
+string_enum! {
+    /// The status of a person's identity verification.
+    pub enum PersonVerificationStatus {
+        Pending => "pending",
+        Unverified => "unverified",
+        Verified => "verified",
+    }
+}
+
+/// An identity document uploaded for verification.
+///
+/// `front` and `back` are ids of files uploaded with purpose `identity_document`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VerificationDocument {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub back: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front: Option<String>,
+
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub details: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    pub details_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PersonVerification {
+    pub details: Option<String>,
+    pub details_code: Option<String>,
+    pub document: Option<VerificationDocument>,
+    pub status: PersonVerificationStatus,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PersonVerificationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<VerificationDocument>,
+}
+
+setters! {
+    PersonVerificationParams {
+        document: VerificationDocument,
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a person.
+///
+/// For more details see https://stripe.com/docs/api/persons/create.
+#[derive(Debug, Default, Serialize)]
+pub struct PersonParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<Dob>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<PersonRelationship>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<PersonVerificationParams>,
+}
+
+setters! {
+    PersonParams<'a> {
+        address: Address,
+        dob: Dob,
+        email: &'a str,
+        first_name: &'a str,
+        id_number: &'a str,
+        last_name: &'a str,
+        metadata: Metadata,
+        phone: &'a str,
+        relationship: PersonRelationship,
+        ssn_last_4: &'a str,
+        verification: PersonVerificationParams,
+    }
+}
+
+/// Filters persons by their relationship to the account.
+#[derive(Debug, Default, Serialize)]
+pub struct PersonRelationshipFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub director: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub representative: Option<bool>,
+}
+
+/// The set of parameters that can be used when listing persons.
+///
+/// For more details see https://stripe.com/docs/api/persons/list.
+#[derive(Debug, Default, Serialize)]
+pub struct PersonListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<PersonRelationshipFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    PersonListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        relationship: PersonRelationshipFilter,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a person associated with a Stripe account.
+///
+/// For more details see https://stripe.com/docs/api/persons/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Person {
+    pub id: String,
+    pub object: String,
+    pub account: String,
+    #[serde(default)]
+    pub address: Option<Address>,
+    pub created: Timestamp,
+    #[serde(default)]
+    pub dob: Option<Dob>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub id_number_provided: bool,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub metadata: Metadata,
+    #[serde(default)]
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub relationship: Option<PersonRelationship>,
+    #[serde(default)]
+    pub requirements: Option<PersonRequirements>,
+    #[serde(default)]
+    pub ssn_last_4_provided: bool,
+    #[serde(default)]
+    pub verification: Option<PersonVerification>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Person {
+    /// Creates a new person on an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/create.
+    pub fn create(client: &Client, account_id: &str, params: PersonParams) -> Result<Person, Error> {
+        client.post(&format!("/accounts/{}/persons", account_id), params)
+    }
+
+    /// Retrieves the details of a person on an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str, person_id: &str) -> Result<Person, Error> {
+        client.get(&format!("/accounts/{}/persons/{}", account_id, person_id))
+    }
+
+    /// Updates a person on an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/update.
+    pub fn update(client: &Client, account_id: &str, person_id: &str, params: PersonParams) -> Result<Person, Error> {
+        client.post(&format!("/accounts/{}/persons/{}", account_id, person_id), params)
+    }
+
+    /// Uploads the front (and, unless it is a passport, the back) of a person's identity document
+    /// and sets them as the person's `verification.document`.
+    ///
+    /// The files are uploaded to the connected account, as Stripe requires for Custom accounts.
+    ///
+    /// For more details see https://stripe.com/docs/connect/identity-verification-api#upload-a-file.
+    pub fn attach_verification_document(client: &Client, account_id: &str, person_id: &str, front: &[u8], back: Option<&[u8]>) -> Result<Person, Error> {
+        let account_client = client.with_stripe_account(account_id);
+        let front = File::create(&account_client, FilePurpose::IdentityDocument, "front", front)?;
+        let back = match back {
+            Some(back) => Some(File::create(&account_client, FilePurpose::IdentityDocument, "back", back)?),
+            None => None,
+        };
+
+        let document = VerificationDocument {
+            front: Some(front.id),
+            back: back.map(|file| file.id),
+            ..Default::default()
+        };
+        let params = PersonParams {
+            verification: Some(PersonVerificationParams { document: Some(document) }),
+            ..Default::default()
+        };
+        Person::update(client, account_id, person_id, params)
+    }
+
+    /// Deletes a person from an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/delete.
+    pub fn delete(client: &Client, account_id: &str, person_id: &str) -> Result<Deleted<Person>, Error> {
+        client.delete(&format!("/accounts/{}/persons/{}", account_id, person_id))
+    }
+
+    /// Lists the persons on an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/list.
+    pub fn list(client: &Client, account_id: &str, params: PersonListParams) -> Result<List<Person>, Error> {
+        client.get(&format!("/accounts/{}/persons?{}", account_id, to_form(&params)?))
+    }
+}
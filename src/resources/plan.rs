@@ -1,12 +1,101 @@
 use error::Error;
 use client::Client;
-use params::{Metadata, Timestamp};
-use resources::{Currency, Deleted};
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, Deleted, UsageType};
+
+string_enum! {
+    /// Describes how to compute the price per period of a plan.
+    pub enum BillingScheme {
+        PerUnit => "per_unit",
+        Tiered => "tiered",
+    }
+}
+
+string_enum! {
+    /// Describes how the price of a tiered plan is computed from its tiers.
+    pub enum TiersMode {
+        /// Each unit is priced by the tier it falls into.
+        Graduated => "graduated",
+        /// The total quantity determines the tier used to price every unit.
+        Volume => "volume",
+    }
+}
+
+string_enum! {
+    /// Describes how the usage reported for a metered plan is aggregated over a period.
+    pub enum AggregateUsage {
+        LastDuringPeriod => "last_during_period",
+        LastEver => "last_ever",
+        Max => "max",
+        Sum => "sum",
+    }
+}
+
+string_enum! {
+    /// The rounding direction used after dividing reported usage.
+    pub enum TransformUsageRound {
+        Down => "down",
+        Up => "up",
+    }
+}
+
+/// Transforms the reported usage of a plan before it is billed (e.g. billing per 1000 units).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransformUsage {
+    pub divide_by: u64,
+    pub round: TransformUsageRound,
+}
+
+/// The upper bound of a pricing tier.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum UpTo {
+    /// The tier applies up to (and including) the given quantity.
+    Max(u64),
+    /// The tier applies to all remaining units; only valid for the last tier.
+    Inf,
+}
+
+impl ::serde::Serialize for UpTo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::ser::Serializer
+    {
+        match *self {
+            UpTo::Max(max) => serializer.serialize_u64(max),
+            UpTo::Inf => serializer.serialize_str("inf"),
+        }
+    }
+}
+
+/// The set of parameters that can be used to define a tier of a tiered plan.
+#[derive(Debug, Serialize)]
+pub struct PlanTierParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<u64>,
+    pub up_to: UpTo,
+}
+
+setters! {
+    PlanTierParams {
+        amount: u64,
+        flat_amount: u64,
+    }
+}
+
+/// A pricing tier of a tiered plan.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PlanTier {
+    pub amount: Option<u64>,
+    #[serde(default)]
+    pub flat_amount: Option<u64>,
+    pub up_to: Option<u64>, // (None for the last, unbounded tier)
+}
 
 /// The set of parameters that can be used when creating or updating a plan.
 ///
 /// For more details see https://stripe.com/docs/api#create_plan and https://stripe.com/docs/api#update_plan.
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct PlanParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<&'a str>,
@@ -18,15 +107,83 @@ pub struct PlanParams<'a> {
     pub interval: Option<&'a str>, // (day, week, month, year)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<AggregateUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<BillingScheme>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<PlanTierParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_usage: Option<TransformUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_type: Option<UsageType>,
+}
+
+setters! {
+    PlanParams<'a> {
+        id: &'a str,
+        amount: u64,
+        currency: Currency,
+        interval: &'a str,
+        name: &'a str,
+        product: &'a str,
+        aggregate_usage: AggregateUsage,
+        billing_scheme: BillingScheme,
+        interval_count: u64,
+        metadata: Metadata,
+        nickname: &'a str,
+        statement_descriptor: &'a str,
+        tiers: Vec<PlanTierParams>,
+        tiers_mode: TiersMode,
+        transform_usage: TransformUsage,
+        trial_period_days: u64,
+        usage_type: UsageType,
+    }
+}
+
+/// The set of parameters that can be used when listing plans.
+///
+/// For more details see https://stripe.com/docs/api#list_plans.
+#[derive(Debug, Default, Serialize)]
+pub struct PlanListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    PlanListParams<'a> {
+        active: bool,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        product: &'a str,
+        starting_after: &'a str,
+    }
 }
 
 /// The resource representing a Stripe plan.
@@ -35,7 +192,13 @@ pub struct PlanParams<'a> {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Plan {
     pub id: String,
-    pub amount: u64,
+    #[serde(default)]
+    pub active: Option<bool>,
+    #[serde(default)]
+    pub aggregate_usage: Option<AggregateUsage>,
+    pub amount: Option<u64>, // (None for tiered plans)
+    #[serde(default)]
+    pub billing_scheme: Option<BillingScheme>,
     pub created: Timestamp,
     pub currency: Currency,
     pub interval: String, // (day, week, month, year)
@@ -43,8 +206,21 @@ pub struct Plan {
     pub livemode: bool,
     pub metadata: Metadata,
     pub nickname: Option<String>,
+    #[serde(default)]
+    pub product: Option<String>,
     pub statement_descriptor: Option<String>,
+    #[serde(default)]
+    pub tiers: Option<Vec<PlanTier>>,
+    #[serde(default)]
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(default)]
+    pub transform_usage: Option<TransformUsage>,
     pub trial_period_days: Option<u64>,
+    #[serde(default)]
+    pub usage_type: Option<UsageType>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 impl Plan {
@@ -72,7 +248,14 @@ impl Plan {
     /// Deletes a plan.
     ///
     /// For more details see https://stripe.com/docs/api#delete_plan.
-    pub fn delete(client: &Client, plan_id: &str) -> Result<Deleted, Error> {
+    pub fn delete(client: &Client, plan_id: &str) -> Result<Deleted<Plan>, Error> {
         client.delete(&format!("/plans/{}", plan_id))
     }
+
+    /// Lists all plans.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_plans.
+    pub fn list(client: &Client, params: PlanListParams) -> Result<List<Plan>, Error> {
+        client.get(&format!("/plans?{}", to_form(&params)?))
+    }
 }
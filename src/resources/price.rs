@@ -0,0 +1,311 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{AggregateUsage, BillingScheme, Currency, TiersMode, TransformUsageRound, UpTo, UsageType};
+
+string_enum! {
+    /// The frequency at which a recurring price is billed.
+    pub enum RecurringInterval {
+        Day => "day",
+        Week => "week",
+        Month => "month",
+        Year => "year",
+    }
+}
+
+string_enum! {
+    /// Whether a price is billed once or on a recurring schedule.
+    pub enum PriceType {
+        OneTime => "one_time",
+        Recurring => "recurring",
+    }
+}
+
+/// The recurring components of a price.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Recurring {
+    pub aggregate_usage: Option<AggregateUsage>,
+    pub interval: RecurringInterval,
+    pub interval_count: u64,
+    pub usage_type: UsageType,
+}
+
+/// The set of parameters that can be used to define the recurring components of a price.
+#[derive(Debug, Serialize)]
+pub struct RecurringParams {
+    pub interval: RecurringInterval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<AggregateUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_type: Option<UsageType>,
+}
+
+setters! {
+    RecurringParams {
+        aggregate_usage: AggregateUsage,
+        interval_count: u64,
+        usage_type: UsageType,
+    }
+}
+
+/// The set of parameters that can be used to define a product inline, see `PriceDataParams`.
+#[derive(Debug, Default, Serialize)]
+pub struct ProductDataParams<'a> {
+    pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    ProductDataParams<'a> {
+        description: &'a str,
+        images: Vec<&'a str>,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used to define a price inline,
+/// instead of creating a `Price` first (eg. in the line items of a Checkout session).
+///
+/// Either `product` or `product_data` must be set.
+#[derive(Debug, Serialize)]
+pub struct PriceDataParams<'a> {
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_data: Option<ProductDataParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<RecurringParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<&'a str>,
+}
+
+setters! {
+    PriceDataParams<'a> {
+        product: &'a str,
+        product_data: ProductDataParams<'a>,
+        recurring: RecurringParams,
+        unit_amount: i64,
+        unit_amount_decimal: &'a str,
+    }
+}
+
+/// Transforms the quantity of a price before it is billed (e.g. selling seats in packs of 10).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransformQuantity {
+    pub divide_by: u64,
+    pub round: TransformUsageRound,
+}
+
+/// A pricing tier of a tiered price.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PriceTier {
+    pub flat_amount: Option<i64>,
+    pub flat_amount_decimal: Option<String>,
+    pub unit_amount: Option<i64>,
+    pub unit_amount_decimal: Option<String>,
+    pub up_to: Option<u64>, // (None for the last, unbounded tier)
+}
+
+/// The set of parameters that can be used to define a tier of a tiered price.
+#[derive(Debug, Serialize)]
+pub struct PriceTierParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount_decimal: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<&'a str>,
+    pub up_to: UpTo,
+}
+
+setters! {
+    PriceTierParams<'a> {
+        flat_amount: i64,
+        flat_amount_decimal: &'a str,
+        unit_amount: i64,
+        unit_amount_decimal: &'a str,
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a price.
+///
+/// Only `active`, `lookup_key`, `metadata`, `nickname`, and `transfer_lookup_key`
+/// can be changed once a price has been created.
+///
+/// For more details see https://stripe.com/docs/api/prices/create and https://stripe.com/docs/api/prices/update.
+#[derive(Debug, Default, Serialize)]
+pub struct PriceParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<BillingScheme>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<RecurringParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<PriceTierParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_lookup_key: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_quantity: Option<TransformQuantity>,
+}
+
+setters! {
+    PriceParams<'a> {
+        currency: Currency,
+        product: &'a str,
+        unit_amount: i64,
+        unit_amount_decimal: &'a str,
+        active: bool,
+        billing_scheme: BillingScheme,
+        lookup_key: &'a str,
+        metadata: Metadata,
+        nickname: &'a str,
+        recurring: RecurringParams,
+        tiers: Vec<PriceTierParams<'a>>,
+        tiers_mode: TiersMode,
+        transfer_lookup_key: bool,
+        transform_quantity: TransformQuantity,
+    }
+}
+
+impl<'a> PriceParams<'a> {
+    /// Creates the parameters for a new price of `product` in `currency`.
+    pub fn new(currency: Currency, product: &'a str) -> Self {
+        PriceParams {
+            currency: Some(currency),
+            product: Some(product),
+            ..Default::default()
+        }
+    }
+}
+
+/// The set of parameters that can be used when listing prices.
+///
+/// For more details see https://stripe.com/docs/api/prices/list.
+#[derive(Debug, Default, Serialize)]
+pub struct PriceListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_keys: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_type: Option<PriceType>,
+}
+
+setters! {
+    PriceListParams<'a> {
+        active: bool,
+        created: RangeQuery<Timestamp>,
+        currency: Currency,
+        ending_before: &'a str,
+        limit: u64,
+        lookup_keys: Vec<&'a str>,
+        product: &'a str,
+        starting_after: &'a str,
+        price_type: PriceType,
+    }
+}
+
+/// The resource representing a Stripe price.
+///
+/// Prices supersede plans, and can be used anywhere a plan is accepted.
+///
+/// For more details see https://stripe.com/docs/api/prices.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Price {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub billing_scheme: BillingScheme,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub lookup_key: Option<String>,
+    pub metadata: Metadata,
+    pub nickname: Option<String>,
+    pub product: String,
+    pub recurring: Option<Recurring>,
+    #[serde(default)]
+    pub tiers: Option<Vec<PriceTier>>, // (only present when expanded)
+    pub tiers_mode: Option<TiersMode>,
+    pub transform_quantity: Option<TransformQuantity>,
+    #[serde(rename = "type")]
+    pub price_type: PriceType,
+    pub unit_amount: Option<i64>,
+    pub unit_amount_decimal: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Price {
+    /// Creates a new price for an existing product.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/create.
+    pub fn create(client: &Client, params: PriceParams) -> Result<Price, Error> {
+        client.post("/prices", params)
+    }
+
+    /// Retrieves the details of a price.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/retrieve.
+    pub fn retrieve(client: &Client, price_id: &str) -> Result<Price, Error> {
+        client.get(&format!("/prices/{}", price_id))
+    }
+
+    /// Updates a price's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/update.
+    pub fn update(client: &Client, price_id: &str, params: PriceParams) -> Result<Price, Error> {
+        client.post(&format!("/prices/{}", price_id), params)
+    }
+
+    /// Lists all prices.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/list.
+    pub fn list(client: &Client, params: PriceListParams) -> Result<List<Price>, Error> {
+        client.get(&format!("/prices?{}", to_form(&params)?))
+    }
+}
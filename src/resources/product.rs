@@ -1,5 +1,7 @@
-use params::{List, Metadata, Timestamp};
-use resources::Sku;
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Deleted, Sku};
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct PackageDimensions {
@@ -9,6 +11,123 @@ pub struct PackageDimensions {
     pub width: f64,
 }
 
+string_enum! {
+    /// The kind of product, either physical goods sold with SKUs or services sold with plans.
+    pub enum ProductType {
+        Good => "good",
+        Service => "service",
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a product.
+///
+/// For more details see https://stripe.com/docs/api#create_product and https://stripe.com/docs/api#update_product.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ProductParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivate_on: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_label: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+setters! {
+    ProductParams<'a> {
+        id: &'a str,
+        name: &'a str,
+        product_type: ProductType,
+        active: bool,
+        attributes: Vec<&'a str>,
+        caption: &'a str,
+        deactivate_on: Vec<&'a str>,
+        description: &'a str,
+        images: Vec<&'a str>,
+        metadata: Metadata,
+        package_dimensions: PackageDimensions,
+        shippable: bool,
+        statement_descriptor: &'a str,
+        unit_label: &'a str,
+        url: &'a str,
+    }
+}
+
+impl<'a> ProductParams<'a> {
+    /// Creates the parameters for a new product called `name`.
+    pub fn new(name: &'a str) -> Self {
+        ProductParams {
+            name: Some(name),
+            ..Default::default()
+        }
+    }
+}
+
+/// The set of parameters that can be used when listing products.
+///
+/// For more details see https://stripe.com/docs/api#list_products.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ProductListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+setters! {
+    ProductListParams<'a> {
+        active: bool,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        ids: Vec<&'a str>,
+        limit: u64,
+        shippable: bool,
+        starting_after: &'a str,
+        product_type: ProductType,
+        url: &'a str,
+    }
+}
+
 /// The resource representing a Stripe product.
 ///
 /// For more details see https://stripe.com/docs/api#product_object.
@@ -17,18 +136,74 @@ pub struct Product {
     pub id: String,
     pub object: String,
     pub active: Option<bool>,
+    #[serde(default)]
     pub attributes: Vec<String>,
+    #[serde(default)]
     pub caption: Option<String>,
     pub created: Timestamp,
+    #[serde(default)]
     pub deactivate_on: Vec<String>,
+    #[serde(default)]
     pub description: Option<String>,
+    #[serde(default)]
     pub images: Vec<String>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub name: String,
+    #[serde(default)]
     pub package_dimensions: Option<PackageDimensions>,
+    #[serde(default)]
     pub shippable: Option<bool>,
-    pub skus: List<Sku>,
+    #[serde(default)]
+    pub skus: Option<List<Sku>>, // (only present for products of type good)
+    #[serde(default)]
+    pub statement_descriptor: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub product_type: Option<ProductType>,
+    #[serde(default)]
+    pub unit_label: Option<String>,
     pub updated: Timestamp,
+    #[serde(default)]
     pub url: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Product {
+    /// Creates a new product.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_product.
+    pub fn create(client: &Client, params: ProductParams) -> Result<Product, Error> {
+        client.post("/products", params)
+    }
+
+    /// Retrieves the details of a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_product.
+    pub fn retrieve(client: &Client, product_id: &str) -> Result<Product, Error> {
+        client.get(&format!("/products/{}", product_id))
+    }
+
+    /// Updates a product's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_product.
+    pub fn update(client: &Client, product_id: &str, params: ProductParams) -> Result<Product, Error> {
+        client.post(&format!("/products/{}", product_id), params)
+    }
+
+    /// Deletes a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_product.
+    pub fn delete(client: &Client, product_id: &str) -> Result<Deleted<Product>, Error> {
+        client.delete(&format!("/products/{}", product_id))
+    }
+
+    /// Lists all products.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_products.
+    pub fn list(client: &Client, params: ProductListParams) -> Result<List<Product>, Error> {
+        client.get(&format!("/products?{}", to_form(&params)?))
+    }
 }
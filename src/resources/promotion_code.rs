@@ -0,0 +1,143 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{Coupon, Currency};
+
+/// Restrictions on when a promotion code can be redeemed.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PromotionCodeRestrictions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_time_transaction: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_amount_currency: Option<Currency>,
+}
+
+/// The set of parameters that can be used when creating or updating a promotion code.
+///
+/// Only `active` and `metadata` can be changed once a promotion code has been created.
+///
+/// For more details see https://stripe.com/docs/api/promotion_codes/create and https://stripe.com/docs/api/promotion_codes/update.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PromotionCodeParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_redemptions: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<PromotionCodeRestrictions>,
+}
+
+setters! {
+    PromotionCodeParams<'a> {
+        coupon: &'a str,
+        active: bool,
+        code: &'a str,
+        customer: &'a str,
+        expires_at: Timestamp,
+        max_redemptions: u64,
+        metadata: Metadata,
+        restrictions: PromotionCodeRestrictions,
+    }
+}
+
+/// The set of parameters that can be used when listing promotion codes.
+///
+/// For more details see https://stripe.com/docs/api/promotion_codes/list.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PromotionCodeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    PromotionCodeListParams<'a> {
+        active: bool,
+        code: &'a str,
+        coupon: &'a str,
+        created: RangeQuery<Timestamp>,
+        customer: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe promotion code.
+///
+/// For more details see https://stripe.com/docs/api/promotion_codes/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PromotionCode {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub code: String,
+    pub coupon: Coupon,
+    pub created: Timestamp,
+    pub customer: Option<String>,
+    pub expires_at: Option<Timestamp>,
+    pub livemode: bool,
+    pub max_redemptions: Option<u64>,
+    pub metadata: Metadata,
+    pub restrictions: PromotionCodeRestrictions,
+    pub times_redeemed: u64,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl PromotionCode {
+    /// Creates a new customer-facing code for a coupon.
+    ///
+    /// For more details see https://stripe.com/docs/api/promotion_codes/create.
+    pub fn create(client: &Client, params: PromotionCodeParams) -> Result<PromotionCode, Error> {
+        client.post("/promotion_codes", params)
+    }
+
+    /// Retrieves the details of a promotion code.
+    ///
+    /// For more details see https://stripe.com/docs/api/promotion_codes/retrieve.
+    pub fn retrieve(client: &Client, promotion_code_id: &str) -> Result<PromotionCode, Error> {
+        client.get(&format!("/promotion_codes/{}", promotion_code_id))
+    }
+
+    /// Updates a promotion code's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/promotion_codes/update.
+    pub fn update(client: &Client, promotion_code_id: &str, params: PromotionCodeParams) -> Result<PromotionCode, Error> {
+        client.post(&format!("/promotion_codes/{}", promotion_code_id), params)
+    }
+
+    /// Lists all promotion codes.
+    ///
+    /// For more details see https://stripe.com/docs/api/promotion_codes/list.
+    pub fn list(client: &Client, params: PromotionCodeListParams) -> Result<List<PromotionCode>, Error> {
+        client.get(&format!("/promotion_codes?{}", to_form(&params)?))
+    }
+}
@@ -0,0 +1,229 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::Currency;
+
+string_enum! {
+    /// The status of a quote.
+    pub enum QuoteStatus {
+        Accepted => "accepted",
+        Canceled => "canceled",
+        Draft => "draft",
+        Open => "open",
+    }
+}
+
+string_enum! {
+    /// How the invoices generated from an accepted quote are paid.
+    pub enum CollectionMethod {
+        ChargeAutomatically => "charge_automatically",
+        SendInvoice => "send_invoice",
+    }
+}
+
+/// The set of parameters that can be used to define a line item of a quote.
+#[derive(Debug, Default, Serialize)]
+pub struct QuoteLineItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<&'a str>>,
+}
+
+setters! {
+    QuoteLineItemParams<'a> {
+        price: &'a str,
+        quantity: u64,
+        tax_rates: Vec<&'a str>,
+    }
+}
+
+/// The set of parameters used to configure the subscription created when a quote is accepted.
+#[derive(Debug, Default, Serialize)]
+pub struct QuoteSubscriptionDataParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u64>,
+}
+
+setters! {
+    QuoteSubscriptionDataParams {
+        effective_date: Timestamp,
+        trial_period_days: u64,
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a quote.
+///
+/// For more details see https://stripe.com/docs/api/quotes/create and https://stripe.com/docs/api/quotes/update.
+#[derive(Debug, Default, Serialize)]
+pub struct QuoteParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<QuoteLineItemParams<'a>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<QuoteSubscriptionDataParams>,
+}
+
+setters! {
+    QuoteParams<'a> {
+        customer: &'a str,
+        line_items: Vec<QuoteLineItemParams<'a>>,
+        collection_method: CollectionMethod,
+        default_tax_rates: Vec<&'a str>,
+        description: &'a str,
+        expires_at: Timestamp,
+        footer: &'a str,
+        header: &'a str,
+        metadata: Metadata,
+        subscription_data: QuoteSubscriptionDataParams,
+    }
+}
+
+/// The set of parameters that can be used when finalizing a quote.
+///
+/// For more details see https://stripe.com/docs/api/quotes/finalize.
+#[derive(Debug, Default, Serialize)]
+pub struct QuoteFinalizeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+setters! {
+    QuoteFinalizeParams {
+        expires_at: Timestamp,
+    }
+}
+
+/// The set of parameters that can be used when listing quotes.
+///
+/// For more details see https://stripe.com/docs/api/quotes/list.
+#[derive(Debug, Default, Serialize)]
+pub struct QuoteListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<QuoteStatus>,
+}
+
+setters! {
+    QuoteListParams<'a> {
+        customer: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        status: QuoteStatus,
+    }
+}
+
+/// The resource representing a Stripe quote.
+///
+/// For more details see https://stripe.com/docs/api/quotes/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Quote {
+    pub id: String,
+    pub object: String,
+    pub amount_subtotal: i64,
+    pub amount_total: i64,
+    pub collection_method: CollectionMethod,
+    pub created: Timestamp,
+    pub currency: Option<Currency>,
+    pub customer: Option<String>,
+    pub description: Option<String>,
+    pub expires_at: Timestamp,
+    pub footer: Option<String>,
+    pub header: Option<String>,
+    pub invoice: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub number: Option<String>,
+    pub status: QuoteStatus,
+    pub subscription: Option<String>,
+    pub subscription_schedule: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Quote {
+    /// Creates a new draft quote.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/create.
+    pub fn create(client: &Client, params: QuoteParams) -> Result<Quote, Error> {
+        client.post("/quotes", params)
+    }
+
+    /// Retrieves the details of a quote.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/retrieve.
+    pub fn retrieve(client: &Client, quote_id: &str) -> Result<Quote, Error> {
+        client.get(&format!("/quotes/{}", quote_id))
+    }
+
+    /// Updates a draft quote.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/update.
+    pub fn update(client: &Client, quote_id: &str, params: QuoteParams) -> Result<Quote, Error> {
+        client.post(&format!("/quotes/{}", quote_id), params)
+    }
+
+    /// Finalizes a draft quote so it can be sent to the customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/finalize.
+    pub fn finalize(client: &Client, quote_id: &str, params: QuoteFinalizeParams) -> Result<Quote, Error> {
+        client.post(&format!("/quotes/{}/finalize", quote_id), params)
+    }
+
+    /// Accepts an open quote, creating its invoice or subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/accept.
+    pub fn accept(client: &Client, quote_id: &str) -> Result<Quote, Error> {
+        client.post_empty(&format!("/quotes/{}/accept", quote_id))
+    }
+
+    /// Cancels a draft or open quote.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/cancel.
+    pub fn cancel(client: &Client, quote_id: &str) -> Result<Quote, Error> {
+        client.post_empty(&format!("/quotes/{}/cancel", quote_id))
+    }
+
+    /// Lists all quotes.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/list.
+    pub fn list(client: &Client, params: QuoteListParams) -> Result<List<Quote>, Error> {
+        client.get(&format!("/quotes?{}", to_form(&params)?))
+    }
+
+    /// Downloads the PDF of a finalized quote.
+    ///
+    /// For more details see https://stripe.com/docs/api/quotes/pdf.
+    pub fn pdf(client: &Client, quote_id: &str) -> Result<Vec<u8>, Error> {
+        client.download(&format!("https://files.stripe.com/v1/quotes/{}/pdf", quote_id))
+    }
+}
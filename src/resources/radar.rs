@@ -0,0 +1,295 @@
+//! Resources for Stripe Radar, Stripe's fraud prevention tools.
+
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::Deleted;
+
+string_enum! {
+    /// The type of the items in a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/object#radar_value_list_object-item_type.
+    pub enum ValueListItemType {
+        CardBin => "card_bin",
+        CardFingerprint => "card_fingerprint",
+        CaseSensitiveString => "case_sensitive_string",
+        Country => "country",
+        Email => "email",
+        IpAddress => "ip_address",
+        String => "string",
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/create.
+#[derive(Debug, Default, Serialize)]
+pub struct ValueListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<&'a str>, // (required on create; used to reference the list in rules)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<ValueListItemType>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    ValueListParams<'a> {
+        alias: &'a str,
+        name: &'a str,
+        item_type: ValueListItemType,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing value lists.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ValueListListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ValueListListParams<'a> {
+        alias: &'a str,
+        contains: &'a str,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Radar value list, eg. a blocklist referenced by rules.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_lists/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValueList {
+    pub id: String,
+    pub object: String,
+    pub alias: String,
+    pub created: Timestamp,
+    pub created_by: String,
+    pub item_type: ValueListItemType,
+    pub list_items: List<ValueListItem>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub name: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ValueList {
+    /// Creates a new value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/create.
+    pub fn create(client: &Client, params: ValueListParams) -> Result<ValueList, Error> {
+        client.post("/radar/value_lists", params)
+    }
+
+    /// Retrieves the details of a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/retrieve.
+    pub fn retrieve(client: &Client, value_list_id: &str) -> Result<ValueList, Error> {
+        client.get(&format!("/radar/value_lists/{}", value_list_id))
+    }
+
+    /// Updates a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/update.
+    pub fn update(client: &Client, value_list_id: &str, params: ValueListParams) -> Result<ValueList, Error> {
+        client.post(&format!("/radar/value_lists/{}", value_list_id), params)
+    }
+
+    /// Deletes a value list; lists referenced by rules can't be deleted.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/delete.
+    pub fn delete(client: &Client, value_list_id: &str) -> Result<Deleted<ValueList>, Error> {
+        client.delete(&format!("/radar/value_lists/{}", value_list_id))
+    }
+
+    /// Lists all value lists.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_lists/list.
+    pub fn list(client: &Client, params: ValueListListParams) -> Result<List<ValueList>, Error> {
+        client.get(&format!("/radar/value_lists?{}", to_form(&params)?))
+    }
+}
+
+/// The set of parameters that can be used when creating a value list item.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_list_items/create.
+#[derive(Debug, Serialize)]
+pub struct ValueListItemParams<'a> {
+    pub value: &'a str,
+    pub value_list: &'a str,
+}
+
+/// The set of parameters that can be used when listing the items of a value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_list_items/list.
+#[derive(Debug, Serialize)]
+pub struct ValueListItemListParams<'a> {
+    pub value_list: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<&'a str>,
+}
+
+setters! {
+    ValueListItemListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+        value: &'a str,
+    }
+}
+
+/// The resource representing an item of a Radar value list.
+///
+/// For more details see https://stripe.com/docs/api/radar/value_list_items/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValueListItem {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub created_by: String,
+    pub livemode: bool,
+    pub value: String,
+    pub value_list: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ValueListItem {
+    /// Adds an item to a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/create.
+    pub fn create(client: &Client, params: ValueListItemParams) -> Result<ValueListItem, Error> {
+        client.post("/radar/value_list_items", params)
+    }
+
+    /// Retrieves the details of a value list item.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/retrieve.
+    pub fn retrieve(client: &Client, item_id: &str) -> Result<ValueListItem, Error> {
+        client.get(&format!("/radar/value_list_items/{}", item_id))
+    }
+
+    /// Removes an item from its value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/delete.
+    pub fn delete(client: &Client, item_id: &str) -> Result<Deleted<ValueListItem>, Error> {
+        client.delete(&format!("/radar/value_list_items/{}", item_id))
+    }
+
+    /// Lists the items of a value list.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/value_list_items/list.
+    pub fn list(client: &Client, params: ValueListItemListParams) -> Result<List<ValueListItem>, Error> {
+        client.get(&format!("/radar/value_list_items?{}", to_form(&params)?))
+    }
+}
+
+string_enum! {
+    /// The type of fraud reported by an early fraud warning.
+    pub enum FraudType {
+        CardNeverReceived => "card_never_received",
+        FraudulentCardApplication => "fraudulent_card_application",
+        MadeWithCounterfeitCard => "made_with_counterfeit_card",
+        MadeWithLostCard => "made_with_lost_card",
+        MadeWithStolenCard => "made_with_stolen_card",
+        Misc => "misc",
+        UnauthorizedUseOfCard => "unauthorized_use_of_card",
+    }
+}
+
+/// The set of parameters that can be used when listing early fraud warnings.
+///
+/// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/list.
+#[derive(Debug, Default, Serialize)]
+pub struct EarlyFraudWarningListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    EarlyFraudWarningListParams<'a> {
+        charge: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        payment_intent: &'a str,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing an early fraud warning issued by a card network.
+///
+/// Refunding an `actionable` warning's charge avoids a likely dispute.
+///
+/// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EarlyFraudWarning {
+    pub id: String,
+    pub object: String,
+    pub actionable: bool,
+    pub charge: String,
+    pub created: Timestamp,
+    pub fraud_type: FraudType,
+    pub livemode: bool,
+    #[serde(default)]
+    pub payment_intent: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl EarlyFraudWarning {
+    /// Retrieves the details of an early fraud warning.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/retrieve.
+    pub fn retrieve(client: &Client, warning_id: &str) -> Result<EarlyFraudWarning, Error> {
+        client.get(&format!("/radar/early_fraud_warnings/{}", warning_id))
+    }
+
+    /// Lists all early fraud warnings.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/early_fraud_warnings/list.
+    pub fn list(client: &Client, params: EarlyFraudWarningListParams) -> Result<List<EarlyFraudWarning>, Error> {
+        client.get(&format!("/radar/early_fraud_warnings?{}", to_form(&params)?))
+    }
+}
@@ -1,6 +1,26 @@
 use params::{Metadata, Timestamp};
 use resources::Currency;
 
+string_enum! {
+    /// The reason given for a refund.
+    pub enum RefundReason {
+        Duplicate => "duplicate",
+        ExpiredUncapturedCharge => "expired_uncaptured_charge",
+        Fraudulent => "fraudulent",
+        RequestedByCustomer => "requested_by_customer",
+    }
+}
+
+string_enum! {
+    /// The status of a refund.
+    pub enum RefundStatus {
+        Canceled => "canceled",
+        Failed => "failed",
+        Pending => "pending",
+        Succeeded => "succeeded",
+    }
+}
+
 /// The resource representing a Stripe refund.
 ///
 /// For more details see https://stripe.com/docs/api#refunds.
@@ -16,7 +36,10 @@ pub struct Refund {
     pub failure_balance_transaction: Option<String>,
     pub failure_reason: Option<String>,
     pub metadata: Metadata,
-    pub reason: Option<String>, // (duplicate, fraudulent, requested_by_customer)
+    pub reason: Option<RefundReason>,
     pub receipt_number: Option<String>,
-    pub status: String, // (succeeded, pending, failed, cancelled)
+    pub status: RefundStatus,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
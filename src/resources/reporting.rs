@@ -0,0 +1,187 @@
+//! Resources for the Stripe Reporting API, used to generate downloadable financial reports.
+
+use error::Error;
+use client::Client;
+use params::{to_form, List, RangeQuery, Timestamp};
+use resources::{Currency, File};
+
+string_enum! {
+    /// The status of a report run.
+    pub enum ReportRunStatus {
+        Failed => "failed",
+        Pending => "pending",
+        Succeeded => "succeeded",
+    }
+}
+
+/// The parameters of a report run; which ones apply depends on the report type.
+///
+/// For more details see https://stripe.com/docs/reports/report-types.
+#[derive(Debug, Default, Serialize)]
+pub struct ReportRunParameters<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connected_account: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_end: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_start: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporting_category: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<&'a str>,
+}
+
+/// The parameters a report run was created with.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ReportRunParametersDetails {
+    #[serde(default)]
+    pub columns: Option<Vec<String>>,
+    #[serde(default)]
+    pub connected_account: Option<String>,
+    #[serde(default)]
+    pub currency: Option<Currency>,
+    #[serde(default)]
+    pub interval_end: Option<Timestamp>,
+    #[serde(default)]
+    pub interval_start: Option<Timestamp>,
+    #[serde(default)]
+    pub payout: Option<String>,
+    #[serde(default)]
+    pub reporting_category: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+/// The set of parameters that can be used when creating a report run.
+///
+/// For more details see https://stripe.com/docs/api/reporting/report_run/create.
+#[derive(Debug, Serialize)]
+pub struct ReportRunParams<'a> {
+    pub report_type: &'a str, // eg. balance.summary.1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<ReportRunParameters<'a>>,
+}
+
+setters! {
+    ReportRunParams<'a> {
+        parameters: ReportRunParameters<'a>,
+    }
+}
+
+/// The set of parameters that can be used when listing report runs.
+///
+/// For more details see https://stripe.com/docs/api/reporting/report_run/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ReportRunListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ReportRunListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe report run.
+///
+/// For more details see https://stripe.com/docs/api/reporting/report_run/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReportRun {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub error: Option<String>,
+    pub livemode: bool,
+    pub parameters: ReportRunParametersDetails,
+    pub report_type: String,
+    pub result: Option<File>,
+    pub status: ReportRunStatus,
+    pub succeeded_at: Option<Timestamp>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ReportRun {
+    /// Creates a new report run; the report is generated asynchronously.
+    ///
+    /// For more details see https://stripe.com/docs/api/reporting/report_run/create.
+    pub fn create(client: &Client, params: ReportRunParams) -> Result<ReportRun, Error> {
+        client.post("/reporting/report_runs", params)
+    }
+
+    /// Retrieves the details of a report run.
+    ///
+    /// For more details see https://stripe.com/docs/api/reporting/report_run/retrieve.
+    pub fn retrieve(client: &Client, report_run_id: &str) -> Result<ReportRun, Error> {
+        client.get(&format!("/reporting/report_runs/{}", report_run_id))
+    }
+
+    /// Lists all report runs.
+    ///
+    /// For more details see https://stripe.com/docs/api/reporting/report_run/list.
+    pub fn list(client: &Client, params: ReportRunListParams) -> Result<List<ReportRun>, Error> {
+        client.get(&format!("/reporting/report_runs?{}", to_form(&params)?))
+    }
+
+    /// Downloads the result of the report run.
+    ///
+    /// Returns `None` if the run has not succeeded (yet).
+    pub fn download_result(&self, client: &Client) -> Result<Option<Vec<u8>>, Error> {
+        match self.result {
+            Some(ref file) => file.download(client).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The resource representing a Stripe report type.
+///
+/// For more details see https://stripe.com/docs/api/reporting/report_type/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReportType {
+    pub id: String,
+    pub object: String,
+    pub data_available_end: Timestamp,
+    pub data_available_start: Timestamp,
+    #[serde(default)]
+    pub default_columns: Option<Vec<String>>,
+    pub name: String,
+    pub updated: Timestamp,
+    pub version: u64,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ReportType {
+    /// Retrieves the details of a report type, eg. `balance.summary.1`.
+    ///
+    /// For more details see https://stripe.com/docs/api/reporting/report_type/retrieve.
+    pub fn retrieve(client: &Client, report_type_id: &str) -> Result<ReportType, Error> {
+        client.get(&format!("/reporting/report_types/{}", report_type_id))
+    }
+
+    /// Lists all report types.
+    ///
+    /// For more details see https://stripe.com/docs/api/reporting/report_type/list.
+    pub fn list(client: &Client) -> Result<List<ReportType>, Error> {
+        client.get("/reporting/report_types")
+    }
+}
@@ -1,5 +1,20 @@
 use params::Timestamp;
 
+string_enum! {
+    /// The reason a review was opened or closed.
+    ///
+    /// For more details see https://stripe.com/docs/api/radar/reviews/object#review_object-reason.
+    pub enum ReviewReason {
+        Approved => "approved",
+        Disputed => "disputed",
+        Manual => "manual",
+        Redacted => "redacted",
+        Refunded => "refunded",
+        RefundedAsFraud => "refunded_as_fraud",
+        Rule => "rule",
+    }
+}
+
 /// The resource representing a Stripe review of a payment.
 ///
 /// For more details see https://stripe.com/docs/api#review_object.
@@ -11,5 +26,8 @@ pub struct Review {
     pub created: Timestamp,
     pub livemode: bool,
     pub open: bool,
-    pub reason: String,
+    pub reason: ReviewReason,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
@@ -1,7 +1,30 @@
-use params::Timestamp;
+use error::Error;
+use client::Client;
+use params::{to_form, List, Timestamp};
 use resources::File;
 use serde_json as json;
 
+/// The set of parameters that can be used when listing scheduled query runs.
+///
+/// For more details see https://stripe.com/docs/api/sigma/scheduled_queries/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ScheduledQueryRunListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ScheduledQueryRunListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
 /// The resource representing a Stripe scheduled query run.
 ///
 /// For more details see https://stripe.com/docs/api#scheduled_query_run_object.
@@ -12,10 +35,39 @@ pub struct ScheduledQueryRun {
     pub created: Timestamp,
     pub data_load_time: Timestamp,
     pub error: Option<json::Value>,
-    pub file: File,
+    pub file: Option<File>, // (not present if the run failed)
     pub livemode: bool,
     pub result_available_until: Timestamp,
     pub sql: String,
     pub status: String, // (completed, canceled, failed, timed_out)
     pub title: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ScheduledQueryRun {
+    /// Retrieves the details of a scheduled query run.
+    ///
+    /// For more details see https://stripe.com/docs/api/sigma/scheduled_queries/retrieve.
+    pub fn retrieve(client: &Client, run_id: &str) -> Result<ScheduledQueryRun, Error> {
+        client.get(&format!("/sigma/scheduled_query_runs/{}", run_id))
+    }
+
+    /// Lists all scheduled query runs.
+    ///
+    /// For more details see https://stripe.com/docs/api/sigma/scheduled_queries/list.
+    pub fn list(client: &Client, params: ScheduledQueryRunListParams) -> Result<List<ScheduledQueryRun>, Error> {
+        client.get(&format!("/sigma/scheduled_query_runs?{}", to_form(&params)?))
+    }
+
+    /// Downloads the CSV results of the run.
+    ///
+    /// Returns `None` if the run has no results file, e.g. because it failed.
+    pub fn download(&self, client: &Client) -> Result<Option<Vec<u8>>, Error> {
+        match self.file {
+            Some(ref file) => file.download(client).map(Some),
+            None => Ok(None),
+        }
+    }
 }
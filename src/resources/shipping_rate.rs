@@ -0,0 +1,185 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::Currency;
+
+string_enum! {
+    /// The type of calculation used for a shipping rate.
+    pub enum ShippingRateType {
+        FixedAmount => "fixed_amount",
+    }
+}
+
+string_enum! {
+    /// Whether a shipping rate is considered inclusive or exclusive of taxes.
+    pub enum TaxBehavior {
+        Exclusive => "exclusive",
+        Inclusive => "inclusive",
+        Unspecified => "unspecified",
+    }
+}
+
+string_enum! {
+    /// A unit of time used in a delivery estimate.
+    pub enum DeliveryEstimateUnit {
+        BusinessDay => "business_day",
+        Day => "day",
+        Hour => "hour",
+        Month => "month",
+        Week => "week",
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeliveryEstimateBound {
+    pub unit: DeliveryEstimateUnit,
+    pub value: u64,
+}
+
+/// The estimated range for how long shipping will take.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DeliveryEstimate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<DeliveryEstimateBound>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<DeliveryEstimateBound>,
+}
+
+/// The amount charged for a fixed amount shipping rate.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FixedAmount {
+    pub amount: u64,
+    pub currency: Currency,
+}
+
+/// The set of parameters that can be used when creating a shipping rate.
+///
+/// For more details see https://stripe.com/docs/api/shipping_rates/create.
+#[derive(Debug, Serialize)]
+pub struct ShippingRateParams<'a> {
+    pub display_name: &'a str,
+    #[serde(rename = "type")]
+    pub shipping_rate_type: ShippingRateType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_estimate: Option<DeliveryEstimate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<FixedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<&'a str>,
+}
+
+setters! {
+    ShippingRateParams<'a> {
+        delivery_estimate: DeliveryEstimate,
+        fixed_amount: FixedAmount,
+        metadata: Metadata,
+        tax_behavior: TaxBehavior,
+        tax_code: &'a str,
+    }
+}
+
+/// The set of parameters that can be used when updating a shipping rate.
+///
+/// For more details see https://stripe.com/docs/api/shipping_rates/update.
+#[derive(Debug, Default, Serialize)]
+pub struct ShippingRateUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxBehavior>,
+}
+
+setters! {
+    ShippingRateUpdateParams {
+        active: bool,
+        metadata: Metadata,
+        tax_behavior: TaxBehavior,
+    }
+}
+
+/// The set of parameters that can be used when listing shipping rates.
+///
+/// For more details see https://stripe.com/docs/api/shipping_rates/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ShippingRateListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    ShippingRateListParams<'a> {
+        active: bool,
+        currency: Currency,
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe shipping rate.
+///
+/// For more details see https://stripe.com/docs/api/shipping_rates/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShippingRate {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub created: Timestamp,
+    pub delivery_estimate: Option<DeliveryEstimate>,
+    pub display_name: Option<String>,
+    pub fixed_amount: Option<FixedAmount>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub tax_behavior: Option<TaxBehavior>,
+    pub tax_code: Option<String>,
+    #[serde(rename = "type")]
+    pub shipping_rate_type: ShippingRateType,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl ShippingRate {
+    /// Creates a new shipping rate.
+    ///
+    /// For more details see https://stripe.com/docs/api/shipping_rates/create.
+    pub fn create(client: &Client, params: ShippingRateParams) -> Result<ShippingRate, Error> {
+        client.post("/shipping_rates", params)
+    }
+
+    /// Retrieves the details of a shipping rate.
+    ///
+    /// For more details see https://stripe.com/docs/api/shipping_rates/retrieve.
+    pub fn retrieve(client: &Client, shipping_rate_id: &str) -> Result<ShippingRate, Error> {
+        client.get(&format!("/shipping_rates/{}", shipping_rate_id))
+    }
+
+    /// Updates a shipping rate.
+    ///
+    /// For more details see https://stripe.com/docs/api/shipping_rates/update.
+    pub fn update(client: &Client, shipping_rate_id: &str, params: ShippingRateUpdateParams) -> Result<ShippingRate, Error> {
+        client.post(&format!("/shipping_rates/{}", shipping_rate_id), params)
+    }
+
+    /// Lists all shipping rates.
+    ///
+    /// For more details see https://stripe.com/docs/api/shipping_rates/list.
+    pub fn list(client: &Client, params: ShippingRateListParams) -> Result<List<ShippingRate>, Error> {
+        client.get(&format!("/shipping_rates?{}", to_form(&params)?))
+    }
+}
@@ -1,13 +1,119 @@
-use params::{Metadata, Timestamp};
-use resources::{Currency, PackageDimensions};
-use serde_json as json;
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, Timestamp};
+use resources::{Currency, Deleted, PackageDimensions};
+use std::collections::HashMap;
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+string_enum! {
+    /// How a SKU's inventory is tracked.
+    ///
+    /// For more details see https://stripe.com/docs/api#sku_object-inventory-type.
+    pub enum InventoryType {
+        Bucket => "bucket",
+        Finite => "finite",
+        Infinite => "infinite",
+    }
+}
+
+string_enum! {
+    /// The stock level of a SKU with `bucket` inventory.
+    pub enum InventoryValue {
+        InStock => "in_stock",
+        Limited => "limited",
+        OutOfStock => "out_of_stock",
+    }
+}
+
+/// The inventory of a SKU.
+///
+/// `quantity` is only used with `finite` inventory and `value` only with `bucket` inventory.
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Inventory {
-    pub quantity: u64,
     #[serde(rename = "type")]
-    pub inventory_type: String,
-    pub value: Option<String>,
+    pub inventory_type: InventoryType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<InventoryValue>,
+}
+
+/// The set of parameters that can be used when creating or updating a SKU.
+///
+/// For more details see https://stripe.com/docs/api#create_sku and https://stripe.com/docs/api#update_sku.
+#[derive(Debug, Default, Serialize)]
+pub struct SkuParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory: Option<Inventory>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<u64>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+}
+
+setters! {
+    SkuParams<'a> {
+        currency: Currency,
+        inventory: Inventory,
+        price: u64,
+        product: &'a str,
+        active: bool,
+        attributes: HashMap<String, String>,
+        id: &'a str,
+        image: &'a str,
+        metadata: Metadata,
+        package_dimensions: PackageDimensions,
+    }
+}
+
+/// The set of parameters that can be used when listing SKUs.
+///
+/// For more details see https://stripe.com/docs/api#list_skus.
+#[derive(Debug, Default, Serialize)]
+pub struct SkuListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_stock: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    SkuListParams<'a> {
+        active: bool,
+        attributes: HashMap<String, String>,
+        ending_before: &'a str,
+        ids: Vec<&'a str>,
+        in_stock: bool,
+        limit: u64,
+        product: &'a str,
+        starting_after: &'a str,
+    }
 }
 
 /// The resource representing a Stripe Sku.
@@ -18,7 +124,7 @@ pub struct Sku {
     pub id: String,
     pub object: String,
     pub active: bool,
-    pub attributes: json::Value,
+    pub attributes: HashMap<String, String>,
     pub created: Timestamp,
     pub currency: Currency,
     pub image: Option<String>,
@@ -29,4 +135,44 @@ pub struct Sku {
     pub price: u64,
     pub product: String,
     pub updated: Timestamp,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Sku {
+    /// Creates a new SKU for a product of type `good`.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_sku.
+    pub fn create(client: &Client, params: SkuParams) -> Result<Sku, Error> {
+        client.post("/skus", params)
+    }
+
+    /// Retrieves the details of a SKU.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_sku.
+    pub fn retrieve(client: &Client, sku_id: &str) -> Result<Sku, Error> {
+        client.get(&format!("/skus/{}", sku_id))
+    }
+
+    /// Updates a SKU's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_sku.
+    pub fn update(client: &Client, sku_id: &str, params: SkuParams) -> Result<Sku, Error> {
+        client.post(&format!("/skus/{}", sku_id), params)
+    }
+
+    /// Deletes a SKU.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_sku.
+    pub fn delete(client: &Client, sku_id: &str) -> Result<Deleted<Sku>, Error> {
+        client.delete(&format!("/skus/{}", sku_id))
+    }
+
+    /// Lists all SKUs.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_skus.
+    pub fn list(client: &Client, params: SkuListParams) -> Result<List<Sku>, Error> {
+        client.get(&format!("/skus?{}", to_form(&params)?))
+    }
 }
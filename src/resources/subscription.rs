@@ -1,8 +1,8 @@
-use error::Error;
+use error::{Error, Violation};
 use client::Client;
-use resources::{Discount, Plan};
-use params::{List, Metadata, Timestamp};
-use serde_qs as qs;
+use money::Money;
+use resources::{Currency, Deleted, Discount, Invoice, InvoiceLineItem, InvoiceUpcomingParams, Plan, SubscriptionItem, TaxRate};
+use params::{to_form, List, Metadata, RangeQuery, Timestamp, UpdateValue, Validate};
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct CancelParams {
@@ -10,11 +10,95 @@ pub struct CancelParams {
     pub at_period_end: Option<bool>,
 }
 
-#[derive(Serialize, Debug)]
+setters! {
+    CancelParams {
+        at_period_end: bool,
+    }
+}
+
+/// The set of parameters that can be used to add, update, or remove an item of a subscription.
+///
+/// When updating a subscription, set `id` to modify an existing item;
+/// otherwise a new item will be created for the given `plan`.
+#[derive(Default, Serialize, Debug)]
 pub struct ItemParams<'a> {
-    pub plan: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<&'a str>>,
+}
+
+setters! {
+    ItemParams<'a> {
+        id: &'a str,
+        deleted: bool,
+        metadata: Metadata,
+        plan: &'a str,
+        quantity: u64,
+        tax_rates: Vec<&'a str>,
+    }
+}
+
+string_enum! {
+    /// How prorations should be handled when a subscription's items or billing cycle change.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/prorations.
+    pub enum ProrationBehavior {
+        AlwaysInvoice => "always_invoice",
+        CreateProrations => "create_prorations",
+        None => "none",
+    }
+}
+
+string_enum! {
+    /// What happens to the invoices of a subscription while its payment collection is paused.
+    pub enum PauseCollectionBehavior {
+        KeepAsDraft => "keep_as_draft",
+        MarkUncollectible => "mark_uncollectible",
+        Void => "void",
+    }
+}
+
+/// The configuration for pausing the payment collection of a subscription.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/pause.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PauseCollection {
+    pub behavior: PauseCollectionBehavior,
+    /// When the subscription resumes collection automatically, if ever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resumes_at: Option<Timestamp>,
+}
+
+/// The anchor used to determine the billing dates of a subscription.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BillingCycleAnchor {
+    /// Reset the billing cycle to start at the time of the request.
+    Now,
+    /// Keep the current billing cycle (only valid on update).
+    Unchanged,
+    /// Anchor the billing cycle to a future timestamp.
+    Timestamp(Timestamp),
+}
+
+impl ::serde::Serialize for BillingCycleAnchor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::ser::Serializer
+    {
+        match *self {
+            BillingCycleAnchor::Now => serializer.serialize_str("now"),
+            BillingCycleAnchor::Unchanged => serializer.serialize_str("unchanged"),
+            BillingCycleAnchor::Timestamp(ts) => serializer.serialize_i64(ts),
+        }
+    }
 }
 
 /// The set of parameters that can be used when creating or updating a subscription.
@@ -27,16 +111,30 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub coupon: Option<&'a str>,
+    pub billing_cycle_anchor: Option<BillingCycleAnchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_at_period_end: Option<bool>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub coupon: UpdateValue<&'a str>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub default_payment_method: UpdateValue<&'a str>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub default_tax_rates: UpdateValue<Vec<&'a str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ItemParams<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "UpdateValue::is_keep")]
+    pub pause_collection: UpdateValue<PauseCollection>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prorate: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
@@ -45,28 +143,168 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trial_end: Option<TrialEnd<'a>>,
+    pub trial_end: Option<TrialEnd>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_from_plan: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
 }
 
+setters! {
+    SubscriptionParams<'a> {
+        customer: &'a str,
+        application_fee_percent: f64,
+        billing_cycle_anchor: BillingCycleAnchor,
+        cancel_at_period_end: bool,
+        coupon: &'a str,
+        default_payment_method: &'a str,
+        items: Vec<ItemParams<'a>>,
+        metadata: Metadata,
+        pause_collection: PauseCollection,
+        plan: &'a str,
+        promotion_code: &'a str,
+        prorate: bool,
+        proration_behavior: ProrationBehavior,
+        proration_date: Timestamp,
+        quantity: u64,
+        source: &'a str,
+        tax_percent: f64,
+        trial_end: TrialEnd,
+        trial_from_plan: bool,
+        trial_period_days: u64,
+    }
+}
+
+impl<'a> SubscriptionParams<'a> {
+    /// Creates the parameters for a new subscription of `customer`.
+    pub fn new(customer: &'a str) -> Self {
+        SubscriptionParams {
+            customer: Some(customer),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the default tax rates, or clears them if `default_tax_rates` is empty.
+    pub fn default_tax_rates(mut self, default_tax_rates: Vec<&'a str>) -> Self {
+        self.default_tax_rates = default_tax_rates.into();
+        self
+    }
+}
 
-#[derive(Serialize, Debug)]
-#[serde(untagged)]
-pub enum TrialEnd<'a> {
+/// The end of a subscription's trial period.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TrialEnd {
+    /// End the trial immediately.
+    Now,
+    /// End the trial at a future timestamp.
     Timestamp(Timestamp),
-    Special(&'a str)
 }
 
-/// The resource representing a Stripe subscription item.
+impl ::serde::Serialize for TrialEnd {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::ser::Serializer
+    {
+        match *self {
+            TrialEnd::Now => serializer.serialize_str("now"),
+            TrialEnd::Timestamp(ts) => serializer.serialize_i64(ts),
+        }
+    }
+}
+
+string_enum! {
+    /// The status of a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/overview#subscription-statuses.
+    pub enum SubscriptionStatus {
+        Active => "active",
+        Canceled => "canceled",
+        Incomplete => "incomplete",
+        IncompleteExpired => "incomplete_expired",
+        PastDue => "past_due",
+        Paused => "paused",
+        Trialing => "trialing",
+        Unpaid => "unpaid",
+    }
+}
+
+/// The effect of changing the items of a subscription, see `Subscription::preview_proration`.
+#[derive(Debug)]
+pub struct ProrationPreview {
+    /// The net amount of the prorations, negative if the customer will be credited.
+    pub amount: Money,
+    /// The proration line items of the upcoming invoice.
+    pub lines: Vec<InvoiceLineItem>,
+    /// The total amount due on the upcoming invoice, including the prorations.
+    pub amount_due: Money,
+}
+
+impl ProrationPreview {
+    fn from_lines<I>(currency: Currency, amount_due: u64, all_lines: I) -> Result<ProrationPreview, Error>
+    where
+        I: IntoIterator<Item = Result<InvoiceLineItem, Error>>,
+    {
+        let overflow = || Error::Conversion("proration amount overflows".into());
+        let amount_due = Money::from_unsigned(amount_due, currency).ok_or_else(overflow)?;
+        let mut amount = Money::zero(currency);
+        let mut lines = Vec::new();
+        for line in all_lines {
+            let line = line?;
+            if line.proration {
+                amount = amount.checked_add(Money::new(line.amount, currency)).ok_or_else(overflow)?;
+                lines.push(line);
+            }
+        }
+        Ok(ProrationPreview { amount, lines, amount_due })
+    }
+}
+
+impl<'a> Validate for SubscriptionParams<'a> {
+    fn violations(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self.plan.is_some() && self.items.is_some() {
+            violations.push(Violation::new("plan", "cannot be combined with `items`"));
+        }
+        if self.trial_from_plan == Some(true) && (self.trial_end.is_some() || self.trial_period_days.is_some()) {
+            violations.push(Violation::new("trial_from_plan", "cannot be combined with `trial_end` or `trial_period_days`"));
+        }
+        if self.trial_end.is_some() && self.trial_period_days.is_some() {
+            violations.push(Violation::new("trial_end", "cannot be combined with `trial_period_days`"));
+        }
+        violations
+    }
+}
+
+/// The set of parameters that can be used when listing subscriptions.
 ///
-/// For more details see https://stripe.com/docs/api#subscription_items.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SubscriptionItem {
-    pub id: String,
-    pub created: Timestamp,
-    pub plan: Plan,
-    pub quantity: u64,
+/// For more details see https://stripe.com/docs/api#list_subscriptions.
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>, // (trialing, active, past_due, canceled, unpaid, all)
+}
+
+setters! {
+    SubscriptionListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        customer: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        plan: &'a str,
+        starting_after: &'a str,
+        status: &'a str,
+    }
 }
 
 /// The resource representing a Stripe subscription.
@@ -76,24 +314,35 @@ pub struct SubscriptionItem {
 pub struct Subscription {
     pub id: String,
     pub application_fee_percent: Option<f64>,
+    #[serde(default)]
+    pub billing_cycle_anchor: Option<Timestamp>,
     pub cancel_at_period_end: bool,
     pub canceled_at: Option<Timestamp>,
     pub created: Option<Timestamp>,
     pub current_period_start: Timestamp,
     pub current_period_end: Timestamp,
     pub customer: String,
+    #[serde(default)]
+    pub default_payment_method: Option<String>,
+    #[serde(default)]
+    pub default_tax_rates: Vec<TaxRate>,
     pub discount: Option<Discount>,
     pub ended_at: Option<Timestamp>,
     pub items: List<SubscriptionItem>,
     pub livemode: bool,
     pub metadata: Metadata,
+    #[serde(default)]
+    pub pause_collection: Option<PauseCollection>,
     pub plan: Plan,
     pub quantity: u64,
     pub start: Timestamp,
-    pub status: String, // (trialing, active, past_due, canceled, unpaid)
+    pub status: SubscriptionStatus,
     pub tax_percent: Option<f64>,
     pub trial_start: Option<Timestamp>,
     pub trial_end: Option<Timestamp>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 impl Subscription {
@@ -101,6 +350,7 @@ impl Subscription {
     ///
     /// For more details see https://stripe.com/docs/api#create_subscription.
     pub fn create(client: &Client, params: SubscriptionParams) -> Result<Subscription, Error> {
+        params.validate()?;
         client.post("/subscriptions", params)
     }
 
@@ -112,15 +362,118 @@ impl Subscription {
     }
 
     /// Updates a subscription's properties.
+    ///
     /// For more details see https://stripe.com/docs/api#update_subscription.
     pub fn update(client: &Client, subscription_id: &str, params: SubscriptionParams) -> Result<Subscription, Error> {
         client.post(&format!("/subscriptions/{}", subscription_id), params)
     }
 
+    /// Pauses the payment collection of a subscription, without canceling it.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/pause.
+    pub fn pause(client: &Client, subscription_id: &str, pause_collection: PauseCollection) -> Result<Subscription, Error> {
+        Subscription::update(client, subscription_id, SubscriptionParams::default().pause_collection(pause_collection))
+    }
+
+    /// Resumes the payment collection of a subscription paused with `Subscription::pause`.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/pause#unpausing.
+    pub fn resume(client: &Client, subscription_id: &str) -> Result<Subscription, Error> {
+        let params = SubscriptionParams { pause_collection: UpdateValue::Clear, ..Default::default() };
+        Subscription::update(client, subscription_id, params)
+    }
+
+    /// Ends the trial period of a subscription immediately, starting its billing.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/trials.
+    pub fn end_trial_now(client: &Client, subscription_id: &str) -> Result<Subscription, Error> {
+        Subscription::update(client, subscription_id, SubscriptionParams::default().trial_end(TrialEnd::Now))
+    }
+
+    /// Previews the prorations of changing the items of a subscription at `proration_date`.
+    ///
+    /// Pass the same `proration_date` when updating the subscription
+    /// to be charged exactly the previewed amount.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/prorations#preview-proration.
+    pub fn preview_proration(&self, client: &Client, new_items: Vec<ItemParams>, proration_date: Timestamp) -> Result<ProrationPreview, Error> {
+        let params = InvoiceUpcomingParams {
+            customer: &self.customer,
+            subscription: Some(&self.id),
+            subscription_items: Some(new_items),
+            subscription_proration_behavior: Some(ProrationBehavior::CreateProrations),
+            subscription_proration_date: Some(proration_date),
+            ..Default::default()
+        };
+        let invoice: Invoice = client.get(&format!("/invoices/upcoming?{}", to_form(&params)?))?;
+        let (currency, amount_due) = (invoice.currency, invoice.amount_due);
+        ProrationPreview::from_lines(currency, amount_due, invoice.lines.paginate(client, &params)?)
+    }
+
     /// Cancels a subscription.
     ///
     /// For more details see https://stripe.com/docs/api#cancel_subscription.
     pub fn cancel(client: &Client, subscription_id: &str, params: CancelParams) -> Result<Subscription, Error> {
-        client.delete(&format!("/subscriptions/{}?{}", subscription_id, qs::to_string(&params)?))
+        client.delete(&format!("/subscriptions/{}?{}", subscription_id, to_form(&params)?))
+    }
+
+    /// Lists subscriptions, by default only those which have not been canceled.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_subscriptions.
+    pub fn list(client: &Client, params: SubscriptionListParams) -> Result<List<Subscription>, Error> {
+        client.get(&format!("/subscriptions?{}", to_form(&params)?))
+    }
+
+    /// Retrieves the discount currently applied to a subscription, if any.
+    pub fn retrieve_discount(client: &Client, subscription_id: &str) -> Result<Option<Discount>, Error> {
+        Subscription::retrieve(client, subscription_id).map(|subscription| subscription.discount)
+    }
+
+    /// Removes the currently applied discount on a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_subscription_discount.
+    pub fn delete_discount(client: &Client, subscription_id: &str) -> Result<Deleted<Discount>, Error> {
+        client.delete(&format!("/subscriptions/{}/discount", subscription_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProrationPreview;
+    use error::Error;
+    use resources::{Currency, InvoiceLineItem};
+    use serde_json as json;
+
+    fn line(id: &str, amount: i64, proration: bool) -> Result<InvoiceLineItem, Error> {
+        let line = format!(
+            r#"{{"id": "{}", "amount": {}, "currency": "usd", "description": null, "discountable": true,
+                "livemode": false, "metadata": {{}}, "period": {{"start": 1500000000, "end": 1502678400}},
+                "plan": null, "proration": {}, "quantity": 1, "subscription": "sub_123", "subscription_item": "si_123"}}"#,
+            id, amount, proration
+        );
+        Ok(json::from_str(&line)?)
+    }
+
+    #[test]
+    fn sums_only_the_proration_lines() {
+        let lines = vec![line("il_1", -1000, true), line("il_2", 2000, false), line("il_3", 1500, true)];
+        let preview = ProrationPreview::from_lines(Currency::USD, 2500, lines).unwrap();
+        assert_eq!(preview.amount.amount, 500);
+        assert_eq!(preview.amount_due.amount, 2500);
+        let ids: Vec<_> = preview.lines.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["il_1", "il_3"]);
+    }
+
+    #[test]
+    fn reports_overflows_and_page_errors() {
+        let lines = vec![line("il_1", i64::max_value(), true), line("il_2", 1, true)];
+        match ProrationPreview::from_lines(Currency::USD, 0, lines) {
+            Err(Error::Conversion(_)) => {}
+            other => panic!("expected a conversion error, got {:?}", other),
+        }
+        assert!(ProrationPreview::from_lines(Currency::USD, u64::max_value(), vec![]).is_err());
+
+        let lines = vec![line("il_1", 100, true), Err(Error::Config("page failed".into()))];
+        assert!(ProrationPreview::from_lines(Currency::USD, 0, lines).is_err());
     }
 }
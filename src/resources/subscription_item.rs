@@ -0,0 +1,142 @@
+use error::Error;
+use client::Client;
+use resources::{Deleted, Plan, ProrationBehavior, TaxRate};
+use params::{to_form, List, Metadata, Timestamp};
+
+/// The set of parameters that can be used when creating or updating a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#create_subscription_item and https://stripe.com/docs/api#update_subscription_item.
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<&'a str>>,
+}
+
+setters! {
+    SubscriptionItemParams<'a> {
+        subscription: &'a str,
+        metadata: Metadata,
+        plan: &'a str,
+        prorate: bool,
+        proration_behavior: ProrationBehavior,
+        proration_date: Timestamp,
+        quantity: u64,
+        tax_rates: Vec<&'a str>,
+    }
+}
+
+/// The set of parameters that can be used when deleting a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#delete_subscription_item.
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionItemDeleteParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_usage: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+}
+
+setters! {
+    SubscriptionItemDeleteParams {
+        clear_usage: bool,
+        prorate: bool,
+        proration_date: Timestamp,
+    }
+}
+
+/// The set of parameters that can be used when listing subscription items.
+///
+/// For more details see https://stripe.com/docs/api#list_subscription_items.
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionItemListParams<'a> {
+    pub subscription: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    SubscriptionItemListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe subscription item.
+///
+/// For more details see https://stripe.com/docs/api#subscription_items.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SubscriptionItem {
+    pub id: String,
+    pub created: Timestamp,
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub plan: Plan,
+    #[serde(default)]
+    // NOTE: Missing for items of metered plans
+    pub quantity: u64,
+    #[serde(default)]
+    pub subscription: Option<String>,
+    #[serde(default)]
+    pub tax_rates: Vec<TaxRate>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl SubscriptionItem {
+    /// Adds a new item to an existing subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_subscription_item.
+    pub fn create(client: &Client, params: SubscriptionItemParams) -> Result<SubscriptionItem, Error> {
+        client.post("/subscription_items", params)
+    }
+
+    /// Retrieves the details of a subscription item.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_subscription_item.
+    pub fn retrieve(client: &Client, item_id: &str) -> Result<SubscriptionItem, Error> {
+        client.get(&format!("/subscription_items/{}", item_id))
+    }
+
+    /// Updates a subscription item's plan or quantity.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_subscription_item.
+    pub fn update(client: &Client, item_id: &str, params: SubscriptionItemParams) -> Result<SubscriptionItem, Error> {
+        client.post(&format!("/subscription_items/{}", item_id), params)
+    }
+
+    /// Deletes an item from its subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_subscription_item.
+    pub fn delete(client: &Client, item_id: &str, params: SubscriptionItemDeleteParams) -> Result<Deleted<SubscriptionItem>, Error> {
+        client.delete(&format!("/subscription_items/{}?{}", item_id, to_form(&params)?))
+    }
+
+    /// Lists the items of a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_subscription_items.
+    pub fn list(client: &Client, params: SubscriptionItemListParams) -> Result<List<SubscriptionItem>, Error> {
+        client.get(&format!("/subscription_items?{}", to_form(&params)?))
+    }
+}
@@ -0,0 +1,314 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+use resources::{ProrationBehavior, TaxRate};
+
+string_enum! {
+    /// What happens to a subscription once its schedule has no more phases.
+    pub enum SubscriptionScheduleEndBehavior {
+        Cancel => "cancel",
+        None => "none",
+        Release => "release",
+        Renew => "renew",
+    }
+}
+
+string_enum! {
+    /// The status of a subscription schedule.
+    pub enum SubscriptionScheduleStatus {
+        Active => "active",
+        Canceled => "canceled",
+        Completed => "completed",
+        NotStarted => "not_started",
+        Released => "released",
+    }
+}
+
+/// The date a subscription schedule starts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SubscriptionScheduleStartDate {
+    /// Start the schedule at the time of the request.
+    Now,
+    Timestamp(Timestamp),
+}
+
+impl ::serde::Serialize for SubscriptionScheduleStartDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::ser::Serializer
+    {
+        match *self {
+            SubscriptionScheduleStartDate::Now => serializer.serialize_str("now"),
+            SubscriptionScheduleStartDate::Timestamp(ts) => serializer.serialize_i64(ts),
+        }
+    }
+}
+
+/// The set of parameters that can be used to define an item of a subscription schedule phase.
+#[derive(Debug, Default, Serialize)]
+pub struct PhaseItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<&'a str>>,
+}
+
+setters! {
+    PhaseItemParams<'a> {
+        plan: &'a str,
+        price: &'a str,
+        quantity: u64,
+        tax_rates: Vec<&'a str>,
+    }
+}
+
+/// The set of parameters that can be used to define a phase of a subscription schedule.
+///
+/// Each phase must specify either an `end_date` or a number of `iterations`, except the last.
+#[derive(Debug, Default, Serialize)]
+pub struct PhaseParams<'a> {
+    pub items: Vec<PhaseItemParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iterations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_end: Option<Timestamp>,
+}
+
+setters! {
+    PhaseParams<'a> {
+        coupon: &'a str,
+        default_tax_rates: Vec<&'a str>,
+        end_date: Timestamp,
+        iterations: u64,
+        proration_behavior: ProrationBehavior,
+        start_date: Timestamp,
+        trial: bool,
+        trial_end: Timestamp,
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a subscription schedule.
+///
+/// For more details see https://stripe.com/docs/api/subscription_schedules/create and https://stripe.com/docs/api/subscription_schedules/update.
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionScheduleParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_subscription: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_behavior: Option<SubscriptionScheduleEndBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phases: Option<Vec<PhaseParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<SubscriptionScheduleStartDate>,
+}
+
+setters! {
+    SubscriptionScheduleParams<'a> {
+        customer: &'a str,
+        from_subscription: &'a str,
+        end_behavior: SubscriptionScheduleEndBehavior,
+        metadata: Metadata,
+        phases: Vec<PhaseParams<'a>>,
+        proration_behavior: ProrationBehavior,
+        start_date: SubscriptionScheduleStartDate,
+    }
+}
+
+/// The set of parameters that can be used when canceling a subscription schedule.
+///
+/// For more details see https://stripe.com/docs/api/subscription_schedules/cancel.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionScheduleCancelParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_now: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+}
+
+setters! {
+    SubscriptionScheduleCancelParams {
+        invoice_now: bool,
+        prorate: bool,
+    }
+}
+
+/// The set of parameters that can be used when releasing a subscription schedule.
+///
+/// For more details see https://stripe.com/docs/api/subscription_schedules/release.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionScheduleReleaseParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_cancel_date: Option<bool>,
+}
+
+setters! {
+    SubscriptionScheduleReleaseParams {
+        preserve_cancel_date: bool,
+    }
+}
+
+/// The set of parameters that can be used when listing subscription schedules.
+///
+/// For more details see https://stripe.com/docs/api/subscription_schedules/list.
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionScheduleListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    SubscriptionScheduleListParams<'a> {
+        created: RangeQuery<Timestamp>,
+        customer: &'a str,
+        ending_before: &'a str,
+        limit: u64,
+        scheduled: bool,
+        starting_after: &'a str,
+    }
+}
+
+/// The start and end of the phase a subscription schedule is currently in.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CurrentPhase {
+    pub start_date: Timestamp,
+    pub end_date: Timestamp,
+}
+
+/// An item of a subscription schedule phase.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PhaseItem {
+    #[serde(default)]
+    pub plan: Option<String>,
+    #[serde(default)]
+    pub price: Option<String>,
+    pub quantity: Option<u64>,
+    #[serde(default)]
+    pub tax_rates: Vec<TaxRate>,
+}
+
+/// A phase of a subscription schedule.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Phase {
+    pub coupon: Option<String>,
+    #[serde(default)]
+    pub default_tax_rates: Vec<TaxRate>,
+    pub end_date: Timestamp,
+    #[serde(alias = "plans")]
+    pub items: Vec<PhaseItem>,
+    pub proration_behavior: ProrationBehavior,
+    pub start_date: Timestamp,
+    pub trial_end: Option<Timestamp>,
+}
+
+/// The resource representing a Stripe subscription schedule.
+///
+/// For more details see https://stripe.com/docs/api/subscription_schedules/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SubscriptionSchedule {
+    pub id: String,
+    pub object: String,
+    pub canceled_at: Option<Timestamp>,
+    pub completed_at: Option<Timestamp>,
+    pub created: Timestamp,
+    pub current_phase: Option<CurrentPhase>,
+    pub customer: String,
+    pub end_behavior: SubscriptionScheduleEndBehavior,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub phases: Vec<Phase>,
+    pub released_at: Option<Timestamp>,
+    pub released_subscription: Option<String>,
+    pub status: SubscriptionScheduleStatus,
+    pub subscription: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl SubscriptionSchedule {
+    /// Creates a new subscription schedule.
+    ///
+    /// For more details see https://stripe.com/docs/api/subscription_schedules/create.
+    pub fn create(client: &Client, params: SubscriptionScheduleParams) -> Result<SubscriptionSchedule, Error> {
+        client.post("/subscription_schedules", params)
+    }
+
+    /// Retrieves the details of a subscription schedule.
+    ///
+    /// For more details see https://stripe.com/docs/api/subscription_schedules/retrieve.
+    pub fn retrieve(client: &Client, schedule_id: &str) -> Result<SubscriptionSchedule, Error> {
+        client.get(&format!("/subscription_schedules/{}", schedule_id))
+    }
+
+    /// Updates a subscription schedule's phases or properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/subscription_schedules/update.
+    pub fn update(
+        client: &Client,
+        schedule_id: &str,
+        params: SubscriptionScheduleParams,
+    ) -> Result<SubscriptionSchedule, Error> {
+        client.post(&format!("/subscription_schedules/{}", schedule_id), params)
+    }
+
+    /// Cancels a subscription schedule and its associated subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api/subscription_schedules/cancel.
+    pub fn cancel(
+        client: &Client,
+        schedule_id: &str,
+        params: SubscriptionScheduleCancelParams,
+    ) -> Result<SubscriptionSchedule, Error> {
+        client.post(&format!("/subscription_schedules/{}/cancel", schedule_id), params)
+    }
+
+    /// Releases a subscription schedule, leaving its subscription in place.
+    ///
+    /// For more details see https://stripe.com/docs/api/subscription_schedules/release.
+    pub fn release(
+        client: &Client,
+        schedule_id: &str,
+        params: SubscriptionScheduleReleaseParams,
+    ) -> Result<SubscriptionSchedule, Error> {
+        client.post(&format!("/subscription_schedules/{}/release", schedule_id), params)
+    }
+
+    /// Lists all subscription schedules.
+    ///
+    /// For more details see https://stripe.com/docs/api/subscription_schedules/list.
+    pub fn list(client: &Client, params: SubscriptionScheduleListParams) -> Result<List<SubscriptionSchedule>, Error> {
+        client.get(&format!("/subscription_schedules?{}", to_form(&params)?))
+    }
+}
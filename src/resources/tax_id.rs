@@ -0,0 +1,144 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Timestamp};
+use resources::Deleted;
+
+string_enum! {
+    /// The type of a customer tax ID.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_tax_ids/object#tax_id_object-type.
+    pub enum TaxIdType {
+        AeTrn => "ae_trn",
+        AuAbn => "au_abn",
+        BrCnpj => "br_cnpj",
+        BrCpf => "br_cpf",
+        CaBn => "ca_bn",
+        CaQst => "ca_qst",
+        ChVat => "ch_vat",
+        ClTin => "cl_tin",
+        EsCif => "es_cif",
+        EuVat => "eu_vat",
+        GbVat => "gb_vat",
+        HkBr => "hk_br",
+        IdNpwp => "id_npwp",
+        InGst => "in_gst",
+        JpCn => "jp_cn",
+        JpRn => "jp_rn",
+        KrBrn => "kr_brn",
+        LiUid => "li_uid",
+        MxRfc => "mx_rfc",
+        MyFrp => "my_frp",
+        MyItn => "my_itn",
+        MySst => "my_sst",
+        NoVat => "no_vat",
+        NzGst => "nz_gst",
+        RuInn => "ru_inn",
+        RuKpp => "ru_kpp",
+        SaVat => "sa_vat",
+        SgGst => "sg_gst",
+        SgUen => "sg_uen",
+        ThVat => "th_vat",
+        TwVat => "tw_vat",
+        UsEin => "us_ein",
+        ZaVat => "za_vat",
+        Unknown => "unknown",
+    }
+}
+
+string_enum! {
+    /// The verification status of a customer tax ID.
+    pub enum TaxIdVerificationStatus {
+        Pending => "pending",
+        Unavailable => "unavailable",
+        Unverified => "unverified",
+        Verified => "verified",
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TaxIdVerification {
+    pub status: TaxIdVerificationStatus,
+    pub verified_address: Option<String>,
+    pub verified_name: Option<String>,
+}
+
+/// The set of parameters that can be used when creating a customer tax ID.
+///
+/// For more details see https://stripe.com/docs/api/customer_tax_ids/create.
+#[derive(Debug, Serialize)]
+pub struct TaxIdParams<'a> {
+    #[serde(rename = "type")]
+    pub tax_id_type: TaxIdType,
+    pub value: &'a str,
+}
+
+/// The set of parameters that can be used when listing customer tax IDs.
+///
+/// For more details see https://stripe.com/docs/api/customer_tax_ids/list.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TaxIdListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    TaxIdListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe customer tax ID.
+///
+/// For more details see https://stripe.com/docs/api/customer_tax_ids/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TaxId {
+    pub id: String,
+    pub object: String,
+    pub country: Option<String>,
+    pub created: Timestamp,
+    pub customer: String,
+    pub livemode: bool,
+    #[serde(rename = "type")]
+    pub tax_id_type: TaxIdType,
+    pub value: String,
+    pub verification: Option<TaxIdVerification>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl TaxId {
+    /// Creates a new tax ID for a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_tax_ids/create.
+    pub fn create(client: &Client, customer_id: &str, params: TaxIdParams) -> Result<TaxId, Error> {
+        client.post(&format!("/customers/{}/tax_ids", customer_id), params)
+    }
+
+    /// Retrieves the details of a customer's tax ID.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_tax_ids/retrieve.
+    pub fn retrieve(client: &Client, customer_id: &str, tax_id: &str) -> Result<TaxId, Error> {
+        client.get(&format!("/customers/{}/tax_ids/{}", customer_id, tax_id))
+    }
+
+    /// Deletes a customer's tax ID.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_tax_ids/delete.
+    pub fn delete(client: &Client, customer_id: &str, tax_id: &str) -> Result<Deleted<TaxId>, Error> {
+        client.delete(&format!("/customers/{}/tax_ids/{}", customer_id, tax_id))
+    }
+
+    /// Lists a customer's tax IDs.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_tax_ids/list.
+    pub fn list(client: &Client, customer_id: &str, params: TaxIdListParams) -> Result<List<TaxId>, Error> {
+        client.get(&format!("/customers/{}/tax_ids?{}", customer_id, to_form(&params)?))
+    }
+}
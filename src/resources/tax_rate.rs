@@ -0,0 +1,130 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Metadata, RangeQuery, Timestamp};
+
+/// The set of parameters that can be used when creating or updating a tax rate.
+///
+/// The `inclusive` and `percentage` of a tax rate cannot be changed once it has been created.
+///
+/// For more details see https://stripe.com/docs/api/tax_rates/create and https://stripe.com/docs/api/tax_rates/update.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TaxRateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inclusive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jurisdiction: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<&'a str>,
+}
+
+setters! {
+    TaxRateParams<'a> {
+        display_name: &'a str,
+        inclusive: bool,
+        percentage: f64,
+        active: bool,
+        country: &'a str,
+        description: &'a str,
+        jurisdiction: &'a str,
+        metadata: Metadata,
+        state: &'a str,
+    }
+}
+
+/// The set of parameters that can be used when listing tax rates.
+///
+/// For more details see https://stripe.com/docs/api/tax_rates/list.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TaxRateListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inclusive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    TaxRateListParams<'a> {
+        active: bool,
+        created: RangeQuery<Timestamp>,
+        ending_before: &'a str,
+        inclusive: bool,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe tax rate.
+///
+/// For more details see https://stripe.com/docs/api/tax_rates/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TaxRate {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    #[serde(default)]
+    pub country: Option<String>,
+    pub created: Timestamp,
+    pub description: Option<String>,
+    pub display_name: String,
+    pub inclusive: bool,
+    pub jurisdiction: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub percentage: f64,
+    #[serde(default)]
+    pub state: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl TaxRate {
+    /// Creates a new tax rate.
+    ///
+    /// For more details see https://stripe.com/docs/api/tax_rates/create.
+    pub fn create(client: &Client, params: TaxRateParams) -> Result<TaxRate, Error> {
+        client.post("/tax_rates", params)
+    }
+
+    /// Retrieves the details of a tax rate.
+    ///
+    /// For more details see https://stripe.com/docs/api/tax_rates/retrieve.
+    pub fn retrieve(client: &Client, tax_rate_id: &str) -> Result<TaxRate, Error> {
+        client.get(&format!("/tax_rates/{}", tax_rate_id))
+    }
+
+    /// Updates a tax rate's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/tax_rates/update.
+    pub fn update(client: &Client, tax_rate_id: &str, params: TaxRateParams) -> Result<TaxRate, Error> {
+        client.post(&format!("/tax_rates/{}", tax_rate_id), params)
+    }
+
+    /// Lists all tax rates.
+    ///
+    /// For more details see https://stripe.com/docs/api/tax_rates/list.
+    pub fn list(client: &Client, params: TaxRateListParams) -> Result<List<TaxRate>, Error> {
+        client.get(&format!("/tax_rates?{}", to_form(&params)?))
+    }
+}
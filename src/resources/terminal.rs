@@ -0,0 +1,397 @@
+//! Resources for Stripe Terminal, used to accept in-person payments.
+
+use error::Error;
+use client::{Client, Profile};
+use params::{to_form, List, Metadata};
+use path::Path;
+use resources::{Address, Deleted};
+
+/// The set of parameters that can be used when creating a connection token.
+///
+/// For more details see https://stripe.com/docs/api/terminal/connection_tokens/create.
+#[derive(Debug, Default, Serialize)]
+pub struct ConnectionTokenParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>,
+}
+
+setters! {
+    ConnectionTokenParams<'a> {
+        location: &'a str,
+    }
+}
+
+/// The resource representing a short-lived token used by a Terminal SDK to connect to a reader.
+///
+/// For more details see https://stripe.com/docs/api/terminal/connection_tokens/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConnectionToken {
+    pub object: String,
+    #[serde(default)]
+    pub location: Option<String>,
+    pub secret: String,
+}
+
+impl ConnectionToken {
+    /// Creates a new connection token.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/connection_tokens/create.
+    pub fn create(client: &Client, params: ConnectionTokenParams) -> Result<ConnectionToken, Error> {
+        client.post("/terminal/connection_tokens", params)
+    }
+}
+
+/// The set of parameters that can be used when creating or updating a location.
+///
+/// For more details see https://stripe.com/docs/api/terminal/locations/create.
+#[derive(Debug, Default, Serialize)]
+pub struct LocationParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>, // (required on create)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    LocationParams<'a> {
+        address: Address,
+        display_name: &'a str,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing locations.
+///
+/// For more details see https://stripe.com/docs/api/terminal/locations/list.
+#[derive(Debug, Default, Serialize)]
+pub struct LocationListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    LocationListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Terminal location, a physical place readers are registered to.
+///
+/// For more details see https://stripe.com/docs/api/terminal/locations/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Location {
+    pub id: String,
+    pub object: String,
+    pub address: Address,
+    pub display_name: String,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Location {
+    /// Creates a new location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/create.
+    pub fn create(client: &Client, params: LocationParams) -> Result<Location, Error> {
+        client.post("/terminal/locations", params)
+    }
+
+    /// Retrieves the details of a location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/retrieve.
+    pub fn retrieve(client: &Client, location_id: &str) -> Result<Location, Error> {
+        client.get(&format!("/terminal/locations/{}", location_id))
+    }
+
+    /// Updates a location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/update.
+    pub fn update(client: &Client, location_id: &str, params: LocationParams) -> Result<Location, Error> {
+        client.post(&format!("/terminal/locations/{}", location_id), params)
+    }
+
+    /// Deletes a location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/delete.
+    pub fn delete(client: &Client, location_id: &str) -> Result<Deleted<Location>, Error> {
+        client.delete(&format!("/terminal/locations/{}", location_id))
+    }
+
+    /// Lists all locations.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/list.
+    pub fn list(client: &Client, params: LocationListParams) -> Result<List<Location>, Error> {
+        client.get(&format!("/terminal/locations?{}", to_form(&params)?))
+    }
+}
+
+string_enum! {
+    /// The type of a reader device.
+    pub enum ReaderDeviceType {
+        BbposChipper2x => "bbpos_chipper2x",
+        BbposWisepad3 => "bbpos_wisepad3",
+        BbposWiseposE => "bbpos_wisepos_e",
+        SimulatedWiseposE => "simulated_wisepos_e",
+        StripeM2 => "stripe_m2",
+        VerifoneP400 => "verifone_p400",
+    }
+}
+
+string_enum! {
+    /// The type of action a reader is performing.
+    pub enum ReaderActionType {
+        ProcessPaymentIntent => "process_payment_intent",
+        ProcessSetupIntent => "process_setup_intent",
+        RefundPayment => "refund_payment",
+        SetReaderDisplay => "set_reader_display",
+    }
+}
+
+string_enum! {
+    /// The status of the action a reader is performing.
+    pub enum ReaderActionStatus {
+        Failed => "failed",
+        InProgress => "in_progress",
+        Succeeded => "succeeded",
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProcessPaymentIntentAction {
+    pub payment_intent: String,
+}
+
+/// The action a reader is performing for a server-driven integration.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReaderAction {
+    #[serde(rename = "type")]
+    pub action_type: ReaderActionType,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    #[serde(default)]
+    pub process_payment_intent: Option<ProcessPaymentIntentAction>,
+    pub status: ReaderActionStatus,
+}
+
+/// The set of parameters that can be used when registering or updating a reader.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/create.
+#[derive(Debug, Default, Serialize)]
+pub struct ReaderParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_code: Option<&'a str>, // (required on create)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>, // (create only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+setters! {
+    ReaderParams<'a> {
+        registration_code: &'a str,
+        label: &'a str,
+        location: &'a str,
+        metadata: Metadata,
+    }
+}
+
+/// The set of parameters that can be used when listing readers.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/list.
+#[derive(Debug, Default, Serialize)]
+pub struct ReaderListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<ReaderDeviceType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>, // (online, offline)
+}
+
+setters! {
+    ReaderListParams<'a> {
+        device_type: ReaderDeviceType,
+        ending_before: &'a str,
+        limit: u64,
+        location: &'a str,
+        starting_after: &'a str,
+        status: &'a str,
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ProcessPaymentIntentParams<'a> {
+    payment_intent: &'a str,
+}
+
+string_enum! {
+    /// The type of payment method presented to a simulated reader.
+    pub enum PresentPaymentMethodType {
+        CardPresent => "card_present",
+        InteracPresent => "interac_present",
+    }
+}
+
+/// The card presented to a simulated reader.
+#[derive(Debug, Default, Serialize)]
+pub struct PresentedCard<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when presenting a payment method to a simulated reader.
+///
+/// Without a card number, Stripe presents a test card that succeeds.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/present_payment_method.
+#[derive(Debug, Default, Serialize)]
+pub struct PresentPaymentMethodParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_tip: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_present: Option<PresentedCard<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interac_present: Option<PresentedCard<'a>>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method_type: Option<PresentPaymentMethodType>,
+}
+
+setters! {
+    PresentPaymentMethodParams<'a> {
+        amount_tip: u64,
+        card_present: PresentedCard<'a>,
+        interac_present: PresentedCard<'a>,
+        method_type: PresentPaymentMethodType,
+    }
+}
+
+impl<'a> PresentPaymentMethodParams<'a> {
+    /// Presents a card with the given test number, eg. `4000000000000002` to decline the payment.
+    pub fn card(number: &'a str) -> Self {
+        PresentPaymentMethodParams {
+            card_present: Some(PresentedCard { number: Some(number) }),
+            method_type: Some(PresentPaymentMethodType::CardPresent),
+            ..PresentPaymentMethodParams::default()
+        }
+    }
+
+    /// Presents an Interac card with the given test number.
+    pub fn interac(number: &'a str) -> Self {
+        PresentPaymentMethodParams {
+            interac_present: Some(PresentedCard { number: Some(number) }),
+            method_type: Some(PresentPaymentMethodType::InteracPresent),
+            ..PresentPaymentMethodParams::default()
+        }
+    }
+}
+
+/// The resource representing a Terminal reader.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Reader {
+    pub id: String,
+    pub object: String,
+    #[serde(default)]
+    pub action: Option<ReaderAction>,
+    pub device_sw_version: Option<String>,
+    pub device_type: ReaderDeviceType,
+    pub ip_address: Option<String>,
+    pub label: String,
+    pub livemode: bool,
+    pub location: Option<String>,
+    pub metadata: Metadata,
+    pub serial_number: String,
+    pub status: Option<String>, // (online, offline)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl Reader {
+    /// Registers a new reader to a location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/create.
+    pub fn create(client: &Client, params: ReaderParams) -> Result<Reader, Error> {
+        client.post("/terminal/readers", params)
+    }
+
+    /// Retrieves the details of a reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/retrieve.
+    pub fn retrieve(client: &Client, reader_id: &str) -> Result<Reader, Error> {
+        client.get(&format!("/terminal/readers/{}", reader_id))
+    }
+
+    /// Updates a reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/update.
+    pub fn update(client: &Client, reader_id: &str, params: ReaderParams) -> Result<Reader, Error> {
+        client.post(&format!("/terminal/readers/{}", reader_id), params)
+    }
+
+    /// Deletes a reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/delete.
+    pub fn delete(client: &Client, reader_id: &str) -> Result<Deleted<Reader>, Error> {
+        client.delete(&format!("/terminal/readers/{}", reader_id))
+    }
+
+    /// Lists all readers.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/list.
+    pub fn list(client: &Client, params: ReaderListParams) -> Result<List<Reader>, Error> {
+        client.get(&format!("/terminal/readers?{}", to_form(&params)?))
+    }
+
+    /// Hands a payment intent to the reader to collect and process a payment.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/process_payment_intent.
+    pub fn process_payment_intent(client: &Client, reader_id: &str, payment_intent_id: &str) -> Result<Reader, Error> {
+        let params = ProcessPaymentIntentParams { payment_intent: payment_intent_id };
+        client.post(&format!("/terminal/readers/{}/process_payment_intent", reader_id), params)
+    }
+
+    /// Cancels the reader's current action.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/cancel_action.
+    pub fn cancel_action(client: &Client, reader_id: &str) -> Result<Reader, Error> {
+        client.post_empty(&format!("/terminal/readers/{}/cancel_action", reader_id))
+    }
+
+    /// Presents a payment method to a simulated reader, completing its current
+    /// `process_payment_intent` action as if a card had been tapped or inserted.
+    ///
+    /// This is only available in test mode, so it fails without sending a request
+    /// when the client has a live mode key.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/present_payment_method.
+    pub fn test_helpers_present_payment_method(client: &Client, reader_id: &str, params: PresentPaymentMethodParams) -> Result<Reader, Error> {
+        if client.profile() == Some(Profile::Live) {
+            return Err(Error::Config("payment methods can only be presented to readers in test mode".to_string()));
+        }
+        client.post(&Path::new("/test_helpers/terminal/readers").id(reader_id)?.segment("present_payment_method"), params)
+    }
+}
@@ -0,0 +1,117 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Timestamp};
+use resources::Deleted;
+
+string_enum! {
+    /// The status of a test clock.
+    pub enum TestClockStatus {
+        Advancing => "advancing",
+        InternalFailure => "internal_failure",
+        Ready => "ready",
+    }
+}
+
+/// The set of parameters that can be used when creating a test clock.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/create.
+#[derive(Debug, Serialize)]
+pub struct TestClockParams<'a> {
+    pub frozen_time: Timestamp,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+setters! {
+    TestClockParams<'a> {
+        name: &'a str,
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TestClockAdvanceParams {
+    frozen_time: Timestamp,
+}
+
+/// The set of parameters that can be used when listing test clocks.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/list.
+#[derive(Debug, Default, Serialize)]
+pub struct TestClockListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    TestClockListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The resource representing a Stripe test clock.
+///
+/// Test clocks only exist in test mode; objects attached to a clock (e.g. customers
+/// created with `test_clock`) see the clock's `frozen_time` as the current time.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TestClock {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub deletes_after: Timestamp,
+    pub frozen_time: Timestamp,
+    pub livemode: bool,
+    pub name: Option<String>,
+    pub status: TestClockStatus,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl TestClock {
+    /// Creates a new test clock.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/create.
+    pub fn create(client: &Client, params: TestClockParams) -> Result<TestClock, Error> {
+        client.post("/test_helpers/test_clocks", params)
+    }
+
+    /// Retrieves the details of a test clock.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/retrieve.
+    pub fn retrieve(client: &Client, test_clock_id: &str) -> Result<TestClock, Error> {
+        client.get(&format!("/test_helpers/test_clocks/{}", test_clock_id))
+    }
+
+    /// Advances a test clock to `frozen_time`, which must be later than its current time.
+    ///
+    /// The clock's status is `advancing` until all attached objects have caught up.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/advance.
+    pub fn advance(client: &Client, test_clock_id: &str, frozen_time: Timestamp) -> Result<TestClock, Error> {
+        let params = TestClockAdvanceParams { frozen_time };
+        client.post(&format!("/test_helpers/test_clocks/{}/advance", test_clock_id), params)
+    }
+
+    /// Deletes a test clock, along with all objects attached to it.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/delete.
+    pub fn delete(client: &Client, test_clock_id: &str) -> Result<Deleted<TestClock>, Error> {
+        client.delete(&format!("/test_helpers/test_clocks/{}", test_clock_id))
+    }
+
+    /// Lists all test clocks.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/list.
+    pub fn list(client: &Client, params: TestClockListParams) -> Result<List<TestClock>, Error> {
+        client.get(&format!("/test_helpers/test_clocks?{}", to_form(&params)?))
+    }
+}
@@ -1,5 +1,67 @@
-use params::{List, Metadata, Timestamp};
-use resources::Currency;
+use client::Client;
+use error::{Error, Violation};
+use params::{List, Metadata, Timestamp, Validate};
+use path::Path;
+use resources::{Charge, ChargeParams, Currency};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The set of parameters that can be used when creating a transfer.
+///
+/// For more details see https://stripe.com/docs/api/transfers/create.
+#[derive(Clone, Debug, Serialize)]
+pub struct TransferParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    pub destination: &'a str, // (a connected account id)
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_transaction: Option<&'a str>, // (a charge id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+setters! {
+    TransferParams<'a> {
+        description: &'a str,
+        metadata: Metadata,
+        source_transaction: &'a str,
+        transfer_group: &'a str,
+    }
+}
+
+impl<'a> TransferParams<'a> {
+    /// Creates the parameters for a transfer of `amount` in `currency` to the connected account `destination`.
+    pub fn new(amount: u64, currency: Currency, destination: &'a str) -> Self {
+        TransferParams {
+            amount: amount,
+            currency: currency,
+            destination: destination,
+            description: None,
+            metadata: None,
+            source_transaction: None,
+            transfer_group: None,
+        }
+    }
+}
+
+impl<'a> Validate for TransferParams<'a> {
+    fn violations(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self.amount == 0 {
+            violations.push(Violation::new("amount", "must be greater than 0"));
+        }
+        if self.destination.is_empty() {
+            violations.push(Violation::new("destination", "must not be empty"));
+        }
+        violations
+    }
+}
 
 /// The resource representing a Stripe transfer reversal.
 ///
@@ -14,6 +76,9 @@ pub struct TransferReversal {
     pub currency: Currency,
     pub metadata: Metadata,
     pub transfer: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
 }
 
 /// The resource representing a Stripe transfer.
@@ -35,7 +100,265 @@ pub struct Transfer {
     pub metadata: Metadata,
     pub reversals: List<TransferReversal>,
     pub reversed: bool,
-    pub source_transaction: String,
+    pub source_transaction: Option<String>,
     pub source_type: String,
-    pub transfer_group: String,
+    pub transfer_group: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl TransferReversal {}
+
+impl Transfer {
+    /// Creates a transfer to a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/transfers/create.
+    pub fn create(client: &Client, params: TransferParams) -> Result<Transfer, Error> {
+        params.validate()?;
+        client.post("/transfers", params)
+    }
+
+    /// Retrieves the details of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api/transfers/retrieve.
+    pub fn retrieve(client: &Client, transfer_id: &str) -> Result<Transfer, Error> {
+        client.get(&Path::new("/transfers").id(transfer_id)?)
+    }
+}
+
+/// A transfer of a `TransferGroup` which failed, see `TransferGroupResult`.
+///
+/// The transfer may have been created anyway, eg. if the connection failed after Stripe
+/// received the request, so it should be retried with `TransferGroup::retry_transfer`.
+#[derive(Debug)]
+pub struct TransferFailure {
+    /// The index of the transfer in the list given to `TransferGroup::charge_and_transfer`.
+    pub index: usize,
+    pub destination: String,
+    pub amount: u64,
+    pub error: Error,
+}
+
+/// The outcome of `TransferGroup::charge_and_transfer`.
+#[derive(Debug)]
+pub struct TransferGroupResult {
+    pub charge: Charge,
+    /// The transfers which were created, in the order they were given.
+    pub transfers: Vec<Transfer>,
+    /// The transfers which failed, eg. to retry them later with `TransferGroup::retry_transfer`.
+    pub failures: Vec<TransferFailure>,
+}
+
+impl TransferGroupResult {
+    /// Whether all the transfers were created.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Groups a charge with the transfers which pay its funds out to connected accounts,
+/// ie. the "separate charges and transfers" flow of Connect.
+///
+/// For more details see https://stripe.com/docs/connect/separate-charges-and-transfers.
+///
+/// ```rust,ignore
+/// let group = stripe::TransferGroup::new();
+/// let result = group.charge_and_transfer(&client, charge_params, vec![
+///     stripe::TransferParams::new(7000, stripe::Currency::USD, "acct_seller"),
+///     stripe::TransferParams::new(2000, stripe::Currency::USD, "acct_courier"),
+/// ])?;
+/// for failure in &result.failures {
+///     println!("transfer to {} failed: {}", failure.destination, failure.error);
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferGroup {
+    id: String,
+}
+
+impl TransferGroup {
+    /// Creates a group with a newly generated id, eg. `group_16f8b2c1a9e3_4d2_0`.
+    pub fn new() -> TransferGroup {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| {
+            time.as_secs() * 1_000_000_000 + u64::from(time.subsec_nanos())
+        });
+        let id = format!(
+            "group_{:x}_{:x}_{:x}",
+            nanos.unwrap_or(0),
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        TransferGroup { id: id }
+    }
+
+    /// Uses an existing id for the group, eg. the id of the order being paid.
+    pub fn with_id<Str: Into<String>>(id: Str) -> TransferGroup {
+        TransferGroup { id: id.into() }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Creates a charge in this group.
+    pub fn charge<'a>(&'a self, client: &Client, mut params: ChargeParams<'a>) -> Result<Charge, Error> {
+        params.transfer_group = Some(&self.id);
+        Charge::create(client, params)
+    }
+
+    /// Creates a transfer in this group.
+    pub fn transfer<'a>(&'a self, client: &Client, mut params: TransferParams<'a>) -> Result<Transfer, Error> {
+        params.transfer_group = Some(&self.id);
+        Transfer::create(client, params)
+    }
+
+    /// The idempotency key of the charge of `charge_and_transfer`, eg. `group_123-charge`.
+    pub fn charge_idempotency_key(&self) -> String {
+        format!("{}-charge", self.id)
+    }
+
+    /// The idempotency key of the transfer at `index` of `charge_and_transfer`, eg. `group_123-transfer-0`.
+    pub fn idempotency_key(&self, index: usize) -> String {
+        format!("{}-transfer-{}", self.id, index)
+    }
+
+    /// Creates a charge, then a transfer of its funds for each of `transfers`, all in this group.
+    ///
+    /// The transfers are tied to the charge with `source_transaction`, so they can be created
+    /// before the charge's funds are available. This fails if the charge can't be created, but
+    /// a failed transfer doesn't stop the others: it is reported in `TransferGroupResult::failures`.
+    ///
+    /// The charge is sent with an idempotency key derived from the group, so calling this again
+    /// after a network error doesn't charge the customer twice. Each transfer is sent with an
+    /// idempotency key derived from the group and its index, so a failed transfer can be retried
+    /// with `retry_transfer` without paying it twice.
+    ///
+    /// Because of those keys, a group id must not be reused for a different charge: within the
+    /// 24 hours Stripe keeps idempotency keys, the request would fail as an `IdempotencyMismatch`
+    /// or return the earlier charge.
+    ///
+    /// The params are validated before anything is created, including that the transfers
+    /// don't exceed the amount of the charge.
+    pub fn charge_and_transfer<'a>(
+        &'a self,
+        client: &Client,
+        charge: ChargeParams<'a>,
+        transfers: Vec<TransferParams<'a>>,
+    ) -> Result<TransferGroupResult, Error> {
+        let mut violations = charge.violations();
+        for transfer in &transfers {
+            violations.extend(transfer.violations());
+            if charge.currency.map_or(false, |currency| currency != transfer.currency) {
+                violations.push(Violation::new("currency", "must be the same for the charge and its transfers"));
+            }
+        }
+        let total = transfers.iter().fold(Some(0u64), |total, transfer| total.and_then(|total| total.checked_add(transfer.amount)));
+        match (charge.amount, total) {
+            (Some(amount), Some(total)) if total <= amount => {}
+            (None, Some(_)) => {}
+            _ => violations.push(Violation::new("amount", "the transfers must not exceed the amount of the charge")),
+        }
+        if !violations.is_empty() {
+            return Err(Error::InvalidParams(violations));
+        }
+
+        let charge = {
+            let client = client.with_header("Idempotency-Key", self.charge_idempotency_key());
+            self.charge(&client, charge)?
+        };
+        let mut result = TransferGroupResult { charge: charge, transfers: Vec::new(), failures: Vec::new() };
+        for (index, transfer) in transfers.into_iter().enumerate() {
+            let destination = transfer.destination.to_string();
+            let amount = transfer.amount;
+            match self.retry_transfer(client, &result.charge.id, index, transfer) {
+                Ok(transfer) => result.transfers.push(transfer),
+                Err(error) => result.failures.push(TransferFailure { index: index, destination: destination, amount: amount, error: error }),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Creates the transfer at `index` of `charge_and_transfer`, eg. to retry a `TransferFailure`.
+    ///
+    /// The transfer is sent with the same idempotency key, so if an earlier attempt was created
+    /// the existing transfer is returned rather than a new one. The params must be the same as
+    /// for the earlier attempt, and Stripe only keeps idempotency keys for 24 hours.
+    pub fn retry_transfer<'a>(
+        &'a self,
+        client: &Client,
+        charge_id: &'a str,
+        index: usize,
+        mut params: TransferParams<'a>,
+    ) -> Result<Transfer, Error> {
+        params.source_transaction = Some(charge_id);
+        let client = client.with_header("Idempotency-Key", self.idempotency_key(index));
+        self.transfer(&client, params)
+    }
+}
+
+impl Default for TransferGroup {
+    fn default() -> Self {
+        TransferGroup::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TransferGroup, TransferParams};
+    use resources::ChargeParams;
+    use client::testing;
+    use resources::Currency;
+    use std::net::TcpListener;
+
+    #[test]
+    fn retries_reuse_the_idempotency_key() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = testing::client(&listener);
+        let server = testing::serve(listener, 2, |_| {
+            let body = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+            format!("HTTP/1.1 500 Stub\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}", body.len(), body)
+        });
+
+        let group = TransferGroup::with_id("order_123");
+        for _ in 0..2 {
+            let params = TransferParams::new(7000, Currency::USD, "acct_seller");
+            assert!(group.retry_transfer(&client, "ch_123", 1, params).is_err());
+        }
+        for head in server.join().unwrap() {
+            assert!(head.contains("Idempotency-Key: order_123-transfer-1\r\n"), "{}", head);
+        }
+    }
+
+    #[test]
+    fn retrieve_rejects_an_empty_id() {
+        use error::Error;
+        use resources::Transfer;
+
+        match Transfer::retrieve(&::Client::new("sk_test_123"), "") {
+            Err(Error::Config(_)) => {}
+            other => panic!("unexpected {:?}", other.map(|transfer| transfer.id)),
+        }
+    }
+
+    #[test]
+    fn the_charge_has_an_idempotency_key() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = testing::client(&listener);
+        let server = testing::serve(listener, 1, |_| {
+            let body = r#"{"error": {"type": "api_error", "message": "oops"}}"#;
+            format!("HTTP/1.1 500 Stub\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}", body.len(), body)
+        });
+
+        let group = TransferGroup::with_id("order_123");
+        let mut charge = ChargeParams::default();
+        charge.amount = Some(10000);
+        charge.currency = Some(Currency::USD);
+        let transfers = vec![TransferParams::new(7000, Currency::USD, "acct_seller")];
+        assert!(group.charge_and_transfer(&client, charge, transfers).is_err());
+        let heads = server.join().unwrap();
+        assert!(heads[0].contains("Idempotency-Key: order_123-charge\r\n"), "{}", heads[0]);
+    }
 }
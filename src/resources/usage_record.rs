@@ -0,0 +1,122 @@
+use error::Error;
+use client::Client;
+use params::{to_form, List, Timestamp};
+
+string_enum! {
+    /// Describes whether a plan is billed by a fixed quantity or by reported usage.
+    pub enum UsageType {
+        Licensed => "licensed",
+        Metered => "metered",
+    }
+}
+
+string_enum! {
+    /// The possible ways a usage record's quantity can be applied to a subscription item.
+    pub enum UsageRecordAction {
+        /// Adds the quantity to any usage already reported for the timestamp.
+        Increment => "increment",
+        /// Overwrites any usage already reported for the timestamp.
+        Set => "set",
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct UsageRecordParams {
+    quantity: u64,
+    timestamp: Timestamp,
+    action: UsageRecordAction,
+}
+
+/// The resource representing a Stripe usage record.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UsageRecord {
+    pub id: String,
+    pub object: String,
+    pub livemode: bool,
+    pub quantity: u64,
+    pub subscription_item: String,
+    pub timestamp: Timestamp,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl UsageRecord {
+    /// Reports usage for a metered subscription item.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_create.
+    pub fn create(
+        client: &Client,
+        subscription_item_id: &str,
+        quantity: u64,
+        timestamp: Timestamp,
+        action: UsageRecordAction,
+    ) -> Result<UsageRecord, Error> {
+        let params = UsageRecordParams { quantity, timestamp, action };
+        client.post(&format!("/subscription_items/{}/usage_records", subscription_item_id), params)
+    }
+}
+
+/// The set of parameters that can be used when listing usage record summaries.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_summary_list.
+#[derive(Debug, Default, Serialize)]
+pub struct UsageRecordSummaryListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+setters! {
+    UsageRecordSummaryListParams<'a> {
+        ending_before: &'a str,
+        limit: u64,
+        starting_after: &'a str,
+    }
+}
+
+/// The billing period covered by a usage record summary.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UsagePeriod {
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+}
+
+/// The resource representing the usage reported for a subscription item over a billing period.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_summary_object.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UsageRecordSummary {
+    pub id: String,
+    pub object: String,
+    pub invoice: Option<String>,
+    pub livemode: bool,
+    pub period: UsagePeriod,
+    pub subscription_item: String,
+    pub total_usage: u64,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ::params::ExtraFields,
+}
+
+impl UsageRecordSummary {
+    /// Lists the usage summaries of a subscription item, one per billing period.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_summary_list.
+    pub fn list(
+        client: &Client,
+        subscription_item_id: &str,
+        params: UsageRecordSummaryListParams,
+    ) -> Result<List<UsageRecordSummary>, Error> {
+        client.get(&format!(
+            "/subscription_items/{}/usage_record_summaries?{}",
+            subscription_item_id,
+            to_form(&params)?
+        ))
+    }
+}
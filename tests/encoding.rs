@@ -1,4 +1,5 @@
 #[macro_use] extern crate serde_json as json;
+#[macro_use] extern crate serde_derive;
 extern crate serde_qs as qs;
 extern crate stripe;
 
@@ -34,6 +35,20 @@ fn deserialize_currency() {
     assert_eq!(json::from_str::<Currency>("\"zmw\"").unwrap(), Currency::ZMW);
 }
 
+#[test]
+fn deserialize_exchange_rate() {
+    use stripe::{Currency, ExchangeRate};
+
+    let rate: ExchangeRate = json::from_value(json!({
+        "id": "usd",
+        "object": "exchange_rate",
+        "rates": {"eur": 0.9, "xyz": 42.0},
+    })).unwrap();
+    assert_eq!(rate.rate(Currency::EUR), Some(0.9));
+    assert_eq!(rate.rate(Currency::GBP), None);
+    assert_eq!(rate.rates["xyz"], 42.0);
+}
+
 #[test]
 fn serialize_range_query() {
     use stripe::{CustomerListParams, RangeQuery, RangeBounds};
@@ -125,3 +140,734 @@ fn serialize_payment_source_params() {
         assert_eq!(&value, expected);
     }
 }
+
+#[test]
+fn serialize_metadata() {
+    use stripe::{CustomerParams, Metadata};
+
+    let mut metadata = Metadata::new();
+    metadata.unset("note");
+    let mut params = CustomerParams::default();
+    params.metadata = Some(metadata);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "metadata[note]=");
+
+    let metadata: Metadata = json::from_str("{\"order_id\":\"42\"}").unwrap();
+    assert_eq!(metadata.get("order_id"), Some("42"));
+    assert_eq!(json::from_str::<Metadata>("null").unwrap(), Metadata::new());
+}
+
+#[test]
+fn serialize_metadata_form_fields() {
+    use stripe::{Address, CustomerParams, Metadata, Shipping};
+
+    // From https://stripe.com/docs/api/metadata and https://stripe.com/docs/api/customers/create
+    let metadata = vec![("order_id", "6735"), ("customer note", "fragile & heavy")].into_iter().collect::<Metadata>();
+    let params = CustomerParams {
+        email: Some("jenny.rosen@example.com"),
+        metadata: Some(metadata),
+        shipping: Some(Shipping {
+            name: "Jenny Rosen".to_string(),
+            address: Address {
+                line1: Some("510 Townsend St".to_string()),
+                postal_code: Some("98140".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(stripe::to_form(&params).unwrap()),
+        "email=jenny.rosen%40example.com&metadata[customer+note]=fragile+%26+heavy&metadata[order_id]=6735\
+         &shipping[name]=Jenny+Rosen&shipping[address][line1]=510+Townsend+St&shipping[address][postal_code]=98140"
+    );
+
+    let mut metadata = Metadata::new();
+    metadata.set("order[id]", 6735);
+    assert!(stripe::to_form(&CustomerParams { metadata: Some(metadata), ..Default::default() }).is_err());
+}
+
+#[test]
+fn deserialize_deleted() {
+    use stripe::{Customer, Deleted, MaybeDeleted, PaymentSource};
+
+    let deleted: Deleted<Customer> = json::from_str("{\"id\":\"cus_123\",\"object\":\"customer\",\"deleted\":true}").unwrap();
+    assert!(deleted.deleted);
+    assert_eq!(deleted.id, "cus_123");
+
+    let source: MaybeDeleted<PaymentSource> = json::from_str("{\"id\":\"card_123\",\"object\":\"card\",\"deleted\":true}").unwrap();
+    assert!(source.is_deleted());
+}
+
+#[test]
+fn serialize_charge_params_builder() {
+    use stripe::{ChargeParams, Currency};
+
+    let params = ChargeParams::new(1000, Currency::USD).customer("cus_123".to_string()).capture(false);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amount=1000&currency=usd&capture=false&customer=cus_123");
+}
+
+#[test]
+fn serialize_generated_params_setters() {
+    use stripe::{Currency, PlanParams, SubscriptionParams};
+
+    let params = PlanParams::default().amount(500).currency(Currency::EUR).interval("month");
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amount=500&currency=eur&interval=month");
+
+    let params = SubscriptionParams::default().coupon("FREE").default_tax_rates(vec![]);
+    assert_eq!(stripe::to_form(&params).unwrap(), "coupon=FREE&default_tax_rates=");
+}
+
+#[test]
+fn serialize_update_value() {
+    use stripe::{SubscriptionParams, UpdateValue};
+
+    let mut params = SubscriptionParams::default();
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "");
+
+    params.coupon = UpdateValue::Clear;
+    params.default_payment_method = UpdateValue::Set("pm_123");
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "coupon=&default_payment_method=pm_123");
+
+    params.coupon = None.into();
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "default_payment_method=pm_123");
+
+    // A field which isn't skipped when `Keep` mustn't clear the value
+    #[derive(Serialize)]
+    struct Unskipped {
+        coupon: UpdateValue<&'static str>,
+    }
+    assert!(stripe::to_form(&Unskipped { coupon: UpdateValue::Keep }).is_err());
+}
+
+#[test]
+fn serialize_invoice_settings() {
+    use stripe::{CustomerParams, InvoiceCustomField, InvoiceSettingsParams, UpdateValue};
+
+    let params = CustomerParams::new().invoice_settings(InvoiceSettingsParams {
+        custom_fields: UpdateValue::Set(vec![InvoiceCustomField { name: "PO".to_string(), value: "1234".to_string() }]),
+        default_payment_method: UpdateValue::Set("pm_123"),
+        footer: UpdateValue::Clear,
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "invoice_settings[custom_fields][0][name]=PO&invoice_settings[custom_fields][0][value]=1234\
+         &invoice_settings[default_payment_method]=pm_123&invoice_settings[footer]="
+    );
+}
+
+#[test]
+fn serialize_trial_end() {
+    use stripe::{SubscriptionParams, TrialEnd};
+
+    let params = SubscriptionParams::default().trial_end(TrialEnd::Now);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "trial_end=now");
+
+    let params = SubscriptionParams::default().trial_end(TrialEnd::Timestamp(1504233902.into())).trial_from_plan(false);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "trial_end=1504233902&trial_from_plan=false");
+}
+
+#[test]
+fn serialize_pause_collection() {
+    use stripe::{PauseCollection, PauseCollectionBehavior, SubscriptionParams, UpdateValue};
+
+    let params = SubscriptionParams::default().pause_collection(PauseCollection {
+        behavior: PauseCollectionBehavior::MarkUncollectible,
+        resumes_at: Some(1504233902.into()),
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "pause_collection[behavior]=mark_uncollectible&pause_collection[resumes_at]=1504233902"
+    );
+
+    let params = SubscriptionParams { pause_collection: UpdateValue::Clear, ..Default::default() };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "pause_collection=");
+}
+
+#[test]
+fn serialize_range_query_bounds() {
+    use stripe::{RangeQuery, Timestamp};
+
+    assert_eq!(urldecode(qs::to_string(&RangeQuery::lt(1504233902)).unwrap()), "lt=1504233902");
+    assert_eq!(urldecode(qs::to_string(&RangeQuery::lte(1504233902)).unwrap()), "lte=1504233902");
+
+    let query: RangeQuery<Timestamp> = RangeQuery::between(1501598702, 1504233902);
+    assert_eq!(urldecode(qs::to_string(&query).unwrap()), "gte=1501598702&lt=1504233902");
+}
+
+#[test]
+#[cfg(feature = "extra-fields")]
+fn deserialize_extra_fields() {
+    use stripe::TaxId;
+
+    let tax_id: TaxId = json::from_value(json!({
+        "id": "txi_123",
+        "object": "tax_id",
+        "country": "DE",
+        "created": 1501598702,
+        "customer": "cus_123",
+        "livemode": false,
+        "type": "eu_vat",
+        "value": "DE123456789",
+        "verification": null,
+        "owner": {"type": "customer"}
+    })).unwrap();
+    assert_eq!(tax_id.extra.get("owner"), Some(&json!({"type": "customer"})));
+    assert_eq!(json::to_value(&tax_id).unwrap()["owner"], json!({"type": "customer"}));
+}
+
+#[test]
+fn deserialize_status_fallback() {
+    use stripe::SubscriptionStatus;
+
+    assert_eq!(json::from_str::<SubscriptionStatus>("\"past_due\"").unwrap(), SubscriptionStatus::PastDue);
+    let status = json::from_str::<SubscriptionStatus>("\"on_hold\"").unwrap();
+    assert_eq!(status, SubscriptionStatus::Other("on_hold".to_string()));
+    assert_eq!(json::to_string(&status).unwrap(), "\"on_hold\"");
+}
+
+#[test]
+fn deserialize_payment_method_details() {
+    use stripe::{CardBrand, CardFunding, PaymentMethodDetails, PaymentMethodType, WalletType};
+
+    let card = json!({
+        "type": "card",
+        "card": {
+            "brand": "visa",
+            "funding": "credit",
+            "last4": "4242",
+            "wallet": {"type": "apple_pay", "dynamic_last4": "1234"},
+        },
+    });
+    let details: PaymentMethodDetails = json::from_value(card).unwrap();
+    match details {
+        PaymentMethodDetails::Card(ref card) => {
+            assert_eq!(card.brand, Some(CardBrand::Visa));
+            assert_eq!(card.funding, Some(CardFunding::Credit));
+            assert_eq!(card.last4.as_ref().map(String::as_str), Some("4242"));
+            assert_eq!(card.wallet.as_ref().map(|wallet| &wallet.wallet_type), Some(&WalletType::ApplePay));
+        }
+        ref other => panic!("unexpected details: {:?}", other),
+    }
+    assert_eq!(json::to_value(&details).unwrap()["card"]["last4"], "4242");
+
+    let details: PaymentMethodDetails = json::from_value(json!({"type": "card_present", "card_present": {"last4": "0000"}})).unwrap();
+    assert_eq!(details.payment_method_type(), PaymentMethodType::CardPresent);
+    assert_eq!(details.card().and_then(|card| card.last4.as_ref()).map(String::as_str), Some("0000"));
+
+    let sepa = json!({"type": "sepa_debit", "sepa_debit": {"last4": "3000"}});
+    let details: PaymentMethodDetails = json::from_value(sepa.clone()).unwrap();
+    match details {
+        PaymentMethodDetails::Other(PaymentMethodType::SepaDebit, ref value) => assert_eq!(value["last4"], "3000"),
+        ref other => panic!("unexpected details: {:?}", other),
+    }
+    assert!(details.card().is_none());
+    assert_eq!(json::to_value(&details).unwrap(), sepa);
+
+    let details: PaymentMethodDetails = json::from_value(json!({"type": "new_wallet", "new_wallet": {}})).unwrap();
+    assert_eq!(details.payment_method_type(), PaymentMethodType::Other("new_wallet".to_string()));
+    assert!(json::from_value::<PaymentMethodDetails>(json!({"card": {}})).is_err());
+}
+
+#[test]
+fn deserialize_balance_transaction_source() {
+    use stripe::{BalanceTransactionSource, Expandable};
+
+    let source: Expandable<BalanceTransactionSource> = json::from_str("\"ch_123\"").unwrap();
+    assert!(!source.is_object());
+
+    let source: Expandable<BalanceTransactionSource> =
+        json::from_value(json!({"id": "adj_123", "object": "adjustment", "amount": -1500, "currency": "usd"})).unwrap();
+    match source.as_object() {
+        Some(&BalanceTransactionSource::Adjustment(ref adjustment)) => {
+            assert_eq!(adjustment.id, "adj_123");
+            assert_eq!(adjustment.amount, Some(-1500));
+        }
+        other => panic!("unexpected source: {:?}", other),
+    }
+
+    let source: Expandable<BalanceTransactionSource> = json::from_value(json!({"id": "fee_123", "object": "application_fee"})).unwrap();
+    match source.as_object() {
+        Some(&BalanceTransactionSource::Other(ref value)) => assert_eq!(value["id"], "fee_123"),
+        other => panic!("unexpected source: {:?}", other),
+    }
+}
+
+#[test]
+fn deserialize_request_error_codes() {
+    use stripe::{DeclineCode, Error, ErrorCode, RequestError};
+
+    let err: RequestError = json::from_value(json!({
+        "type": "card_error",
+        "code": "card_declined",
+        "decline_code": "insufficient_funds",
+        "message": "Your card has insufficient funds."
+    })).unwrap();
+    assert_eq!(err.decline_code, Some(DeclineCode::InsufficientFunds));
+    assert!(Error::from(err).is_card_declined());
+
+    let err: RequestError = json::from_value(json!({"type": "invalid_request_error", "code": "brand_new_code"})).unwrap();
+    assert_eq!(err.code, Some(ErrorCode::Other("brand_new_code".to_string())));
+}
+
+#[test]
+fn display_request_error() {
+    use stripe::RequestError;
+
+    let mut err: RequestError = json::from_value(json!({
+        "type": "card_error",
+        "code": "card_declined",
+        "message": "Your card was declined.",
+        "param": "payment_method",
+        "payment_intent": {"id": "pi_123", "object": "payment_intent"},
+        "doc_url": "https://stripe.com/docs/error-codes/card-declined"
+    })).unwrap();
+    err.http_status = 402;
+    assert_eq!(err.payment_intent, Some("pi_123".to_string()));
+    assert_eq!(
+        err.to_string(),
+        "card_error(402): Your card was declined. (param: payment_method) (payment_intent: pi_123) \
+         see https://stripe.com/docs/error-codes/card-declined"
+    );
+}
+
+#[test]
+fn retryable_request_errors() {
+    use stripe::{Error, ErrorCode, RequestError};
+
+    let mut err = RequestError::default();
+    err.http_status = 429;
+    assert!(Error::from(err).is_retryable());
+
+    let mut err = RequestError::default();
+    err.http_status = 409;
+    err.code = Some(ErrorCode::LockTimeout);
+    assert!(Error::from(err).is_retryable());
+
+    let mut err = RequestError::default();
+    err.http_status = 402;
+    err.code = Some(ErrorCode::CardDeclined);
+    assert!(!Error::from(err).is_retryable());
+}
+
+#[test]
+fn idempotency_errors_are_distinct() {
+    use stripe::{Error, RequestError};
+
+    let mut err: RequestError = json::from_value(json!({
+        "type": "idempotency_error",
+        "message": "Keys for idempotent requests can only be used with the same parameters they were first used with."
+    })).unwrap();
+    err.http_status = 400;
+    let err = Error::from(err);
+    match err {
+        Error::IdempotencyMismatch { .. } => {}
+        ref other => panic!("expected an idempotency mismatch, got {:?}", other),
+    }
+    assert!(err.is_idempotency_conflict());
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn deserialize_discount_event() {
+    use stripe::{Event, EventObject, EventOrigin};
+
+    let event: Event = json::from_value(json!({
+        "id": "evt_1",
+        "type": "customer.discount.created",
+        "data": {"object": {
+            "id": "di_1",
+            "object": "discount",
+            "coupon": {
+                "id": "SUMMER", "object": "coupon", "amount_off": null, "created": 1_600_000_000, "currency": null,
+                "duration": "once", "duration_in_months": null, "livemode": false, "max_redemptions": null,
+                "metadata": {}, "percent_off": 25.0, "redeem_by": null, "times_redeemed": 1, "valid": true
+            },
+            "customer": "cus_1",
+            "promotion_code": "promo_1",
+            "subscription": null,
+            "start": 1_600_000_000,
+            "end": null
+        }}
+    })).unwrap();
+    assert_eq!(event.origin(), EventOrigin::Platform);
+    match event.data.object {
+        EventObject::Discount(discount) => {
+            assert_eq!(discount.id, "di_1");
+            assert_eq!(discount.promotion_code, Some("promo_1".to_string()));
+        }
+        other => panic!("expected a discount, got {:?}", other),
+    }
+}
+
+#[test]
+fn serialize_checkout_price_data() {
+    use stripe::checkout::{SessionLineItemParams, SessionParams};
+    use stripe::{Currency, PriceDataParams, ProductDataParams};
+
+    let params = SessionParams {
+        line_items: Some(vec![SessionLineItemParams {
+            price_data: Some(PriceDataParams {
+                currency: Currency::USD,
+                product: None,
+                product_data: Some(ProductDataParams { name: "Donation", ..Default::default() }),
+                recurring: None,
+                unit_amount: Some(2500),
+                unit_amount_decimal: None,
+            }),
+            quantity: Some(1),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "line_items[0][price_data][currency]=usd&line_items[0][price_data][product_data][name]=Donation\
+         &line_items[0][price_data][unit_amount]=2500&line_items[0][quantity]=1"
+    );
+}
+
+#[test]
+fn serialize_funding_instructions() {
+    use stripe::{BankTransferType, Currency, EuBankTransferParams, FundingInstructionsParams};
+
+    let mut params = FundingInstructionsParams::new(Currency::EUR, BankTransferType::EuBankTransfer);
+    params.bank_transfer.eu_bank_transfer = Some(EuBankTransferParams { country: "FR" });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "bank_transfer[type]=eu_bank_transfer&bank_transfer[eu_bank_transfer][country]=FR\
+         &currency=eur&funding_type=bank_transfer"
+    );
+}
+
+#[test]
+fn deserialize_source_transaction_event() {
+    use stripe::{Event, EventObject, EventOrigin};
+
+    let event: Event = json::from_value(json!({
+        "id": "evt_1",
+        "account": "acct_1",
+        "type": "source.transaction.created",
+        "data": {"object": {
+            "id": "srctxn_1",
+            "object": "source_transaction",
+            "ach_credit_transfer": {"customer_data": null, "fingerprint": "af1", "last4": "6789", "routing_number": "110000000"},
+            "amount": 1000,
+            "created": 1_600_000_000,
+            "currency": "usd",
+            "livemode": false,
+            "source": "src_1",
+            "status": "succeeded",
+            "type": "ach_credit_transfer"
+        }}
+    })).unwrap();
+    assert_eq!(event.origin(), EventOrigin::ConnectedAccount("acct_1"));
+    match event.data.object {
+        EventObject::SourceTransaction(transaction) => assert_eq!(transaction.amount, 1000),
+        other => panic!("expected a source transaction, got {:?}", other),
+    }
+}
+
+#[test]
+fn deserialize_balance() {
+    use stripe::{Balance, Currency, Money};
+
+    let balance: Balance = json::from_value(json!({
+        "object": "balance",
+        "available": [{"amount": 2000, "currency": "usd", "source_types": {"card": 1500, "bank_account": 500}}],
+        "livemode": false,
+        "pending": [{"amount": -300, "currency": "eur", "source_types": {"card": -300}}]
+    })).unwrap();
+    assert_eq!(balance.available_in(Currency::USD), Money::new(2000, Currency::USD));
+    assert_eq!(balance.available[0].source_types.as_ref().unwrap().bank_account, 500);
+    assert_eq!(balance.pending_in(Currency::EUR), Money::new(-300, Currency::EUR));
+    assert!(balance.available_in(Currency::EUR).is_zero());
+}
+
+#[test]
+fn serialize_nested_arrays() {
+    use stripe::{Expand, ItemParams, SubscriptionParams};
+
+    let params = SubscriptionParams {
+        customer: Some("cus_123"),
+        items: Some(vec![
+            ItemParams { plan: Some("gold"), tax_rates: Some(vec!["txr_1", "txr_2"]), ..Default::default() },
+            ItemParams { id: Some("si_123"), deleted: Some(true), ..Default::default() },
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(stripe::to_form(&params).unwrap()),
+        "customer=cus_123&items[0][plan]=gold&items[0][tax_rates][0]=txr_1&items[0][tax_rates][1]=txr_2\
+         &items[1][id]=si_123&items[1][deleted]=true"
+    );
+
+    let expand = Expand { expand: &["customer", "latest_invoice.payment_intent"] };
+    assert_eq!(
+        urldecode(stripe::to_form(&expand).unwrap()),
+        "expand[0]=customer&expand[1]=latest_invoice.payment_intent"
+    );
+}
+
+#[test]
+fn nested_arrays_round_trip() {
+    use stripe::{ItemParams, SubscriptionParams};
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        plan: String,
+        quantity: Option<u64>,
+        tax_rates: Option<Vec<String>>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Decoded {
+        items: Vec<Item>,
+    }
+
+    // More than 10 items, so the indexes must be ordered as numbers rather than strings
+    let plans = (0..12).map(|i| format!("plan_{}", i)).collect::<Vec<_>>();
+    let params = SubscriptionParams {
+        items: Some(plans.iter().map(|plan| ItemParams {
+            plan: Some(plan),
+            quantity: Some(2),
+            tax_rates: Some(vec!["txr_1"]),
+            ..Default::default()
+        }).collect()),
+        ..Default::default()
+    };
+    let decoded: Decoded = qs::from_str(&stripe::to_form(&params).unwrap()).unwrap();
+    assert_eq!(decoded.items.len(), 12);
+    for (item, plan) in decoded.items.iter().zip(&plans) {
+        assert_eq!(item, &Item { plan: plan.clone(), quantity: Some(2), tax_rates: Some(vec!["txr_1".to_string()]) });
+    }
+}
+
+#[test]
+fn serialize_empty_list_update() {
+    use stripe::{SubscriptionParams, UpdateValue};
+
+    let params = SubscriptionParams::default().default_tax_rates(vec![]);
+    assert_eq!(params.default_tax_rates, UpdateValue::Clear);
+    assert_eq!(stripe::to_form(&params).unwrap(), "default_tax_rates=");
+
+    let mut params = SubscriptionParams::default();
+    params.default_tax_rates = Vec::new().into();
+    assert_eq!(stripe::to_form(&params).unwrap(), "default_tax_rates=");
+
+    params.default_tax_rates = vec!["txr_1"].into();
+    assert_eq!(urldecode(stripe::to_form(&params).unwrap()), "default_tax_rates[0]=txr_1");
+}
+
+#[test]
+fn validate_statement_descriptors() {
+    use stripe::{ChargeParams, Currency, Error, PaymentIntentParams, Validate};
+
+    let params = ChargeParams::new(1000, Currency::USD).statement_descriptor("ACME* Shop");
+    match params.validate() {
+        Err(Error::InvalidParams(violations)) => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].param, "statement_descriptor");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let params = PaymentIntentParams::new(1000, Currency::USD)
+        .statement_descriptor("ACME SHOP")
+        .statement_descriptor_suffix("ORDER 1234 FOR A VERY BIG BOX");
+    match params.validate() {
+        Err(Error::InvalidParams(violations)) => {
+            assert_eq!(violations.len(), 1);
+            assert_eq!(violations[0].param, "statement_descriptor_suffix");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let params = PaymentIntentParams::new(1000, Currency::USD).statement_descriptor_suffix("ORDER 1234");
+    assert!(params.validate().is_ok());
+    assert!(ChargeParams::new(1000, Currency::USD).statement_descriptor("1234").validate().is_err());
+    assert!(ChargeParams::new(1000, Currency::USD).statement_descriptor_suffix("1234").validate().is_ok());
+}
+
+#[test]
+fn validate_connect_params() {
+    use stripe::{ChargeParams, Currency, DestinationParams, PaymentIntentParams, TransferDataParams, Validate};
+
+    let transfer = |amount| TransferDataParams { destination: "acct_123", amount };
+    let params = ChargeParams::new(1000, Currency::USD).application_fee_amount(100).transfer_data(transfer(None)).on_behalf_of("acct_123");
+    assert!(params.validate().is_ok());
+
+    let params = ChargeParams::new(1000, Currency::USD).application_fee_amount(1001);
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["application_fee_amount"]);
+
+    let params = ChargeParams::new(1000, Currency::USD).application_fee_amount(100).transfer_data(transfer(Some(2000)));
+    assert_eq!(
+        params.violations().iter().map(|v| v.param).collect::<Vec<_>>(),
+        vec!["transfer_data[amount]", "transfer_data[amount]"]
+    );
+
+    let params = ChargeParams::new(1000, Currency::USD)
+        .transfer_data(TransferDataParams { destination: "", amount: None })
+        .on_behalf_of("acct_456");
+    assert_eq!(
+        params.violations().iter().map(|v| v.param).collect::<Vec<_>>(),
+        vec!["transfer_data[destination]", "on_behalf_of"]
+    );
+
+    let params = ChargeParams::new(1000, Currency::USD)
+        .application_fee(100)
+        .application_fee_amount(100)
+        .destination(DestinationParams { account: "acct_123", amount: 900 })
+        .transfer_data(transfer(None));
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["application_fee", "destination"]);
+
+    let params = PaymentIntentParams::new(1000, Currency::USD).transfer_data(transfer(Some(1500))).on_behalf_of("acct_456");
+    assert_eq!(
+        params.violations().iter().map(|v| v.param).collect::<Vec<_>>(),
+        vec!["transfer_data[amount]", "on_behalf_of"]
+    );
+}
+
+#[test]
+fn validate_params() {
+    use stripe::{ChargeParams, Currency, ItemParams, PaymentSourceParams, SubscriptionParams, TokenId, Validate};
+
+    let params = ChargeParams::new(0, Currency::USD);
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["amount"]);
+
+    let params = ChargeParams { amount: Some(1000), ..Default::default() };
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["currency"]);
+
+    let params = ChargeParams::default();
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["amount", "currency"]);
+    assert!(params.validate().is_err());
+
+    let params = ChargeParams::new(1000, Currency::USD)
+        .customer("cus_123".to_string())
+        .source(PaymentSourceParams::Token("tok_visa".parse::<TokenId>().unwrap()));
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["source"]);
+    assert!(params.validate().is_err());
+
+    let params = SubscriptionParams::new("cus_123").plan("gold").items(vec![ItemParams::default()]);
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["plan"]);
+    let params = SubscriptionParams::new("cus_123").plan("gold").trial_from_plan(true).trial_period_days(7);
+    assert_eq!(params.violations().iter().map(|v| v.param).collect::<Vec<_>>(), vec!["trial_from_plan"]);
+    assert!(SubscriptionParams::new("cus_123").plan("gold").trial_period_days(7).validate().is_ok());
+}
+
+#[test]
+fn transfer_group_validates_before_charging() {
+    use stripe::{ChargeParams, Client, Currency, Error, TransferGroup, TransferParams};
+
+    let group = TransferGroup::new();
+    assert!(group.id().starts_with("group_"));
+    assert_ne!(group, TransferGroup::new());
+
+    // Invalid params fail before any request is sent
+    let client = Client::new("sk_test_123");
+    let transfers = vec![
+        TransferParams::new(7000, Currency::USD, "acct_seller"),
+        TransferParams::new(4000, Currency::EUR, "acct_courier"),
+    ];
+    match group.charge_and_transfer(&client, ChargeParams::new(10000, Currency::USD), transfers) {
+        Err(Error::InvalidParams(violations)) => {
+            let params = violations.iter().map(|v| v.param).collect::<Vec<_>>();
+            assert_eq!(params, vec!["currency", "amount"]);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // The total of the transfers mustn't overflow
+    let transfers = vec![
+        TransferParams::new(u64::max_value(), Currency::USD, "acct_seller"),
+        TransferParams::new(1, Currency::USD, "acct_courier"),
+    ];
+    match group.charge_and_transfer(&client, ChargeParams::new(10000, Currency::USD), transfers) {
+        Err(Error::InvalidParams(violations)) => assert_eq!(violations[0].param, "amount"),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn deserialize_external_accounts() {
+    use stripe::{Currency, ExternalAccount, List};
+
+    let accounts: List<ExternalAccount> = json::from_value(json!({
+        "object": "list",
+        "data": [
+            {
+                "id": "ba_123", "object": "bank_account", "account": "acct_123", "account_holder_name": null,
+                "account_holder_type": null, "bank_name": "STRIPE TEST BANK", "country": "US", "currency": "usd",
+                "default_for_currency": true, "fingerprint": null, "last4": "6789", "routing_number": "110000000",
+                "status": "new"
+            },
+            {
+                "id": "card_123", "object": "card", "account": "acct_123", "address_city": null, "address_country": null,
+                "address_line1": null, "address_line1_check": null, "address_line2": null, "address_state": null,
+                "address_zip": null, "address_zip_check": null, "brand": "Visa", "country": "US", "currency": "usd",
+                "customer": null, "cvc_check": null, "default_for_currency": false, "exp_month": 8, "exp_year": 2030,
+                "fingerprint": "Xt5EWLLDS7FJjR1c", "funding": "debit", "last4": "5556"
+            }
+        ],
+        "has_more": false,
+        "url": "/v1/accounts/acct_123/external_accounts"
+    })).unwrap();
+
+    let currencies = accounts.data.iter().map(|account| (account.currency(), account.is_default_for_currency())).collect::<Vec<_>>();
+    assert_eq!(currencies, vec![(Some(Currency::USD), true), (Some(Currency::USD), false)]);
+}
+
+#[test]
+fn deserialize_issuing_card_details() {
+    use stripe::issuing::{CardDetails, ReplacementReason, ShippingCarrier, ShippingStatus};
+
+    let details: CardDetails = json::from_value(json!({
+        "id": "ic_123", "object": "issuing.card", "brand": "Visa", "cancellation_reason": null,
+        "cardholder": {
+            "id": "ich_123", "object": "issuing.cardholder",
+            "billing": {"address": {"city": "San Francisco", "country": "US", "line1": "1 Main St", "postal_code": "94111", "state": "CA"}},
+            "created": 1600000000, "email": null, "livemode": false, "metadata": {}, "name": "Jenny Rosen",
+            "phone_number": null, "status": "active", "type": "individual"
+        },
+        "created": 1600000000, "currency": "usd", "cvc": "123", "exp_month": 8, "exp_year": 2030, "last4": "4242",
+        "livemode": false, "metadata": {}, "number": "4000009990000000",
+        "replaced_by": null, "replacement_for": "ic_122", "replacement_reason": "damaged",
+        "shipping": {
+            "address": {"city": "San Francisco", "country": "US", "line1": "1 Main St", "postal_code": "94111", "state": "CA"},
+            "carrier": "usps", "eta": 1600500000, "name": "Jenny Rosen", "service": "standard", "status": "shipped",
+            "tracking_number": "9400100000000000000000", "tracking_url": "https://tools.usps.com/go/TrackConfirmAction?tLabels=9400100000000000000000",
+            "type": "individual"
+        },
+        "status": "inactive", "type": "physical"
+    })).unwrap();
+
+    assert_eq!(details.number, "4000009990000000");
+    assert!(!format!("{:?}", details).contains("4000009990000000"));
+    assert_eq!(details.card.replacement_reason, Some(ReplacementReason::Damaged));
+
+    let mut shipping = details.card.shipping.unwrap();
+    assert_eq!(shipping.carrier, Some(ShippingCarrier::Usps));
+    assert_eq!(shipping.status, Some(ShippingStatus::Shipped));
+    assert!(!shipping.is_finished());
+
+    shipping.status = json::from_value(json!("lost_in_transit")).unwrap();
+    assert_eq!(shipping.status, Some(ShippingStatus::Other("lost_in_transit".to_string())));
+    assert!(!shipping.is_finished());
+
+    let encoded = stripe::to_form(&shipping).unwrap();
+    assert!(!encoded.contains("tracking_number"));
+}
+
+#[test]
+fn serialize_present_payment_method() {
+    use stripe::terminal::{PresentPaymentMethodParams, Reader};
+
+    let params = PresentPaymentMethodParams::card("4000000000000002").amount_tip(150);
+    assert_eq!(urldecode(stripe::to_form(&params).unwrap()), "amount_tip=150&card_present[number]=4000000000000002&type=card_present");
+
+    let client = stripe::Client::new("sk_live_123");
+    match Reader::test_helpers_present_payment_method(&client, "tmr_123", PresentPaymentMethodParams::default()) {
+        Err(stripe::Error::Config(_)) => {}
+        other => panic!("expected a config error, got {:?}", other),
+    }
+}
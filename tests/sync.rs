@@ -15,3 +15,11 @@ fn sync() {
         assert!(stripe::Customer::retrieve(&clone2, "").is_err());
     });
 }
+
+#[test]
+fn errors_are_send_and_sync() {
+    fn assert_error<E: std::error::Error + Send + Sync + 'static>() {}
+    assert_error::<stripe::Error>();
+    assert_error::<stripe::RequestError>();
+    assert_error::<stripe::WebhookError>();
+}